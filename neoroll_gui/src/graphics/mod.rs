@@ -1,4 +1,5 @@
 use bevy::prelude::Color;
+use neoroll_world::entity::elevation::Elevation;
 
 pub mod resolver;
 
@@ -51,3 +52,17 @@ impl AlphaByScale {
         }
     }
 }
+
+/// Darken lowest tiles so relief is readable.
+pub fn shade_by_elevation(color: Color, elevation: &Option<Elevation>) -> Color {
+    const MIN_BRIGHTNESS: f32 = 0.7;
+
+    let elevation = elevation.unwrap_or_default().0 as f32 / u8::MAX as f32;
+    let brightness = MIN_BRIGHTNESS + (1.0 - MIN_BRIGHTNESS) * elevation;
+    Color::Rgba {
+        red: color.r() * brightness,
+        green: color.g() * brightness,
+        blue: color.b() * brightness,
+        alpha: color.a(),
+    }
+}
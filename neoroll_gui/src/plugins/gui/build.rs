@@ -6,7 +6,10 @@ use bevy::{
 };
 use bevy_egui::egui::{self, Ui};
 use bevy_tileset::prelude::{TileIndex, Tilesets};
-use neoroll_server::{server::ClientMessage, state::game::ClientGameMessage};
+use neoroll_server::{
+    server::ClientMessage,
+    state::{client::construction::ConstructionState, game::ClientGameMessage},
+};
 use neoroll_world::{gameplay::build::Buildable, space::AbsoluteWorldPoint};
 
 use crate::{
@@ -35,7 +38,7 @@ pub fn display_build_cursor(
     mut cursor: Query<(&BuildCursor, &mut Transform)>,
     camera: Query<(&Camera, &GlobalTransform), (With<SceneItemsCamera>, Without<BackgroundCamera>)>,
 ) {
-    if let Current::Build(_) = state.current_mode() {
+    if let Current::Build(_) | Current::Migrate = state.current_mode() {
         let window = windows.single();
         if let Ok((_, mut transform)) = cursor.get_single_mut() {
             let (camera, camera_transform) = camera.single();
@@ -61,7 +64,7 @@ pub fn display_build_outline(
     mut outline: Query<(&BuildOutline, &mut Transform)>,
     camera: Query<(&Camera, &GlobalTransform), (With<SceneItemsCamera>, Without<BackgroundCamera>)>,
 ) {
-    if let Current::Build(_) = state.current_mode() {
+    if let Current::Build(_) | Current::Migrate | Current::Field = state.current_mode() {
         let window = windows.single();
         if let Ok((_, mut transform)) = outline.get_single_mut() {
             let (camera, camera_transform) = camera.single();
@@ -130,7 +133,10 @@ pub fn spawn_build_outline(
 }
 
 pub fn despawn_build_cursor(commands: &mut Commands, cursor: Query<Entity, With<BuildCursor>>) {
-    commands.entity(cursor.single()).despawn();
+    // Field designation has no cursor
+    if let Ok(entity) = cursor.get_single() {
+        commands.entity(entity).despawn();
+    }
 }
 
 pub fn despawn_build_outline(commands: &mut Commands, cursor: Query<Entity, With<BuildOutline>>) {
@@ -149,29 +155,44 @@ pub fn try_build(
     mut mouse: EventReader<MouseButtonInput>,
     mut commands: Commands,
 ) {
-    if let Current::Build(building) = state.current_mode() {
-        if let Some(event) = mouse.iter().last() {
-            if let ButtonState::Pressed = event.state {
-                let window = windows.single();
-                let (camera, camera_transform) = camera.single();
-                if let Some(world_position) = window
-                    .cursor_position()
-                    .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor))
-                {
-                    let point = tile_point_from_world_xy(world_position);
-                    gateway.send(ClientMessage::Game(ClientGameMessage::TryBuild(
-                        *building, point,
-                    )));
-                    state.set_current(Current::Explore);
-
-                    despawn_build_cursor(&mut commands, cursor);
-                    despawn_build_outline(&mut commands, outline);
-                }
+    if let Current::Explore | Current::Order(_, _) = state.current_mode() {
+        return;
+    }
+
+    if let Some(event) = mouse.iter().last() {
+        if let ButtonState::Pressed = event.state {
+            let window = windows.single();
+            let (camera, camera_transform) = camera.single();
+            if let Some(world_position) = window
+                .cursor_position()
+                .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor))
+            {
+                let point = tile_point_from_world_xy(world_position);
+                // When migrating, player picks the new campfire place. When
+                // designating a field, player picks its center
+                let message = match state.current_mode() {
+                    Current::Build(building) => ClientGameMessage::TryBuild(*building, point),
+                    Current::Migrate => ClientGameMessage::Migrate(point),
+                    Current::Field => ClientGameMessage::NewField(point),
+                    Current::Explore | Current::Order(_, _) => return,
+                };
+                gateway.send(ClientMessage::Game(message));
+                state.set_current(Current::Explore);
+
+                despawn_build_cursor(&mut commands, cursor);
+                despawn_build_outline(&mut commands, outline);
             }
         }
     }
 }
 
+fn button_illustration(buildable: &Buildable) -> Illustration {
+    match buildable {
+        Buildable::Campfire => Illustration::CampfireButton,
+        Buildable::Storage => Illustration::ShortAndDryGrassButton,
+    }
+}
+
 fn tile_point_from_world_xy(position: Vec2) -> AbsoluteWorldPoint {
     AbsoluteWorldPoint::from_scene_point(ScenePoint::new(position.x, -position.y))
 }
@@ -181,28 +202,109 @@ impl<'a> Painter<'a> {
         let mut actions = vec![];
 
         ui.horizontal_wrapped(|ui| {
-            if self.game().build().can_build_campfire()
-                && ui
+            for buildable in self.game().build().buildables() {
+                if ui
                     .add_sized(
                         [75., 75.],
-                        egui::ImageButton::new(Illustration::CampfireButton.data()),
+                        egui::ImageButton::new(button_illustration(buildable).data()),
                     )
+                    .on_hover_text(buildable.to_string())
                     .clicked()
-            {
-                actions.extend(vec![GuiAction::Build(Buildable::Campfire)]);
-            };
+                {
+                    actions.extend(vec![GuiAction::Build(*buildable)]);
+                };
+            }
+        });
 
-            if self.game().build().can_build_storage()
-                && ui
-                    .add_sized(
-                        [75., 75.],
-                        egui::ImageButton::new(Illustration::ShortAndDryGrassButton.data()),
-                    )
-                    .clicked()
+        ui.separator();
+        match self.game().build().migration() {
+            Some(migration) => {
+                let to = *migration.to();
+                let cancelable = self
+                    .game()
+                    .constructions()
+                    .constructions()
+                    .iter()
+                    .any(|c| c.point() == &to);
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(format!("Migrating to {}x{}", to.0 .0, to.1 .0));
+                    if cancelable && ui.button("Cancel migration").clicked() {
+                        actions.push(GuiAction::CancelMigration);
+                    }
+                });
+            }
+            None => {
+                if self.game().build().can_migrate()
+                    && ui
+                        .button("Migrate...")
+                        .on_hover_text("Pick the new campfire place")
+                        .clicked()
+                {
+                    actions.push(GuiAction::PickMigrationPlace);
+                }
+            }
+        }
+
+        if self.game().build().can_farm() {
+            ui.separator();
+            if ui
+                .button("Add field...")
+                .on_hover_text("Pick the new field center")
+                .clicked()
             {
-                actions.extend(vec![GuiAction::Build(Buildable::Storage)]);
-            };
+                actions.push(GuiAction::PickFieldPlace);
+            }
+            for field in self.game().build().fields() {
+                let center = *field.center();
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(format!("Field ({}x{})", center.0 .0, center.1 .0));
+                    if ui.button("Remove").clicked() {
+                        actions.push(GuiAction::RemoveField(center));
+                    }
+                });
+            }
+        }
+
+        let constructions = self.game().constructions().constructions().to_vec();
+        if !constructions.is_empty() {
+            ui.separator();
+            ui.label("Constructions:");
+            for construction in &constructions {
+                actions.extend(self.construction_detail(ui, construction));
+                ui.separator();
+            }
+        }
+
+        actions
+    }
+
+    pub fn construction_detail(
+        &self,
+        ui: &mut Ui,
+        construction: &ConstructionState,
+    ) -> Vec<GuiAction> {
+        let mut actions = vec![];
+        let point = construction.point();
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!(
+                "{} ({}x{})",
+                construction.buildable(),
+                point.0 .0,
+                point.1 .0
+            ));
+            ui.add(
+                egui::ProgressBar::new((*construction.progress()).into())
+                    .desired_width(150.)
+                    .show_percentage(),
+            );
+            if ui.button("Cancel").clicked() {
+                actions.push(GuiAction::CancelConstruction(*point));
+            }
         });
+        for (material, required, brought) in construction.materials() {
+            ui.label(format!("{}: {}/{}", material, brought, required));
+        }
 
         actions
     }
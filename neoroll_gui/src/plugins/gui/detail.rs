@@ -9,8 +9,10 @@ use bevy::{
 use bevy_egui::egui::{Grid, Ui, Vec2};
 use neoroll_world::{
     entity::{creature::PartialCreature, floor::Floor},
+    gameplay::skill::{Skill, MAX_LEVEL},
     space::AbsoluteWorldPoint,
 };
+use strum::IntoEnumIterator;
 
 use crate::{
     camera::{BackgroundCamera, SceneItemsCamera},
@@ -90,15 +92,28 @@ impl<'a> Painter<'a> {
     }
 
     fn creature_detail(&self, ui: &mut Ui, creature: &PartialCreature) -> Vec<GuiAction> {
-        ui.label("Creature");
+        ui.label(format!("Creature: {}", creature.name()));
+        ui.label(format!("Age: {}", creature.age()));
+        ui.label(format!("Hunger: {}", creature.hunger()));
         ui.label(format!("Job: {}", creature.job()));
         ui.label(format!("Behavior: {}", creature.behavior()));
+        let actions = self.creature_orders(ui, creature);
+        ui.label("Skills:");
+        for skill in Skill::iter() {
+            ui.label(format!(
+                "{}: {}/{}",
+                skill,
+                creature.skills().level(&skill),
+                MAX_LEVEL
+            ));
+        }
 
-        vec![]
+        actions
     }
 
     fn tile_detail(&self, ui: &mut Ui, point: &AbsoluteWorldPoint) -> Vec<GuiAction> {
         let world = self.world();
+        let mut actions = vec![];
 
         if let Some(ground) = world.ground(point) {
             ui.label(format!("Ground: {}", ground.detail_string()));
@@ -117,6 +132,14 @@ impl<'a> Painter<'a> {
         if let Some(structure) = world.structure(point) {
             ui.label(format!("Structure: {}", structure.detail_string()));
             self.illustration(ui, structure);
+            if self.game().build().is_owned(point) && ui.button("Demolish").clicked() {
+                actions.push(GuiAction::Demolish(*point));
+            }
+            ui.separator();
+        }
+
+        if let Some(construction) = self.game().constructions().construction(point) {
+            actions.extend(self.construction_detail(ui, construction));
             ui.separator();
         }
 
@@ -132,13 +155,13 @@ impl<'a> Painter<'a> {
                         for (material, quantity) in materials {
                             ui.label(material.to_string());
                             self.illustration(ui, material);
-                            ui.label(material.quantity_string(quantity));
+                            ui.label(quantity.to_string());
                             ui.end_row();
                         }
                     });
             }
         }
 
-        vec![]
+        actions
     }
 }
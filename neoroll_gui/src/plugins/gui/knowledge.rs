@@ -0,0 +1,70 @@
+use bevy_egui::egui::{Button, Grid, Ui, Vec2};
+use neoroll_world::gameplay::tech::{Technology, Unlock};
+use strum::IntoEnumIterator;
+
+use super::{paint::Painter, GuiAction};
+
+impl<'a> Painter<'a> {
+    pub fn knowledge(&mut self, ui: &mut Ui) -> Vec<GuiAction> {
+        let mut actions = vec![];
+        let knowledge = self.game().knowledge();
+
+        ui.label(format!("Knowledge points: {}", knowledge.points()));
+        ui.separator();
+
+        Grid::new("technologies")
+            .min_col_width(115.)
+            .spacing(Vec2::new(10., 10.))
+            .striped(true)
+            .show(ui, |ui| {
+                for technology in Technology::iter() {
+                    ui.label(technology.to_string());
+                    ui.label(
+                        technology
+                            .unlocks()
+                            .iter()
+                            .map(unlock_string)
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    );
+
+                    if knowledge.knows(&technology) {
+                        ui.label("Known");
+                    } else {
+                        let requires = technology
+                            .requires()
+                            .iter()
+                            .map(|t| t.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        ui.vertical(|ui| {
+                            ui.label(format!("Cost: {}", technology.cost()));
+                            if !requires.is_empty() {
+                                ui.label(format!("Require: {}", requires));
+                            }
+                        });
+                        if ui
+                            .add_enabled(
+                                knowledge.can_discover(&technology),
+                                Button::new("Discover"),
+                            )
+                            .clicked()
+                        {
+                            actions.push(GuiAction::Discover(technology));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        actions
+    }
+}
+
+fn unlock_string(unlock: &Unlock) -> String {
+    match unlock {
+        Unlock::Buildable(buildable) => format!("Build {}", buildable),
+        Unlock::Recipe(crafted) => format!("Craft {}", crafted),
+        Unlock::Job(job) => job.to_string(),
+    }
+}
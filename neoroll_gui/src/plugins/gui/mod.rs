@@ -2,6 +2,8 @@ pub mod stock;
 pub mod targets;
 
 pub mod detail;
+pub mod knowledge;
+pub mod order;
 pub mod paint;
 pub mod root;
 
@@ -18,7 +20,14 @@ use neoroll_server::{
     server::ClientMessage,
     state::game::{settings::TargetSetting, ClientGameMessage, TargetMessage},
 };
-use neoroll_world::gameplay::{build::Buildable, target::TargetId};
+use neoroll_world::{
+    entity::creature::CreatureId,
+    gameplay::{
+        build::Buildable, material::Material, order::Order, target::TargetId, tech::Technology,
+    },
+    space::AbsoluteWorldPoint,
+};
+use order::{try_order, OrderKind};
 use paint::Painter;
 use state::GuiState;
 use strum_macros::EnumIter;
@@ -48,6 +57,7 @@ impl Plugin for GuiPlugin {
                     display_build_outline,
                     try_build,
                     details,
+                    try_order.after(details),
                 ),
             );
     }
@@ -56,6 +66,9 @@ impl Plugin for GuiPlugin {
 pub enum Current {
     Explore,
     Build(Buildable),
+    Order(CreatureId, OrderKind),
+    Migrate,
+    Field,
 }
 
 impl Default for Current {
@@ -116,9 +129,48 @@ fn gui(
                 spawn_build_outline(&mut commands, &mut meshes, &mut materials);
                 spawn_build_cursor(&mut commands, buildable, &tilesets);
             }
+            GuiAction::PickMigrationPlace => {
+                state.set_display_window(false);
+                state.set_current(Current::Migrate);
+                spawn_build_outline(&mut commands, &mut meshes, &mut materials);
+                spawn_build_cursor(&mut commands, Buildable::Campfire, &tilesets);
+            }
+            GuiAction::CancelMigration => {
+                gateway.send(ClientMessage::Game(ClientGameMessage::CancelMigration))
+            }
+            GuiAction::PickFieldPlace => {
+                state.set_display_window(false);
+                state.set_current(Current::Field);
+                spawn_build_outline(&mut commands, &mut meshes, &mut materials);
+            }
+            GuiAction::RemoveField(point) => {
+                gateway.send(ClientMessage::Game(ClientGameMessage::RemoveField(point)))
+            }
             GuiAction::Target(target_id, target_action) => gateway.send(ClientMessage::Game(
                 ClientGameMessage::Target(target_id, target_action.into()),
             )),
+            GuiAction::CancelConstruction(point) => gateway.send(ClientMessage::Game(
+                ClientGameMessage::CancelConstruction(point),
+            )),
+            GuiAction::Demolish(point) => {
+                gateway.send(ClientMessage::Game(ClientGameMessage::Demolish(point)))
+            }
+            GuiAction::StorageAccept(point, material, value) => gateway.send(ClientMessage::Game(
+                ClientGameMessage::StorageAccept(point, material, value),
+            )),
+            GuiAction::PickOrderTarget(creature_id, kind) => {
+                state.set_display_window(false);
+                state.set_current(Current::Order(creature_id, kind));
+            }
+            GuiAction::Order(creature_id, order) => gateway.send(ClientMessage::Game(
+                ClientGameMessage::Order(creature_id, order),
+            )),
+            GuiAction::CancelOrder(creature_id) => gateway.send(ClientMessage::Game(
+                ClientGameMessage::CancelOrder(creature_id),
+            )),
+            GuiAction::Discover(technology) => {
+                gateway.send(ClientMessage::Game(ClientGameMessage::Discover(technology)))
+            }
         }
     }
 
@@ -152,6 +204,7 @@ pub enum Panel {
     Build,
     Details,
     Stock,
+    Knowledge,
 }
 
 impl Default for Panel {
@@ -168,18 +221,33 @@ impl Display for Panel {
             Panel::Details => f.write_str("Details"),
             Panel::Build => f.write_str("Build"),
             Panel::Stock => f.write_str("Stocks"),
+            Panel::Knowledge => f.write_str("Knowledge"),
         }
     }
 }
 
 pub enum GuiAction {
     Build(Buildable),
+    CancelConstruction(AbsoluteWorldPoint),
+    CancelMigration,
+    CancelOrder(CreatureId),
+    Demolish(AbsoluteWorldPoint),
+    Discover(Technology),
+    Order(CreatureId, Order),
+    PickFieldPlace,
+    PickMigrationPlace,
+    PickOrderTarget(CreatureId, OrderKind),
+    RemoveField(AbsoluteWorldPoint),
+    StorageAccept(AbsoluteWorldPoint, Material, bool),
     Target(TargetId, TargetAction),
 }
 
 pub enum TargetAction {
     New(TargetSetting),
     Set(TargetSetting),
+    Remove,
+    Pause,
+    Resume,
 }
 
 impl From<TargetAction> for TargetMessage {
@@ -187,6 +255,9 @@ impl From<TargetAction> for TargetMessage {
         match value {
             TargetAction::Set(target) => TargetMessage::Set(target),
             TargetAction::New(target) => TargetMessage::New(target),
+            TargetAction::Remove => TargetMessage::Remove,
+            TargetAction::Pause => TargetMessage::Pause,
+            TargetAction::Resume => TargetMessage::Resume,
         }
     }
 }
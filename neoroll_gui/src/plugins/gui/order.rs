@@ -0,0 +1,125 @@
+use bevy::{
+    prelude::{Query, Res, ResMut, With, Without},
+    render::camera::Camera,
+    transform::components::GlobalTransform,
+    window::Window,
+};
+use bevy_egui::egui::{Ui, Vec2};
+use neoroll_server::{server::ClientMessage, state::game::ClientGameMessage};
+use neoroll_world::{
+    entity::creature::{CreatureId, PartialCreature},
+    gameplay::{material::Resource, order::Order},
+    space::AbsoluteWorldPoint,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    camera::{BackgroundCamera, SceneItemsCamera},
+    graphics::REGION_TILE_WIDTH,
+    plugins::{inputs::state::InputState, server::gateway::GatewayWrapper},
+    scene::{FromScenePoint, ScenePoint},
+};
+
+use super::{paint::Painter, state::GuiState, Current, GuiAction, Panel};
+
+/// Order waiting for player to pick its target tile
+#[derive(Clone, Copy)]
+pub enum OrderKind {
+    MoveTo,
+    Collect(Resource),
+    DropOff,
+}
+
+impl OrderKind {
+    pub fn order(&self, point: AbsoluteWorldPoint) -> Order {
+        match self {
+            OrderKind::MoveTo => Order::MoveTo(point),
+            OrderKind::Collect(resource) => Order::Collect(point, *resource),
+            OrderKind::DropOff => Order::DropOff(point),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn try_order(
+    mut state: ResMut<GuiState>,
+    gateway: Res<GatewayWrapper>,
+    input_state: Res<InputState>,
+    camera: Query<(&Camera, &GlobalTransform), (With<SceneItemsCamera>, Without<BackgroundCamera>)>,
+    windows: Query<&Window>,
+) {
+    if input_state.is_clicked_outside_gui() {
+        if let Current::Order(creature_id, kind) = state.current_mode() {
+            let (creature_id, kind) = (*creature_id, *kind);
+            let window = windows.single();
+            let (camera, camera_transform) = camera.single();
+            if let Some(world_position) = window
+                .cursor_position()
+                .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor))
+            {
+                // NOTE: there is a display decal
+                let world_position = Vec2::new(
+                    world_position.x + REGION_TILE_WIDTH as f32 / 2.,
+                    world_position.y - REGION_TILE_WIDTH as f32 / 2.,
+                );
+
+                let point = AbsoluteWorldPoint::from_scene_point(ScenePoint::new(
+                    world_position.x,
+                    -world_position.y,
+                ));
+
+                gateway.send(ClientMessage::Game(ClientGameMessage::Order(
+                    creature_id,
+                    kind.order(point),
+                )));
+                state.set_current(Current::Explore);
+                *state.display_window_mut() = true;
+                *state.current_panel_mut() = Panel::Details;
+            }
+        }
+    }
+}
+
+impl<'a> Painter<'a> {
+    pub fn creature_orders(&self, ui: &mut Ui, creature: &PartialCreature) -> Vec<GuiAction> {
+        let mut actions = vec![];
+        let creature_id: CreatureId = *creature.id();
+
+        match self.game().human().order(&creature_id) {
+            Some(order) => {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(format!("Order: {}", order));
+                    if ui.button("Cancel order").clicked() {
+                        actions.push(GuiAction::CancelOrder(creature_id));
+                    }
+                });
+            }
+            None => {
+                ui.label("Order: none");
+            }
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            if ui.button("Move to...").clicked() {
+                actions.push(GuiAction::PickOrderTarget(creature_id, OrderKind::MoveTo));
+            }
+            for resource in Resource::iter() {
+                if ui.button(format!("Collect {}...", resource)).clicked() {
+                    actions.push(GuiAction::PickOrderTarget(
+                        creature_id,
+                        OrderKind::Collect(resource),
+                    ));
+                }
+            }
+            if ui.button("Drop off...").clicked() {
+                actions.push(GuiAction::PickOrderTarget(creature_id, OrderKind::DropOff));
+            }
+            if ui.button("Stay idle").clicked() {
+                actions.push(GuiAction::Order(creature_id, Order::Idle));
+            }
+        });
+
+        actions
+    }
+}
@@ -40,6 +40,7 @@ impl<'a> Painter<'a> {
             Panel::Details => self.details(ui),
             Panel::Build => self.builds(ui),
             Panel::Stock => self.stocks(ui),
+            Panel::Knowledge => self.knowledge(ui),
         }
     }
 
@@ -61,6 +62,9 @@ impl<'a> Painter<'a> {
                     ui.selectable_value(gui_zoom, GuiZoom::VeryBig, "VeryBig");
                 });
 
+            ui.separator();
+            ui.label(self.game.calendar().to_string());
+
             if ui.button("Close").clicked() {
                 *self.state_mut().display_window_mut() = false;
             }
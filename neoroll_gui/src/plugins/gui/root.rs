@@ -26,6 +26,15 @@ impl<'a> Painter<'a> {
                 )));
         };
 
+        let script_errors = self.game().script_errors().to_vec();
+        if !script_errors.is_empty() {
+            ui.separator();
+            ui.label("Script errors:");
+            for error in &script_errors {
+                ui.label(error);
+            }
+        }
+
         vec![]
     }
 }
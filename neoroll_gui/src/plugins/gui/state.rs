@@ -3,7 +3,7 @@ use std::fmt::Display;
 use bevy::prelude::Resource as BevyResource;
 use neoroll_world::{
     entity::creature::CreatureId,
-    gameplay::material::{Material, Resource},
+    gameplay::material::{Crafted, Hunted, Material, Resource},
     space::AbsoluteWorldPoint,
 };
 
@@ -111,6 +111,12 @@ impl GuiState {
 pub enum AddTarget {
     None,
     KeepStock,
+    KeepBuilt,
+    Explore,
+    Produce,
+    Plant,
+    Farm,
+    Scripted,
 }
 impl AddTarget {
     pub fn is_none(&self) -> bool {
@@ -123,6 +129,12 @@ impl Display for AddTarget {
         match self {
             AddTarget::None => f.write_str(""),
             AddTarget::KeepStock => f.write_str("Keep Stock of"),
+            AddTarget::KeepBuilt => f.write_str("Keep Built"),
+            AddTarget::Explore => f.write_str("Explore"),
+            AddTarget::Produce => f.write_str("Produce"),
+            AddTarget::Plant => f.write_str("Plant trees"),
+            AddTarget::Farm => f.write_str("Farm"),
+            AddTarget::Scripted => f.write_str("Scenario"),
         }
     }
 }
@@ -138,6 +150,11 @@ pub enum AddKeepStockTargetMaterial {
     Food,
     RawFlint,
     Branches,
+    Logs,
+    Fish,
+    Water,
+    CarvedFlint,
+    Hide,
 }
 
 impl Display for AddKeepStockTargetMaterial {
@@ -146,6 +163,11 @@ impl Display for AddKeepStockTargetMaterial {
             AddKeepStockTargetMaterial::Food => f.write_str("Food"),
             AddKeepStockTargetMaterial::RawFlint => f.write_str("Raw Flint"),
             AddKeepStockTargetMaterial::Branches => f.write_str("Branches"),
+            AddKeepStockTargetMaterial::Logs => f.write_str("Logs"),
+            AddKeepStockTargetMaterial::Fish => f.write_str("Fish"),
+            AddKeepStockTargetMaterial::Water => f.write_str("Water"),
+            AddKeepStockTargetMaterial::CarvedFlint => f.write_str("Carved Flint"),
+            AddKeepStockTargetMaterial::Hide => f.write_str("Hide"),
         }
     }
 }
@@ -162,6 +184,11 @@ impl From<AddKeepStockTargetMaterial> for Material {
             AddKeepStockTargetMaterial::Food => Material::Resource(Resource::Food),
             AddKeepStockTargetMaterial::RawFlint => Material::Resource(Resource::RawFlint),
             AddKeepStockTargetMaterial::Branches => Material::Resource(Resource::Branches),
+            AddKeepStockTargetMaterial::Logs => Material::Resource(Resource::Logs),
+            AddKeepStockTargetMaterial::Fish => Material::Resource(Resource::Fish),
+            AddKeepStockTargetMaterial::Water => Material::Resource(Resource::Water),
+            AddKeepStockTargetMaterial::CarvedFlint => Material::Crafted(Crafted::CarvedFlint),
+            AddKeepStockTargetMaterial::Hide => Material::Hunted(Hunted::Hide),
         }
     }
 }
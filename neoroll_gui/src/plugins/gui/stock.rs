@@ -1,9 +1,12 @@
 use bevy_egui::egui::{ComboBox, Grid, Ui, Vec2};
+use neoroll_world::gameplay::material::{Material, Resource};
+use strum::IntoEnumIterator;
 
 use super::{paint::Painter, GuiAction};
 
 impl<'a> Painter<'a> {
     pub fn stocks(&mut self, ui: &mut Ui) -> Vec<GuiAction> {
+        let mut actions = vec![];
         let storages = self.game().materials().storages();
         let mut selected_storage = self.state().storage();
         let selected_text = self
@@ -32,6 +35,19 @@ impl<'a> Painter<'a> {
                 .iter()
                 .find(|(p, _)| p == &point)
             {
+                if let Some(capacity) = self.game().materials().capacity(&point) {
+                    ui.label(format!(
+                        "Weight: {} / {}",
+                        capacity.weight(),
+                        capacity.max_weight()
+                    ));
+                    ui.label(format!(
+                        "Volume: {} / {}",
+                        capacity.volume(),
+                        capacity.max_volume()
+                    ));
+                }
+
                 Grid::new("stock")
                     .min_col_width(175.)
                     .min_row_height(50.)
@@ -41,13 +57,23 @@ impl<'a> Painter<'a> {
                         for (material, quantity) in stocks {
                             ui.label(material.to_string());
                             self.illustration(ui, material);
-                            ui.label(material.quantity_string(quantity));
+                            ui.label(quantity.to_string());
                             ui.end_row();
                         }
                     });
+
+                ui.separator();
+                ui.label("Accepted materials");
+                for resource in Resource::iter() {
+                    let material = Material::Resource(resource);
+                    let mut accept = self.game().materials().accept(&point, &material);
+                    if ui.checkbox(&mut accept, material.to_string()).changed() {
+                        actions.push(GuiAction::StorageAccept(point, material, accept));
+                    }
+                }
             }
         }
 
-        vec![]
+        actions
     }
 }
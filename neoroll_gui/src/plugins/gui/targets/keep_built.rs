@@ -0,0 +1,22 @@
+use bevy_egui::egui::{Slider, Ui};
+use neoroll_server::state::game::settings::TargetSetting;
+use neoroll_world::gameplay::target::{ComputedTarget, Target};
+
+use crate::plugins::gui::{paint::Painter, GuiAction, TargetAction};
+
+impl<'a> Painter<'a> {
+    pub fn keep_built_settings(&self, ui: &mut Ui, target: &ComputedTarget) -> Vec<GuiAction> {
+        if let Target::KeepBuilt(buildable, count) = target.target() {
+            let mut value = *count;
+            if ui.add(Slider::new(&mut value, 0..=10)).changed() {
+                let new_target = Target::KeepBuilt(*buildable, value);
+                return vec![GuiAction::Target(
+                    *target.id(),
+                    TargetAction::Set(TargetSetting::from(target).with_target(new_target)),
+                )];
+            }
+        }
+
+        vec![]
+    }
+}
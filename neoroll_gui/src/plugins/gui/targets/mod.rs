@@ -1,9 +1,15 @@
+pub mod explore;
+pub mod keep_built;
 pub mod keep_stock;
+pub mod plant;
+pub mod produce;
 
-use bevy_egui::egui::{ComboBox, Grid, Ui, Vec2};
+use bevy_egui::egui::{Button, ComboBox, DragValue, Grid, Ui, Vec2};
 use neoroll_server::state::game::settings::TargetSetting;
-use neoroll_world::gameplay::material::Material;
+use neoroll_world::gameplay::build::Buildable;
+use neoroll_world::gameplay::material::{Crafted, Material};
 use neoroll_world::gameplay::target::{ComputedTarget, Target, TargetId};
+use strum::IntoEnumIterator;
 
 use crate::plugins::gui::state::{AddKeepStockTargetMaterial, AddTarget};
 use crate::plugins::gui::TargetAction;
@@ -54,24 +60,58 @@ impl<'a> Painter<'a> {
             ui.label(target_.name());
         });
 
+        actions.extend(self.target_resume(ui, target));
+        if let Some(material) = target_.material() {
+            self.illustration(ui, &material);
+        } else {
+            ui.label("");
+        }
+
         match target_ {
-            Target::KeepStock(_, _) => {
-                actions.extend(self.keep_stock_resume(ui, target));
-                if let Some(material) = target_.material() {
-                    self.illustration(ui, material);
-                } else {
-                    ui.label("");
-                }
-                actions.extend(self.keep_stock_settings(ui, target));
+            Target::KeepStock(_, _) => actions.extend(self.keep_stock_settings(ui, target)),
+            Target::KeepBuilt(_, _) => actions.extend(self.keep_built_settings(ui, target)),
+            Target::Explore(_) => actions.extend(self.explore_settings(ui, target)),
+            Target::Produce(_, _) => actions.extend(self.produce_settings(ui, target)),
+            Target::Plant(_) => actions.extend(self.plant_settings(ui, target)),
+            // Fields are designated from build panel
+            // Scenario scripts targets have no settings
+            Target::Farm | Target::Scripted(_) => {
+                ui.label("");
             }
         }
 
+        actions.extend(self.target_controls(ui, target));
+
         actions
     }
 
+    fn target_resume(&self, ui: &mut Ui, target: &ComputedTarget) -> Vec<GuiAction> {
+        ui.vertical(|ui| {
+            let state_value = target.state_string();
+            ui.label(&format!("State: {}", state_value));
+
+            ui.label(&format!("Affected: {}", target.affected()));
+            ui.label(&format!("Urgency: {}%", target.urgency()));
+
+            if !target.covered() {
+                if let Some(waiting) = self.game().target().waitings().get(target.id()) {
+                    let waiting_str = waiting
+                        .iter()
+                        .map(|w| w.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    ui.label(&format!("Require: {}", waiting_str));
+                }
+            };
+        });
+
+        vec![]
+    }
+
     // TODO: Make this more dynamic
     fn add_target(&mut self, ui: &mut Ui) -> Vec<GuiAction> {
         let mut actions = vec![];
+        let script_targets = self.game().script_targets().to_vec();
 
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
@@ -90,6 +130,38 @@ impl<'a> Painter<'a> {
                             AddTarget::KeepStock,
                             &AddTarget::KeepStock.to_string(),
                         );
+                        ui.selectable_value(
+                            add_target,
+                            AddTarget::KeepBuilt,
+                            &AddTarget::KeepBuilt.to_string(),
+                        );
+                        ui.selectable_value(
+                            add_target,
+                            AddTarget::Explore,
+                            &AddTarget::Explore.to_string(),
+                        );
+                        ui.selectable_value(
+                            add_target,
+                            AddTarget::Produce,
+                            &AddTarget::Produce.to_string(),
+                        );
+                        ui.selectable_value(
+                            add_target,
+                            AddTarget::Plant,
+                            &AddTarget::Plant.to_string(),
+                        );
+                        ui.selectable_value(
+                            add_target,
+                            AddTarget::Farm,
+                            &AddTarget::Farm.to_string(),
+                        );
+                        if !script_targets.is_empty() {
+                            ui.selectable_value(
+                                add_target,
+                                AddTarget::Scripted,
+                                AddTarget::Scripted.to_string(),
+                            );
+                        }
                     });
 
                 match add_target {
@@ -115,18 +187,91 @@ impl<'a> Painter<'a> {
                                     AddKeepStockTargetMaterial::Branches,
                                     &AddKeepStockTargetMaterial::Branches.to_string(),
                                 );
+                                ui.selectable_value(
+                                    material,
+                                    AddKeepStockTargetMaterial::Logs,
+                                    &AddKeepStockTargetMaterial::Logs.to_string(),
+                                );
+                                ui.selectable_value(
+                                    material,
+                                    AddKeepStockTargetMaterial::Fish,
+                                    &AddKeepStockTargetMaterial::Fish.to_string(),
+                                );
+                                ui.selectable_value(
+                                    material,
+                                    AddKeepStockTargetMaterial::Water,
+                                    &AddKeepStockTargetMaterial::Water.to_string(),
+                                );
+                                ui.selectable_value(
+                                    material,
+                                    AddKeepStockTargetMaterial::CarvedFlint,
+                                    &AddKeepStockTargetMaterial::CarvedFlint.to_string(),
+                                );
+                                ui.selectable_value(
+                                    material,
+                                    AddKeepStockTargetMaterial::Hide,
+                                    &AddKeepStockTargetMaterial::Hide.to_string(),
+                                );
                             });
 
+                        let material: Material = material.to_owned().into();
+                        let target = Target::KeepStock(material, Default::default());
+                        if ui
+                            .add_enabled(
+                                self.game().knowledge().can_target(&target),
+                                Button::new("Add"),
+                            )
+                            .clicked()
+                        {
+                            actions.push(self.new_target(target));
+                        }
+                    }
+                    AddTarget::KeepBuilt => {
+                        for buildable in
+                            Buildable::iter().filter(|b| self.game().knowledge().can_build(b))
+                        {
+                            if ui.button(format!("Add {}", buildable)).clicked() {
+                                actions.push(self.new_target(Target::KeepBuilt(buildable, 1)));
+                            }
+                        }
+                    }
+                    AddTarget::Explore => {
+                        if ui.button("Add").clicked() {
+                            actions.push(self.new_target(Target::Explore(0)));
+                        }
+                    }
+                    AddTarget::Produce => {
+                        for crafted in
+                            Crafted::iter().filter(|c| self.game().knowledge().can_craft(c))
+                        {
+                            if ui.button(format!("Add {}", crafted)).clicked() {
+                                actions.push(
+                                    self.new_target(Target::Produce(crafted, Default::default())),
+                                );
+                            }
+                        }
+                    }
+                    AddTarget::Plant => {
                         if ui.button("Add").clicked() {
-                            let material: Material = material.to_owned().into();
-                            let priority = self.game().target().targets().len() + 1;
-                            actions.extend(vec![GuiAction::Target(
-                                TargetId::new(),
-                                TargetAction::New(TargetSetting::new(
-                                    Target::KeepStock(material, Default::default()).default(),
-                                    priority,
-                                )),
-                            )]);
+                            actions.push(self.new_target(Target::Plant(0)));
+                        }
+                    }
+                    AddTarget::Farm => {
+                        if ui
+                            .add_enabled(
+                                self.game().knowledge().can_target(&Target::Farm),
+                                Button::new("Add"),
+                            )
+                            .clicked()
+                        {
+                            actions.push(self.new_target(Target::Farm));
+                        }
+                    }
+                    AddTarget::Scripted => {
+                        for name in script_targets {
+                            if ui.button(format!("Add {}", name)).clicked() {
+                                actions.push(self.new_target(Target::Scripted(name)));
+                            }
                         }
                     }
                 };
@@ -135,4 +280,60 @@ impl<'a> Painter<'a> {
 
         actions
     }
+
+    fn new_target(&self, target: Target) -> GuiAction {
+        let priority = self.game().target().targets().len() + 1;
+        GuiAction::Target(
+            TargetId::new(),
+            TargetAction::New(TargetSetting::new(target.default(), priority)),
+        )
+    }
+
+    fn target_controls(&self, ui: &mut Ui, target: &ComputedTarget) -> Vec<GuiAction> {
+        let mut actions = vec![];
+
+        ui.vertical(|ui| {
+            let mut min = target.min_workers();
+            let mut max = target.max_workers().unwrap_or(0);
+            let mut limited = target.max_workers().is_some();
+            let mut changed = false;
+
+            ui.horizontal(|ui| {
+                ui.label("Min workers");
+                changed |= ui
+                    .add(DragValue::new(&mut min).clamp_range(0..=50))
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                changed |= ui.checkbox(&mut limited, "Max workers").changed();
+                if limited {
+                    changed |= ui
+                        .add(DragValue::new(&mut max).clamp_range(0..=50))
+                        .changed();
+                }
+            });
+            if changed {
+                let max = if limited { Some(max.max(min)) } else { None };
+                actions.push(GuiAction::Target(
+                    *target.id(),
+                    TargetAction::Set(TargetSetting::from(target).with_workers(min, max)),
+                ));
+            }
+
+            ui.horizontal(|ui| {
+                if target.enabled() {
+                    if ui.button("Pause").clicked() {
+                        actions.push(GuiAction::Target(*target.id(), TargetAction::Pause));
+                    }
+                } else if ui.button("Resume").clicked() {
+                    actions.push(GuiAction::Target(*target.id(), TargetAction::Resume));
+                }
+                if ui.button("Remove").clicked() {
+                    actions.push(GuiAction::Target(*target.id(), TargetAction::Remove));
+                }
+            });
+        });
+
+        actions
+    }
 }
@@ -0,0 +1,22 @@
+use bevy_egui::egui::{Slider, Ui};
+use neoroll_server::state::game::settings::TargetSetting;
+use neoroll_world::gameplay::target::{ComputedTarget, Target};
+
+use crate::plugins::gui::{paint::Painter, GuiAction, TargetAction};
+
+impl<'a> Painter<'a> {
+    pub fn produce_settings(&self, ui: &mut Ui, target: &ComputedTarget) -> Vec<GuiAction> {
+        if let Target::Produce(crafted, quantity) = target.target() {
+            let mut value = quantity.0;
+            if ui.add(Slider::new(&mut value, 0..=100)).changed() {
+                let new_target = Target::Produce(*crafted, quantity.with(value));
+                return vec![GuiAction::Target(
+                    *target.id(),
+                    TargetAction::Set(TargetSetting::from(target).with_target(new_target)),
+                )];
+            }
+        }
+
+        vec![]
+    }
+}
@@ -8,8 +8,9 @@ use neoroll_server::{
 use neoroll_world::{
     entity::creature::{CreatureId, PartialCreatureChange},
     space::part::{
-        WorldPartCreatureMessage, WorldPartFloorMessage, WorldPartGroundMessage,
-        WorldPartMaterialMessage, WorldPartMessage, WorldPartStructureMessage,
+        WorldPartAnimalMessage, WorldPartCreatureMessage, WorldPartFloorMessage,
+        WorldPartGroundMessage, WorldPartMaterialMessage, WorldPartMessage,
+        WorldPartStructureMessage,
     },
 };
 
@@ -18,6 +19,7 @@ use crate::{
         game::GameStateWrapper,
         map::container::{MapPartContainer, MapPartContainerRefreshed},
         world::{
+            animal::{AnimalComponent, AnimalsMap},
             container::{WorldPartContainer, WorldPartContainerRefreshed},
             creature::{
                 display_progress, CreatureComponent, CreaturesMap, ProgressDone, ProgressMap,
@@ -35,6 +37,7 @@ use super::gateway::GatewayWrapper;
 pub fn listen(
     gateway: Res<GatewayWrapper>,
     creatures_map: Res<CreaturesMap>,
+    animals_map: Res<AnimalsMap>,
     mut progress_map: ResMut<ProgressMap>,
     mut commands: Commands,
     mut world_container_refreshed: EventWriter<WorldPartContainerRefreshed>,
@@ -42,6 +45,14 @@ pub fn listen(
     mut map_container_refreshed: EventWriter<MapPartContainerRefreshed>,
     mut map_part: ResMut<MapPartContainer>,
     mut creatures: Query<(&CreatureComponent, &mut Transform)>,
+    mut animals: Query<
+        &mut Transform,
+        (
+            With<AnimalComponent>,
+            Without<CreatureComponent>,
+            Without<ProgressDone>,
+        ),
+    >,
     mut progress_done: Query<
         &mut Transform,
         (
@@ -97,6 +108,15 @@ pub fn listen(
                         PartialCreatureChange::SetJob(job) => {
                             creature.set_job(job);
                         }
+                        PartialCreatureChange::Practice(skill, experience) => {
+                            creature.practice(skill, experience);
+                        }
+                        PartialCreatureChange::SetAge(age) => {
+                            creature.set_age(age);
+                        }
+                        PartialCreatureChange::SetHunger(hunger) => {
+                            creature.set_hunger(hunger);
+                        }
                         // FIXME BS NOW: need big refactor here
                         PartialCreatureChange::SetBehavior(behavior) => {
                             // Update our world state part creature
@@ -120,6 +140,12 @@ pub fn listen(
                 ServerGameMessage::TryBuildError(original, error) => {
                     info!("TODO: (in gui) build error ({:?}): {:?}", original, error)
                 }
+                ServerGameMessage::DemolishError(original, error) => {
+                    info!(
+                        "TODO: (in gui) demolish error ({:?}): {:?}",
+                        original, error
+                    )
+                }
             },
             ServerMessage::WorldPart(change) => match change {
                 WorldPartMessage::Structure(point, change) => match change {
@@ -128,7 +154,7 @@ pub fn listen(
                         world_container_refreshed.send(WorldPartContainerRefreshed);
                     }
                 },
-                WorldPartMessage::Creature(_, change) => match change {
+                WorldPartMessage::Creature(id, change) => match change {
                     WorldPartCreatureMessage::New(creature) => {
                         gateway.send(ClientMessage::Subscriptions(
                             SubscriptionsMessage::PushCreatures(*creature.id()),
@@ -136,6 +162,12 @@ pub fn listen(
                         world_part.0.add_creature(creature);
                         world_container_refreshed.send(WorldPartContainerRefreshed);
                     }
+                    WorldPartCreatureMessage::Remove => {
+                        if world_part.0.creature(&id).is_some() {
+                            world_part.0.remove_creature(&id);
+                            world_container_refreshed.send(WorldPartContainerRefreshed);
+                        }
+                    }
                 },
                 WorldPartMessage::Floor(point, change) => match change {
                     WorldPartFloorMessage::Set(floor) => {
@@ -154,6 +186,36 @@ pub fn listen(
                         world_part.0.set_materials(&point, materials)
                     }
                 },
+                WorldPartMessage::Animal(id, change) => match change {
+                    WorldPartAnimalMessage::Set(animal) => {
+                        let known = world_part.0.animals().contains_key(&id);
+                        world_part.0.set_animal(animal);
+
+                        match (known, world_part.0.animals().get(&id)) {
+                            // Only moved, update bevy component display
+                            (true, Some(animal)) => {
+                                let point: Vec3 =
+                                    ScenePoint::from_world_point(animal.point()).into();
+                                if let Some(entity) = animals_map.get(&id) {
+                                    if let Ok(mut transform) = animals.get_mut(*entity) {
+                                        transform.translation = point;
+                                    }
+                                }
+                            }
+                            // Appeared or left displayed area
+                            (false, Some(_)) | (true, None) => {
+                                world_container_refreshed.send(WorldPartContainerRefreshed)
+                            }
+                            (false, None) => {}
+                        }
+                    }
+                    WorldPartAnimalMessage::Remove => {
+                        if world_part.0.animals().contains_key(&id) {
+                            world_part.0.remove_animal(&id);
+                            world_container_refreshed.send(WorldPartContainerRefreshed);
+                        }
+                    }
+                },
             },
         }
     }
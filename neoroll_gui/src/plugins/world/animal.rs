@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use bevy::{prelude::*, render::view::RenderLayers};
+use bevy_tileset::prelude::TileIndex;
+use neoroll_world::entity::animal::AnimalId;
+
+use crate::layer::LAYER_SCENE_ITEMS;
+
+#[derive(Component)]
+pub struct AnimalComponent;
+
+#[derive(Resource, Deref, DerefMut, Default)]
+pub struct AnimalsMap(pub HashMap<AnimalId, Entity>);
+
+pub fn animal_color(alpha: f32) -> Color {
+    Color::rgba(0.55, 0.35, 0.15, alpha)
+}
+
+pub fn spawn_animal(
+    atlas: &Handle<TextureAtlas>,
+    tile_index: &TileIndex,
+    point: Vec3,
+    color: Color,
+) -> (AnimalComponent, SpriteSheetBundle, RenderLayers) {
+    // Animated tiles are displayed with their start frame
+    let index = match tile_index {
+        TileIndex::Standard(index) => *index,
+        TileIndex::Animated(start, _end, _speed) => *start,
+    };
+    let mut sprite = TextureAtlasSprite::new(index);
+    sprite.color = color;
+
+    (
+        AnimalComponent,
+        SpriteSheetBundle {
+            transform: Transform {
+                translation: point,
+                ..Default::default()
+            },
+            sprite,
+            texture_atlas: atlas.clone(),
+            ..Default::default()
+        },
+        RenderLayers::layer(LAYER_SCENE_ITEMS),
+    )
+}
@@ -0,0 +1,53 @@
+use bevy::{prelude::*, render::view::visibility::RenderLayers};
+
+use crate::{camera::SceneItemsCamera, layer::LAYER_SCENE_ITEMS, plugins::game::GameStateWrapper};
+
+/// Above all scene items
+const DAYLIGHT_Z: f32 = 10.;
+/// Night tint opacity when there is no daylight at all
+const NIGHT_ALPHA: f32 = 0.6;
+
+#[derive(Component)]
+pub struct DaylightOverlay;
+
+pub fn spawn_daylight(mut commands: Commands) {
+    commands.spawn((
+        DaylightOverlay,
+        SpriteBundle {
+            sprite: Sprite {
+                color: night_color(0.),
+                custom_size: Some(Vec2::new(1., 1.)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0., 0., DAYLIGHT_Z),
+            ..default()
+        },
+        RenderLayers::layer(LAYER_SCENE_ITEMS),
+    ));
+}
+
+/// Cover the camera view with a dark tint depending on day time
+pub fn refresh_daylight(
+    game_state: Res<GameStateWrapper>,
+    windows: Query<&Window>,
+    camera: Query<&Transform, (With<SceneItemsCamera>, Without<DaylightOverlay>)>,
+    mut overlay: Query<(&mut Sprite, &mut Transform), With<DaylightOverlay>>,
+) {
+    if let (Some(game), Ok(camera_transform), Ok(window), Ok((mut sprite, mut transform))) = (
+        game_state.state(),
+        camera.get_single(),
+        windows.get_single(),
+        overlay.get_single_mut(),
+    ) {
+        sprite.color = night_color(1. - game.calendar().daylight());
+        sprite.custom_size = Some(Vec2::new(
+            window.width() * camera_transform.scale.x,
+            window.height() * camera_transform.scale.y,
+        ));
+        transform.translation = camera_transform.translation.truncate().extend(DAYLIGHT_Z);
+    }
+}
+
+fn night_color(darkness: f32) -> Color {
+    Color::rgba(0.0, 0.0, 0.1, darkness * NIGHT_ALPHA)
+}
@@ -3,12 +3,13 @@ use bevy_tileset::prelude::*;
 
 use crate::{
     camera::SceneItemsCamera,
-    graphics::AlphaByScale,
+    graphics::{shade_by_elevation, AlphaByScale},
     plugins::world::{container::WorldPartContainer, region::TileComponent},
     scene::ScenePoint,
 };
 
 use super::{
+    animal::{animal_color, spawn_animal, AnimalComponent, AnimalsMap},
     container::WorldPartContainerRefreshed,
     creature::{
         spawn_creature, CreatureComponent, CreaturesMap, ProgressDone, ProgressMap, ProgressTotal,
@@ -22,10 +23,12 @@ use super::{
 pub fn refresh_world_display(
     world_part: Res<WorldPartContainer>,
     creatures_map: ResMut<CreaturesMap>,
+    animals_map: ResMut<AnimalsMap>,
     camera: Query<(&SceneItemsCamera, &Camera, &mut Transform)>,
     mut world_container_refreshed: EventReader<WorldPartContainerRefreshed>,
     tiles: Query<Entity, With<TileComponent>>,
     creatures: Query<Entity, With<CreatureComponent>>,
+    animals: Query<Entity, With<AnimalComponent>>,
     tilesets: Tilesets,
     commands: Commands,
 ) {
@@ -41,8 +44,10 @@ pub fn refresh_world_display(
             re_spawn_world(
                 world_part,
                 creatures_map,
+                animals_map,
                 tiles,
                 creatures,
+                animals,
                 tileset,
                 commands,
                 camera_transform.scale,
@@ -56,8 +61,10 @@ pub fn refresh_world_display(
 pub fn re_spawn_world(
     world_part: Res<WorldPartContainer>,
     mut creatures_map: ResMut<CreaturesMap>,
+    mut animals_map: ResMut<AnimalsMap>,
     tiles: Query<Entity, With<TileComponent>>,
     creatures: Query<Entity, With<CreatureComponent>>,
+    animals: Query<Entity, With<AnimalComponent>>,
     tileset: &Tileset,
     mut commands: Commands,
     scale: Vec3,
@@ -65,10 +72,12 @@ pub fn re_spawn_world(
     let atlas = tileset.atlas();
     let world_part = world_part.world_part();
 
-    // Creatures mapping will be completely refilled
+    // Creatures and animals mapping will be completely refilled
     creatures_map.clear();
+    animals_map.clear();
     tiles.iter().for_each(|e| commands.entity(e).despawn());
     creatures.iter().for_each(|e| commands.entity(e).despawn());
+    animals.iter().for_each(|e| commands.entity(e).despawn());
 
     let alpha = AlphaByScale::world();
 
@@ -77,19 +86,27 @@ pub fn re_spawn_world(
     }
 
     let color = alpha.color(scale.x);
-    for ((((point, ground), (_, floor)), (_, materials)), (_, structure)) in world_part
-        .grounds()
-        .iter()
-        .zip(world_part.floors().iter())
-        .zip(world_part.materials().iter())
-        .zip(world_part.structures())
+    for (((((point, ground), (_, floor)), (_, materials)), (_, structure)), (_, elevation)) in
+        world_part
+            .grounds()
+            .iter()
+            .zip(world_part.floors().iter())
+            .zip(world_part.materials().iter())
+            .zip(world_part.structures())
+            .zip(world_part.elevations())
     {
         let tiles = LayersResolver.resolve(ground, floor, structure);
         let scene_point = ScenePoint::from_world_point(point);
+        let tile_color = shade_by_elevation(color, elevation);
 
         for tile in tiles {
             if let Some((tile_index, _)) = &tileset.select_tile(&tile.0) {
-                commands.spawn(spawn_tile(atlas, tile_index, scene_point.into(), color));
+                commands.spawn(spawn_tile(
+                    atlas,
+                    tile_index,
+                    scene_point.into(),
+                    tile_color,
+                ));
             }
         }
 
@@ -121,6 +138,23 @@ pub fn re_spawn_world(
                 .id(),
         );
     }
+
+    for animal in world_part.animals().values() {
+        let (animal_tile_index, _) = &tileset.select_tile("Animal").unwrap();
+        let scene_point = ScenePoint::from_world_point(animal.point());
+
+        animals_map.insert(
+            *animal.id(),
+            commands
+                .spawn(spawn_animal(
+                    atlas,
+                    animal_tile_index,
+                    scene_point.into(),
+                    animal_color(color.a()),
+                ))
+                .id(),
+        );
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1,6 +1,6 @@
 use neoroll_world::{
     entity::{floor::Floor, ground::Ground, structure::Structure},
-    gameplay::material::{Material, Resource},
+    gameplay::material::{Crafted, Material, Resource},
 };
 
 use crate::image::Illustration;
@@ -17,6 +17,9 @@ impl IntoIllustration for Structure {
             Structure::FruitTree(_, _) => Some(Illustration::ApricotTree),
             Structure::Campfire => Some(Illustration::Campfire),
             Structure::Storage => None,
+            Structure::Construction(_) => Some(Illustration::Branches),
+            Structure::Sapling(_) => None,
+            Structure::Stump => None,
         }
     }
 }
@@ -24,7 +27,7 @@ impl IntoIllustration for Structure {
 impl IntoIllustration for Ground {
     fn illustration(&self) -> Option<Illustration> {
         match self {
-            Ground::FreshWater => Some(Illustration::FreshWater1),
+            Ground::FreshWater(_) => Some(Illustration::FreshWater1),
             Ground::Soil => Some(Illustration::Soil),
             Ground::SoilFlint(_) => Some(Illustration::FlintQuary),
         }
@@ -37,6 +40,7 @@ impl IntoIllustration for Floor {
             Floor::Nothing => None,
             Floor::ShortGrass => Some(Illustration::ShortAndDryGrass),
             Floor::FruitBush(_) => Some(Illustration::AbuluntuEmpetrumNigrum),
+            Floor::Sown(_) => None,
         }
     }
 }
@@ -48,7 +52,14 @@ impl IntoIllustration for Material {
                 Resource::Food => Some(Illustration::Fruits1),
                 Resource::RawFlint => Some(Illustration::RawFlint1),
                 Resource::Branches => Some(Illustration::Branches),
+                Resource::Fish => None,
+                Resource::Water => Some(Illustration::FreshWater1),
+                Resource::Logs => Some(Illustration::Branches),
             },
+            Material::Crafted(crafted) => match crafted {
+                Crafted::CarvedFlint => Some(Illustration::CurvedFlint1),
+            },
+            Material::Hunted(_) => None,
         }
     }
 }
@@ -1,7 +1,9 @@
 pub mod illustration;
+use animal::AnimalsMap;
 use bevy::prelude::*;
 use container::WorldPartContainerRefreshed;
 use creature::{CreaturesMap, ProgressMap};
+use daylight::{refresh_daylight, spawn_daylight};
 use display::refresh_progress_display;
 
 use self::{
@@ -11,8 +13,10 @@ use self::{
     tileset::WorldTileset,
 };
 
+pub mod animal;
 pub mod container;
 pub mod creature;
+pub mod daylight;
 pub mod display;
 pub mod init;
 pub mod region;
@@ -26,9 +30,11 @@ impl Plugin for WorldDisplayPlugin {
         app.init_resource::<WorldTileset>()
             .init_resource::<WorldPartContainer>()
             .init_resource::<CreaturesMap>()
+            .init_resource::<AnimalsMap>()
             .init_resource::<ProgressMap>()
             .add_event::<WorldPartContainerNeedRefresh>()
             .add_event::<WorldPartContainerRefreshed>()
+            .add_systems(Startup, spawn_daylight)
             .add_systems(
                 Update,
                 (
@@ -36,6 +42,7 @@ impl Plugin for WorldDisplayPlugin {
                     refresh_world_part_container,
                     refresh_world_display,
                     refresh_progress_display,
+                    refresh_daylight,
                 ),
             );
     }
@@ -2,7 +2,7 @@ use bevy::{prelude::*, render::view::RenderLayers};
 use bevy_tileset::prelude::*;
 use neoroll_world::entity::{floor::Floor, ground::Ground, structure::Structure};
 use neoroll_world::gameplay::material::Material as Material_;
-use neoroll_world::gameplay::material::Resource;
+use neoroll_world::gameplay::material::{Crafted, Hunted, Resource};
 
 use crate::{graphics::TileName, layer::LAYER_SCENE_ITEMS, plugins::world::region::TileComponent};
 
@@ -10,7 +10,7 @@ pub const WORLD_TILESET_NAME: &str = "World";
 
 pub fn ground_tile_name(ground: &Ground) -> TileName {
     match ground {
-        Ground::FreshWater => TileName("FreshWater".to_string()),
+        Ground::FreshWater(_) => TileName("FreshWater".to_string()),
         Ground::Soil => TileName("Soil".to_string()),
         Ground::SoilFlint(filled) => match filled.0 {
             0 => TileName("Soil".to_string()),
@@ -30,6 +30,7 @@ pub fn floor_tile_name(ground: &Floor) -> TileName {
             51..=128 => TileName("FruitBush1".to_string()),
             129..=255 => TileName("FruitBush2".to_string()),
         },
+        Floor::Sown(_) => TileName("Sown".to_string()),
     }
 }
 
@@ -38,6 +39,11 @@ pub fn material_tile_name(material: &Material_) -> TileName {
         Material_::Resource(Resource::Food) => TileName("Apple".to_string()),
         Material_::Resource(Resource::RawFlint) => TileName("RawFlint".to_string()),
         Material_::Resource(Resource::Branches) => TileName("Branches".to_string()),
+        Material_::Resource(Resource::Fish) => TileName("Fish".to_string()),
+        Material_::Resource(Resource::Water) => TileName("Water".to_string()),
+        Material_::Resource(Resource::Logs) => TileName("Logs".to_string()),
+        Material_::Crafted(Crafted::CarvedFlint) => TileName("CarvedFlint".to_string()),
+        Material_::Hunted(Hunted::Hide) => TileName("Hide".to_string()),
     }
 }
 
@@ -52,6 +58,9 @@ pub fn structure_tile_name(structure: &Structure) -> TileName {
         },
         Structure::Campfire => TileName("Campfire".to_string()),
         Structure::Storage => TileName("Storage".to_string()),
+        Structure::Construction(_) => TileName("Construction".to_string()),
+        Structure::Sapling(_) => TileName("Sapling".to_string()),
+        Structure::Stump => TileName("Stump".to_string()),
     }
 }
 
@@ -18,7 +18,7 @@ impl BodyTick<ComputeAndSendClientStatesChange> for ComputeAndSendClientStates {
             let client_state = ClientGameStateBuilder::new(state).build(&client_id);
             messages.push(StateChange::Game(GameChange::SendClientGameState(
                 client_id,
-                client_state,
+                Box::new(client_state),
             )))
         }
 
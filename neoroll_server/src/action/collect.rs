@@ -1,18 +1,24 @@
 use neoroll_world::{
-    entity::creature::{CreatureChange, CreatureId},
-    gameplay::{behavior::Behavior, config::IntoCollect, material::Resource, progress::Progress},
+    entity::creature::{Creature, CreatureChange, CreatureId},
+    gameplay::{
+        behavior::Behavior,
+        config::{gameplay, IntoCollect},
+        material::Resource,
+        progress::Progress,
+        skill::Skill,
+    },
     space::world::{FloorChange, GroundChange, StructureChange, WorldChange},
 };
 
 use crate::{
     action::{Action, ActionId, BodyTick, NextTick},
-    run::TICK_BASE_PERIOD,
     state::{FrameI, State, StateChange},
 };
 
 use super::{ActionChange, UpdateAction};
 
-const TICK_PERIOD: u64 = TICK_BASE_PERIOD / 2;
+/// Gathering experience won by collect, multiplied by resource difficulty
+const PRACTICE_EXPERIENCE: u32 = 10;
 
 #[derive(Debug, PartialEq)]
 pub struct CollectResource {
@@ -23,11 +29,27 @@ pub struct CollectResource {
 }
 
 impl CollectResource {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
     fn is_start(&self) -> bool {
         self.start.is_none() || self.end.is_none()
     }
 
+    fn efficiency(&self, creature: &Creature) -> f32 {
+        creature.work_speed(&Skill::Gathering, self.resource.tool())
+    }
+
     fn start(&self, id: ActionId, state: &State) -> Vec<StateChange> {
+        let duration = match state.world().creatures().get(&self.creature_id) {
+            Some(creature) => {
+                (gameplay().ticks.collect * 10 * self.resource.difficulty()) as f32
+                    / self.efficiency(creature)
+            }
+            None => (gameplay().ticks.collect * 10) as f32,
+        };
+
         vec![
             StateChange::Action(
                 id,
@@ -38,8 +60,7 @@ impl CollectResource {
             StateChange::Action(
                 id,
                 ActionChange::Update(UpdateAction::Collect(CollectChange::SetEnd(
-                    // FIXME: collect duration according to resource
-                    *state.frame_i() + TICK_PERIOD * 10,
+                    *state.frame_i() + duration as u64,
                 ))),
             ),
         ]
@@ -56,56 +77,92 @@ impl CollectResource {
             if progress.full() {
                 let world = state.world();
                 let creature = world.creatures().get(&self.creature_id).unwrap();
-                if let Some(structure) = &world.structure(creature.point()) {
+                // Water resources are collected from an adjacent tile
+                let point = world
+                    .collect_point(creature.point(), self.resource.into())
+                    .unwrap_or(*creature.point());
+                if let Some(structure) = &world.structure(&point) {
                     if let Some(material) = structure.material(self.resource.into()) {
-                        let (new_structure, collected_quantity) =
-                            structure.reduced(self.resource.into());
+                        let (new_structure, collected_quantity) = structure.reduced(
+                            self.resource.into(),
+                            &creature.can_carry(&material),
+                            self.efficiency(creature),
+                        );
                         if collected_quantity.0 > 0 {
                             changes.extend(vec![
                                 StateChange::World(WorldChange::Structure(
-                                    *creature.point(),
+                                    point,
                                     StructureChange::Set(Some(new_structure.clone())),
                                 )),
                                 StateChange::World(WorldChange::Creature(
                                     self.creature_id,
                                     CreatureChange::AddToCarrying(material, collected_quantity),
                                 )),
+                                StateChange::World(WorldChange::Creature(
+                                    self.creature_id,
+                                    CreatureChange::Practice(
+                                        Skill::Gathering,
+                                        PRACTICE_EXPERIENCE * self.resource.difficulty() as u32,
+                                    ),
+                                )),
                             ]);
                             return changes;
                         }
                     }
                 }
-                if let Some(floor) = world.floor(creature.point()) {
+                if let Some(floor) = world.floor(&point) {
                     if let Some(material) = floor.material(self.resource.into()) {
-                        let (new_floor, collected_quantity) = floor.reduced(self.resource.into());
+                        let (new_floor, collected_quantity) = floor.reduced(
+                            self.resource.into(),
+                            &creature.can_carry(&material),
+                            self.efficiency(creature),
+                        );
                         if collected_quantity.0 > 0 {
                             changes.extend(vec![
                                 StateChange::World(WorldChange::Floor(
-                                    *creature.point(),
+                                    point,
                                     FloorChange::Set(new_floor.clone()),
                                 )),
                                 StateChange::World(WorldChange::Creature(
                                     self.creature_id,
                                     CreatureChange::AddToCarrying(material, collected_quantity),
                                 )),
+                                StateChange::World(WorldChange::Creature(
+                                    self.creature_id,
+                                    CreatureChange::Practice(
+                                        Skill::Gathering,
+                                        PRACTICE_EXPERIENCE * self.resource.difficulty() as u32,
+                                    ),
+                                )),
                             ]);
                             return changes;
                         }
                     }
                 }
-                if let Some(ground) = world.ground(creature.point()) {
+                if let Some(ground) = world.ground(&point) {
                     if let Some(material) = ground.material(self.resource.into()) {
-                        let (new_ground, collected_quantity) = ground.reduced(self.resource.into());
+                        let (new_ground, collected_quantity) = ground.reduced(
+                            self.resource.into(),
+                            &creature.can_carry(&material),
+                            self.efficiency(creature),
+                        );
                         if collected_quantity.0 > 0 {
                             changes.extend(vec![
                                 StateChange::World(WorldChange::Ground(
-                                    *creature.point(),
+                                    point,
                                     GroundChange::Set(new_ground.clone()),
                                 )),
                                 StateChange::World(WorldChange::Creature(
                                     self.creature_id,
                                     CreatureChange::AddToCarrying(material, collected_quantity),
                                 )),
+                                StateChange::World(WorldChange::Creature(
+                                    self.creature_id,
+                                    CreatureChange::Practice(
+                                        Skill::Gathering,
+                                        PRACTICE_EXPERIENCE * self.resource.difficulty() as u32,
+                                    ),
+                                )),
                             ]);
                             return changes;
                         }
@@ -164,7 +221,10 @@ impl BodyTick<CollectChange> for CollectResource {
             changes.push(StateChange::Action(id, ActionChange::Remove));
         }
 
-        (NextTick(*state.frame_i() + TICK_PERIOD), changes)
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.collect),
+            changes,
+        )
     }
 
     fn apply(&mut self, change: CollectChange) {
@@ -203,3 +263,54 @@ impl CollectBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::{structure::Structure, Filled},
+        gameplay::{
+            job::Job,
+            material::{Material, Resource},
+            tribe::TribeId,
+            Quantity,
+        },
+    };
+    use rstest::*;
+
+    use crate::testing::{self, point};
+
+    use super::*;
+
+    #[rstest]
+    fn test_collect_branches_with_required_tool() {
+        // Given
+        let mut creature = testing::creature(TribeId::new(), point(0, 0));
+        let creature_id = *creature.id();
+        creature.set_job(Job::SearchResource(Resource::Branches));
+        creature
+            .add_to_carrying(Material::Resource(Resource::RawFlint), Quantity::units(1))
+            .unwrap();
+        let mut world = testing::world(1, 1, vec![creature]);
+        world.set_structure(point(0, 0), Some(Structure::BigLeafTree(Filled::full())));
+        let mut state = testing::state(world, Default::default());
+        let collect = CollectResource {
+            creature_id,
+            start: Some(FrameI(0)),
+            end: Some(FrameI(1)),
+            resource: Resource::Branches,
+        };
+        state.increment();
+
+        // When
+        let changes = collect.tick_collect(&state);
+
+        // Then
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StateChange::World(WorldChange::Creature(
+                _,
+                CreatureChange::AddToCarrying(Material::Resource(Resource::Branches), quantity)
+            )) if quantity.0 > 0
+        )));
+    }
+}
@@ -0,0 +1,181 @@
+use neoroll_world::{
+    entity::creature::{CreatureChange, CreatureId},
+    gameplay::{behavior::Behavior, config::gameplay, progress::Progress, skill::Skill},
+    space::{world::WorldChange, AbsoluteWorldPoint},
+};
+
+use crate::{
+    action::{Action, ActionId, BodyTick, NextTick},
+    state::{
+        game::{ConstructionChange, GameChange},
+        FrameI, State, StateChange,
+    },
+};
+
+use super::{ActionChange, UpdateAction};
+
+/// Building experience won by each work step
+const PRACTICE_EXPERIENCE: u32 = 10;
+
+/// One work step of a creature on a construction site
+#[derive(Debug, PartialEq)]
+pub struct Construct {
+    creature_id: CreatureId,
+    point: AbsoluteWorldPoint,
+    start: Option<FrameI>,
+    end: Option<FrameI>,
+}
+
+impl Construct {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
+    fn is_start(&self) -> bool {
+        self.start.is_none() || self.end.is_none()
+    }
+
+    fn start(&self, id: ActionId, state: &State) -> Vec<StateChange> {
+        let speed = state
+            .world()
+            .creatures()
+            .get(&self.creature_id)
+            .map(|c| c.work_speed(&Skill::Building, None))
+            .unwrap_or(1.);
+        let duration = (gameplay().ticks.construct * 10) as f32 / speed;
+
+        vec![
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Construct(ConstructChange::SetStart(
+                    *state.frame_i(),
+                ))),
+            ),
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Construct(ConstructChange::SetEnd(
+                    *state.frame_i() + duration as u64,
+                ))),
+            ),
+        ]
+    }
+
+    fn tick_construct(&self, state: &State) -> Vec<StateChange> {
+        let mut changes = vec![];
+
+        if let Some(progress) = self.progress(state) {
+            changes.push(StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::SetBehavior(Behavior::Build(progress)),
+            )));
+
+            if progress.full() {
+                changes.extend(vec![
+                    StateChange::Game(GameChange::Construction(ConstructionChange::Work(
+                        self.point, 1,
+                    ))),
+                    StateChange::World(WorldChange::Creature(
+                        self.creature_id,
+                        CreatureChange::Practice(Skill::Building, PRACTICE_EXPERIENCE),
+                    )),
+                ]);
+            }
+        }
+
+        changes
+    }
+
+    fn progress(&self, state: &State) -> Option<Progress> {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            let total = end.0 - start.0;
+            let done = state.frame_i().0 - start.0;
+            return Some(Progress::from(done as f32 / total as f32));
+        }
+
+        None
+    }
+
+    fn is_end(&self, state: &State) -> bool {
+        if let Some(progress) = self.progress(state) {
+            return progress.full();
+        }
+        false
+    }
+}
+
+impl BodyTick<ConstructChange> for Construct {
+    fn stamp(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Build(Progress::from(0.))),
+        )]
+    }
+
+    fn take_off(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Idle),
+        )]
+    }
+
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let mut changes = vec![];
+
+        // Construction can have been cancelled meanwhile
+        if state.game().construction(&self.point).is_none() {
+            return (
+                NextTick(*state.frame_i()),
+                vec![StateChange::Action(id, ActionChange::Remove)],
+            );
+        }
+
+        if self.is_start() {
+            changes.extend(self.start(id, state));
+        }
+
+        // Its important to tick_construct before end to execute end progression changes
+        changes.extend(self.tick_construct(state));
+
+        if self.is_end(state) {
+            changes.push(StateChange::Action(id, ActionChange::Remove));
+        }
+
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.construct),
+            changes,
+        )
+    }
+
+    fn apply(&mut self, change: ConstructChange) {
+        match change {
+            ConstructChange::SetStart(start) => self.start = Some(start),
+            ConstructChange::SetEnd(end) => self.end = Some(end),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConstructChange {
+    SetStart(FrameI),
+    SetEnd(FrameI),
+}
+
+pub struct ConstructBuilder {
+    creature_id: CreatureId,
+    point: AbsoluteWorldPoint,
+}
+
+impl ConstructBuilder {
+    pub fn new(creature_id: CreatureId, point: AbsoluteWorldPoint) -> Self {
+        Self { creature_id, point }
+    }
+
+    pub fn build(&self) -> Action {
+        Action::Construct(Construct {
+            creature_id: self.creature_id,
+            point: self.point,
+            start: Default::default(),
+            end: Default::default(),
+        })
+    }
+}
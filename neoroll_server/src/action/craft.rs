@@ -0,0 +1,208 @@
+use neoroll_world::{
+    entity::creature::{CreatureChange, CreatureId},
+    gameplay::{
+        behavior::Behavior,
+        config::gameplay,
+        material::{Crafted, Material},
+        progress::Progress,
+        skill::Skill,
+    },
+    space::world::WorldChange,
+};
+
+use crate::{
+    action::{Action, ActionId, BodyTick, NextTick},
+    state::{game::GameChange, FrameI, State, StateChange},
+};
+
+use super::{ActionChange, UpdateAction};
+
+/// Crafting experience won by each work step
+const PRACTICE_EXPERIENCE: u32 = 20;
+
+/// Transform carried recipe materials into one crafted unit
+#[derive(Debug, PartialEq)]
+pub struct Craft {
+    creature_id: CreatureId,
+    crafted: Crafted,
+    start: Option<FrameI>,
+    end: Option<FrameI>,
+}
+
+impl Craft {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
+    fn is_start(&self) -> bool {
+        self.start.is_none() || self.end.is_none()
+    }
+
+    fn start(&self, id: ActionId, state: &State) -> Vec<StateChange> {
+        let speed = state
+            .world()
+            .creatures()
+            .get(&self.creature_id)
+            .map(|c| c.work_speed(&Skill::Crafting, None))
+            .unwrap_or(1.);
+        let duration = (gameplay().ticks.craft * 10) as f32 / speed;
+
+        vec![
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Craft(CraftChange::SetStart(*state.frame_i()))),
+            ),
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Craft(CraftChange::SetEnd(
+                    *state.frame_i() + duration as u64,
+                ))),
+            ),
+        ]
+    }
+
+    fn tick_craft(&self, state: &State) -> Vec<StateChange> {
+        let mut changes = vec![];
+
+        if let Some(progress) = self.progress(state) {
+            changes.push(StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::SetBehavior(Behavior::Craft(progress)),
+            )));
+
+            if progress.full() {
+                changes.extend(self.produce(state));
+            }
+        }
+
+        changes
+    }
+
+    fn produce(&self, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let creature = world.creatures().get(&self.creature_id).unwrap();
+        let recipe = self.crafted.recipe();
+
+        // Recipe materials can have been dropped meanwhile
+        if recipe
+            .iter()
+            .any(|(material, quantity)| creature.carrying_quantity(*material).0 < quantity.0)
+        {
+            return vec![];
+        }
+
+        let material = Material::Crafted(self.crafted);
+        let mut changes = recipe
+            .into_iter()
+            .map(|(material, quantity)| {
+                StateChange::World(WorldChange::Creature(
+                    self.creature_id,
+                    CreatureChange::RemoveFromCarrying(material, quantity),
+                ))
+            })
+            .collect::<Vec<StateChange>>();
+        changes.extend(vec![
+            StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::AddToCarrying(material, material.quantity(1)),
+            )),
+            StateChange::Game(GameChange::Produced(
+                *creature.tribe_id(),
+                self.crafted,
+                material.quantity(1),
+            )),
+            StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::Practice(Skill::Crafting, PRACTICE_EXPERIENCE),
+            )),
+        ]);
+
+        changes
+    }
+
+    fn progress(&self, state: &State) -> Option<Progress> {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            let total = end.0 - start.0;
+            let done = state.frame_i().0 - start.0;
+            return Some(Progress::from(done as f32 / total as f32));
+        }
+
+        None
+    }
+
+    fn is_end(&self, state: &State) -> bool {
+        if let Some(progress) = self.progress(state) {
+            return progress.full();
+        }
+        false
+    }
+}
+
+impl BodyTick<CraftChange> for Craft {
+    fn stamp(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Craft(Progress::from(0.))),
+        )]
+    }
+
+    fn take_off(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Idle),
+        )]
+    }
+
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let mut changes = vec![];
+
+        if self.is_start() {
+            changes.extend(self.start(id, state));
+        }
+
+        // Its important to tick_craft before end to execute end progression changes
+        changes.extend(self.tick_craft(state));
+
+        if self.is_end(state) {
+            changes.push(StateChange::Action(id, ActionChange::Remove));
+        }
+
+        (NextTick(*state.frame_i() + gameplay().ticks.craft), changes)
+    }
+
+    fn apply(&mut self, change: CraftChange) {
+        match change {
+            CraftChange::SetStart(start) => self.start = Some(start),
+            CraftChange::SetEnd(end) => self.end = Some(end),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CraftChange {
+    SetStart(FrameI),
+    SetEnd(FrameI),
+}
+
+pub struct CraftBuilder {
+    creature_id: CreatureId,
+    crafted: Crafted,
+}
+
+impl CraftBuilder {
+    pub fn new(creature_id: CreatureId, crafted: Crafted) -> Self {
+        Self {
+            creature_id,
+            crafted,
+        }
+    }
+
+    pub fn build(&self) -> Action {
+        Action::Craft(Craft {
+            creature_id: self.creature_id,
+            crafted: self.crafted,
+            start: Default::default(),
+            end: Default::default(),
+        })
+    }
+}
@@ -0,0 +1,350 @@
+use neoroll_world::{
+    gameplay::{
+        material::Material,
+        target::WaitingReason,
+        tribe::{structure::StructureOwn, TribeId},
+        Quantity,
+    },
+    space::{
+        world::{MaterialChange, StructureChange, World, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::state::{
+    game::{GameChange, GameState, WaitingChange},
+    State, StateChange,
+};
+
+use super::{ActionChange, ActionId, BodyTick, NextTick};
+
+/// Maximum distance from demolished structure where its content can be dropped
+const DROP_RADIUS: u32 = 3;
+
+/// Remove an owned structure, refund part of its materials and drop its
+/// content (like storage stocks) around
+#[derive(Debug, PartialEq)]
+pub struct Demolish {
+    tribe_id: TribeId,
+    point: AbsoluteWorldPoint,
+}
+
+impl Demolish {
+    pub fn new(tribe_id: TribeId, point: AbsoluteWorldPoint) -> Self {
+        Self { tribe_id, point }
+    }
+
+    fn demolish(&self, state: &State) -> Vec<StateChange> {
+        let game = state.game();
+        let world = state.world();
+        let mut changes = vec![];
+
+        let own = match game.structure_own(&self.point) {
+            Some(own) if own.tribe_id() == &self.tribe_id => own,
+            _ => return changes,
+        };
+        if let Err(error) = check(&world, &game, &self.tribe_id, &self.point) {
+            eprintln!("Can't demolish {:?}: {:?}", self.point, error);
+            return changes;
+        }
+
+        // Structure content is dropped around to let place to refund
+        let content = world
+            .materials_on(&self.point, None)
+            .iter()
+            .map(|(m, q)| (*m, q.clone()))
+            .collect::<Vec<(Material, Quantity)>>();
+        let around = drop_places(&world, &self.point);
+        for ((material, quantity), point) in content.into_iter().zip(around.iter().cycle()) {
+            changes.extend(vec![
+                StateChange::World(WorldChange::Material(
+                    self.point,
+                    MaterialChange::Remove(material, quantity.clone()),
+                )),
+                StateChange::World(WorldChange::Material(
+                    *point,
+                    MaterialChange::Add(material, quantity),
+                )),
+            ]);
+        }
+
+        if let Some(buildable) = own.type_().buildable() {
+            for (material, quantity) in buildable.refund() {
+                changes.push(StateChange::World(WorldChange::Material(
+                    self.point,
+                    MaterialChange::Add(material, quantity),
+                )));
+            }
+        }
+
+        changes.push(StateChange::World(WorldChange::Structure(
+            self.point,
+            StructureChange::Set(None),
+        )));
+
+        changes.extend(self.cancel_transports(state));
+        changes.extend(self.free_place_waitings(&game));
+
+        for client_id in game.client_ids() {
+            if game.client_tribe_id(&client_id) == Some(&self.tribe_id) {
+                changes.push(StateChange::Game(
+                    GameChange::ImmediateClientGameStateRefresh(client_id),
+                ));
+            }
+        }
+
+        changes
+    }
+
+    /// Creatures going to bring or take materials from demolished structure stop
+    fn cancel_transports(&self, state: &State) -> Vec<StateChange> {
+        state
+            .transport_actions(&self.point)
+            .into_iter()
+            .map(|id| StateChange::Action(id, ActionChange::Remove))
+            .collect()
+    }
+
+    /// Demolished structure let place, so targets waiting for a place to build
+    /// can try again (other waitings are not affected by the removal)
+    fn free_place_waitings(&self, game: &GameState) -> Vec<StateChange> {
+        game.tribe_waitings()
+            .get(&self.tribe_id)
+            .map(|waitings| {
+                waitings
+                    .iter()
+                    .filter(|(_, reasons)| {
+                        reasons
+                            .iter()
+                            .any(|r| matches!(r, WaitingReason::NoPlaceToBuild(_)))
+                    })
+                    .map(|(target_id, reasons)| {
+                        let reasons = reasons
+                            .iter()
+                            .filter(|r| !matches!(r, WaitingReason::NoPlaceToBuild(_)))
+                            .cloned()
+                            .collect();
+                        StateChange::Game(GameChange::Waiting(
+                            self.tribe_id,
+                            WaitingChange::Set(*target_id, reasons),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DemolishError {
+    NotOwned,
+    /// Structure content can't be dropped around it
+    NoPlaceToDrop,
+}
+
+/// Check if given tribe can demolish structure at given point
+pub fn check(
+    world: &World,
+    game: &GameState,
+    tribe_id: &TribeId,
+    point: &AbsoluteWorldPoint,
+) -> Result<(), DemolishError> {
+    if game.structure_own(point).map(StructureOwn::tribe_id) != Some(tribe_id) {
+        return Err(DemolishError::NotOwned);
+    }
+    if !world.materials_on(point, None).is_empty() && drop_places(world, point).is_empty() {
+        return Err(DemolishError::NoPlaceToDrop);
+    }
+
+    Ok(())
+}
+
+/// Walkable tiles without structure around given point, nearest first
+fn drop_places(world: &World, point: &AbsoluteWorldPoint) -> Vec<AbsoluteWorldPoint> {
+    let mut places = point
+        .around(DROP_RADIUS)
+        .into_iter()
+        .filter(|place| place != point && world.can_walk(place) && world.structure(place).is_none())
+        .collect::<Vec<AbsoluteWorldPoint>>();
+    places.sort_by_key(|place| place.squared_distance(point));
+    places
+}
+
+impl BodyTick<DemolishChange> for Demolish {
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let mut changes = self.demolish(state);
+        changes.push(StateChange::Action(id, ActionChange::Remove));
+        (NextTick(*state.frame_i()), changes)
+    }
+
+    fn apply(&mut self, _change: DemolishChange) {}
+}
+
+#[derive(Debug)]
+pub enum DemolishChange {}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::structure::Structure,
+        gameplay::{build::Buildable, material::Resource, target::TargetId},
+    };
+    use rstest::*;
+
+    use crate::{
+        action::{drop::DropOff, Action},
+        testing::{self, apply, creature, own, point},
+    };
+
+    use super::*;
+
+    fn food() -> Material {
+        Material::Resource(Resource::Food)
+    }
+
+    /// World with a tribe storage containing given food quantity at its center
+    fn storage_world(size: usize, food_: u64) -> (World, GameState, TribeId, AbsoluteWorldPoint) {
+        let tribe_id = TribeId::new();
+        let center = point(size as isize / 2, size as isize / 2);
+        let mut world = testing::world(size, size, vec![]);
+        let mut game = GameState::default();
+        own(&mut world, &mut game, tribe_id, Structure::Storage, center);
+        if food_ > 0 {
+            world
+                .add_material(center, food(), food().quantity(food_))
+                .unwrap();
+        }
+
+        (world, game, tribe_id, center)
+    }
+
+    fn state(size: usize, food_: u64) -> (State, TribeId, AbsoluteWorldPoint) {
+        let (world, game, tribe_id, center) = storage_world(size, food_);
+        (testing::state(world, game), tribe_id, center)
+    }
+
+    #[rstest]
+    fn test_demolish_refund_and_drop_content() {
+        // Given
+        let (mut state, tribe_id, center) = state(5, 10);
+
+        // When
+        let changes = Demolish::new(tribe_id, center).demolish(&state);
+        apply(&mut state, changes);
+
+        // Then
+        let world = state.world();
+        assert_eq!(world.structure(&center), &None);
+        assert!(state.game().structure_own(&center).is_none());
+        let refund = Buildable::Storage.refund();
+        let on_center = world
+            .materials_on(&center, None)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<(Material, Quantity)>>();
+        assert_eq!(on_center, refund);
+        let dropped = center
+            .around(DROP_RADIUS)
+            .iter()
+            .filter(|point| point != &&center)
+            .map(|point| world.material_quantity(point, food()).0)
+            .sum::<u64>();
+        assert_eq!(dropped, 10);
+    }
+
+    #[rstest]
+    fn test_demolish_refused_without_place_to_drop() {
+        // Given
+        let (state, tribe_id, center) = state(1, 10);
+
+        // When
+        let checked = check(&state.world(), &state.game(), &tribe_id, &center);
+        let changes = Demolish::new(tribe_id, center).demolish(&state);
+
+        // Then
+        assert_eq!(checked, Err(DemolishError::NoPlaceToDrop));
+        assert!(changes.is_empty());
+    }
+
+    #[rstest]
+    fn test_demolish_empty_structure_without_place_to_drop() {
+        // Given
+        let (state, tribe_id, center) = state(1, 0);
+
+        // When
+        let checked = check(&state.world(), &state.game(), &tribe_id, &center);
+
+        // Then
+        assert_eq!(checked, Ok(()));
+    }
+
+    #[rstest]
+    fn test_demolish_only_free_place_waitings() {
+        // Given
+        let (world, mut game, tribe_id, center) = storage_world(5, 0);
+        let (place, other) = (TargetId::new(), TargetId::new());
+        game.set_waitings(
+            &tribe_id,
+            &place,
+            vec![
+                WaitingReason::NoPlaceToBuild(Buildable::Storage),
+                WaitingReason::NotEnoughWorker,
+            ],
+        );
+        game.set_waitings(
+            &tribe_id,
+            &other,
+            vec![WaitingReason::NotEnoughMaterial(food())],
+        );
+        let mut state = testing::state(world, game);
+
+        // When
+        let changes = Demolish::new(tribe_id, center).demolish(&state);
+        apply(&mut state, changes);
+
+        // Then
+        let game = state.game();
+        let waitings = game.tribe_waitings().get(&tribe_id).unwrap();
+        assert_eq!(waitings[&place], vec![WaitingReason::NotEnoughWorker]);
+        assert_eq!(
+            waitings[&other],
+            vec![WaitingReason::NotEnoughMaterial(food())]
+        );
+    }
+
+    #[rstest]
+    fn test_demolish_cancel_transports_to_structure() {
+        // Given
+        let (mut world, game, tribe_id, center) = storage_world(5, 0);
+        let creature = creature(tribe_id, point(0, 0));
+        let creature_id = *creature.id();
+        world.add_creature(creature);
+        let mut state = testing::state(world, game);
+        let (to_center, elsewhere) = (ActionId::new(), ActionId::new());
+        apply(
+            &mut state,
+            vec![
+                StateChange::Action(
+                    to_center,
+                    ActionChange::New(Action::DropOff(DropOff::new(creature_id, center, food()))),
+                ),
+                StateChange::Action(
+                    elsewhere,
+                    ActionChange::New(Action::DropOff(DropOff::new(
+                        creature_id,
+                        point(4, 4),
+                        food(),
+                    ))),
+                ),
+            ],
+        );
+
+        // When
+        let changes = Demolish::new(tribe_id, center).demolish(&state);
+        apply(&mut state, changes);
+
+        // Then
+        let actions = state.creature_actions(&creature_id);
+        assert_eq!(actions, vec![elsewhere]);
+    }
+}
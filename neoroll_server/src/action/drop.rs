@@ -7,14 +7,12 @@ use neoroll_world::{
     },
 };
 
-use crate::{
-    run::TICK_BASE_PERIOD,
-    state::{State, StateChange},
-};
-
-use super::{ActionChange, ActionId, BodyTick, NextTick, UpdateAction};
+use crate::state::{State, StateChange};
 
-const TICK_FREQUENCY: u64 = TICK_BASE_PERIOD;
+use super::{
+    move_::{walk, Walk},
+    ActionChange, ActionId, BodyTick, NextTick, UpdateAction,
+};
 
 #[derive(Debug, PartialEq)]
 pub struct DropOff {
@@ -25,6 +23,15 @@ pub struct DropOff {
 }
 
 impl DropOff {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
+    /// Storage where creature goes
+    pub fn point(&self) -> &AbsoluteWorldPoint {
+        &self.point
+    }
+
     pub fn new(creature_id: CreatureId, point: AbsoluteWorldPoint, material: Material) -> Self {
         Self {
             creature_id,
@@ -34,13 +41,31 @@ impl DropOff {
         }
     }
 
-    pub fn find_path(&self, state: &State) -> Option<Vec<AbsoluteWorldPoint>> {
+    fn drop(&self, id: ActionId, state: &State) -> Vec<StateChange> {
         let world = state.world();
         let creature = world.creatures().get(&self.creature_id).unwrap();
-        state
-            .world()
-            .find_path(creature.point(), &self.point)
-            .map(|p| p.0)
+        let carrying = creature.carrying_quantity(self.material);
+        // Storages refuse what overflow their capacity
+        let quantity = match world.storage_room(creature.point(), &self.material) {
+            Some(room) => self.material.quantity(carrying.0.min(room.0)),
+            None => carrying,
+        };
+        let mut changes = vec![];
+        if quantity.0 > 0 {
+            changes.extend(vec![
+                StateChange::World(WorldChange::Material(
+                    *creature.point(),
+                    MaterialChange::Add(self.material, quantity.clone()),
+                )),
+                StateChange::World(WorldChange::Creature(
+                    self.creature_id,
+                    CreatureChange::RemoveFromCarrying(self.material, quantity),
+                )),
+            ]);
+        }
+        changes.push(StateChange::Action(id, ActionChange::Remove));
+
+        changes
     }
 }
 
@@ -59,82 +84,18 @@ impl BodyTick<DropOffChange> for DropOff {
         )]
     }
     fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
-        if let Some(path) = &self.path {
-            if let Some(try_point) = path.iter().next() {
-                let mut meta = state.meta_mut();
-                let world = state.world();
-
-                if world.can_walk(try_point) {
-                    if let Some(next_point) = meta.book(try_point) {
-                        let new_path = path[1..].to_vec();
-                        (
-                            NextTick(*state.frame_i() + TICK_FREQUENCY),
-                            vec![
-                                StateChange::World(WorldChange::Creature(
-                                    self.creature_id,
-                                    CreatureChange::SetPoint(next_point),
-                                )),
-                                StateChange::Action(
-                                    id,
-                                    ActionChange::Update(UpdateAction::DropOff(
-                                        DropOffChange::SetPath(Some(new_path)),
-                                    )),
-                                ),
-                            ],
-                        )
-                    } else {
-                        // Place is busy, wait next tick
-                        (NextTick(*state.frame_i() + TICK_FREQUENCY), vec![])
-                    }
-                } else {
-                    // Path seems corrupted, try another one
-                    (
-                        NextTick(*state.frame_i() + TICK_FREQUENCY),
-                        vec![StateChange::Action(
-                            id,
-                            ActionChange::Update(UpdateAction::DropOff(DropOffChange::SetPath(
-                                None,
-                            ))),
-                        )],
-                    )
-                }
-            } else {
-                // Drop + remove this action
-                let world = state.world();
-                let creature = world.creatures().get(&self.creature_id).unwrap();
-                let quantity = creature.carrying_quantity(Some(self.material));
-                (
-                    NextTick(*state.frame_i()),
-                    vec![
-                        StateChange::World(WorldChange::Material(
-                            *creature.point(),
-                            MaterialChange::Add(self.material, quantity.clone()),
-                        )),
-                        StateChange::World(WorldChange::Creature(
-                            self.creature_id,
-                            CreatureChange::RemoveFromCarrying(self.material, quantity),
-                        )),
-                        StateChange::Action(id, ActionChange::Remove),
-                    ],
-                )
-            }
-
-        // If path found, use it at next step
-        } else if let Some(path) = self.find_path(state) {
-            (
-                NextTick(*state.frame_i() + TICK_FREQUENCY),
-                vec![StateChange::Action(
-                    id,
-                    ActionChange::Update(UpdateAction::DropOff(DropOffChange::SetPath(Some(path)))),
-                )],
-            )
-
-        // If path cant be find, cancel this action
-        } else {
-            (
-                NextTick(*state.frame_i()),
-                vec![StateChange::Action(id, ActionChange::Remove)],
-            )
+        let set_path = |path| UpdateAction::DropOff(DropOffChange::SetPath(path));
+        match walk(
+            id,
+            state,
+            &self.creature_id,
+            &self.point,
+            &self.path,
+            set_path,
+        ) {
+            Walk::Step(next, changes) => (next, changes),
+            // Drop + remove this action
+            Walk::Arrived => (NextTick(*state.frame_i()), self.drop(id, state)),
         }
     }
 
@@ -0,0 +1,216 @@
+use neoroll_world::{
+    entity::{
+        animal::{AnimalChange, AnimalId},
+        creature::{CreatureChange, CreatureId},
+    },
+    gameplay::{
+        behavior::Behavior,
+        config::gameplay,
+        material::{Crafted, Material},
+        progress::Progress,
+        skill::Skill,
+    },
+    space::world::{MaterialChange, WorldChange},
+};
+
+use crate::{
+    action::{Action, ActionId, BodyTick, NextTick},
+    state::{FrameI, State, StateChange},
+};
+
+use super::{ActionChange, UpdateAction};
+
+/// Hunting experience won by each killed animal
+const PRACTICE_EXPERIENCE: u32 = 30;
+/// Weapon making the kill faster
+const TOOL: Material = Material::Crafted(Crafted::CarvedFlint);
+
+/// Kill an adjacent animal. Animal yields are carried, or dropped on the
+/// ground when they can't be carried.
+#[derive(Debug, PartialEq)]
+pub struct Hunt {
+    creature_id: CreatureId,
+    animal_id: AnimalId,
+    start: Option<FrameI>,
+    end: Option<FrameI>,
+}
+
+impl Hunt {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
+    fn is_start(&self) -> bool {
+        self.start.is_none() || self.end.is_none()
+    }
+
+    fn start(&self, id: ActionId, state: &State) -> Vec<StateChange> {
+        let speed = state
+            .world()
+            .creatures()
+            .get(&self.creature_id)
+            .map(|c| c.work_speed(&Skill::Hunting, Some(TOOL)))
+            .unwrap_or(1.);
+        let duration = (gameplay().ticks.hunt * 10) as f32 / speed;
+
+        vec![
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Hunt(HuntChange::SetStart(*state.frame_i()))),
+            ),
+            StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Hunt(HuntChange::SetEnd(
+                    *state.frame_i() + duration as u64,
+                ))),
+            ),
+        ]
+    }
+
+    fn tick_hunt(&self, state: &State) -> Vec<StateChange> {
+        let mut changes = vec![];
+
+        if let Some(progress) = self.progress(state) {
+            changes.push(StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::SetBehavior(Behavior::Hunt(progress)),
+            )));
+
+            if progress.full() {
+                changes.extend(self.kill(state));
+            }
+        }
+
+        changes
+    }
+
+    fn kill(&self, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let creature = world.creatures().get(&self.creature_id).unwrap();
+        let animal = match world.animals().get(&self.animal_id) {
+            Some(animal) => animal,
+            None => return vec![],
+        };
+
+        // Animal can have fled meanwhile
+        if animal.point().squared_distance(creature.point()) > 2 {
+            return vec![];
+        }
+
+        let mut changes = vec![
+            StateChange::World(WorldChange::Animal(self.animal_id, AnimalChange::Remove)),
+            StateChange::World(WorldChange::Creature(
+                self.creature_id,
+                CreatureChange::Practice(Skill::Hunting, PRACTICE_EXPERIENCE),
+            )),
+        ];
+
+        // Carrying capacity is consumed by each yield, so compute it on a copy
+        let mut creature = creature.clone();
+        for (material, quantity) in animal.species().yields() {
+            let carried = material.quantity(quantity.0.min(creature.can_carry(&material).0));
+            let dropped = material.quantity(quantity.0 - carried.0);
+
+            if carried.0 > 0 && creature.add_to_carrying(material, carried.clone()).is_ok() {
+                changes.push(StateChange::World(WorldChange::Creature(
+                    self.creature_id,
+                    CreatureChange::AddToCarrying(material, carried),
+                )));
+            }
+            if dropped.0 > 0 {
+                changes.push(StateChange::World(WorldChange::Material(
+                    *creature.point(),
+                    MaterialChange::Add(material, dropped),
+                )));
+            }
+        }
+
+        changes
+    }
+
+    fn progress(&self, state: &State) -> Option<Progress> {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            let total = end.0 - start.0;
+            let done = state.frame_i().0 - start.0;
+            return Some(Progress::from(done as f32 / total as f32));
+        }
+
+        None
+    }
+
+    fn is_end(&self, state: &State) -> bool {
+        if let Some(progress) = self.progress(state) {
+            return progress.full();
+        }
+        false
+    }
+}
+
+impl BodyTick<HuntChange> for Hunt {
+    fn stamp(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Hunt(Progress::from(0.))),
+        )]
+    }
+
+    fn take_off(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Idle),
+        )]
+    }
+
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let mut changes = vec![];
+
+        if self.is_start() {
+            changes.extend(self.start(id, state));
+        }
+
+        // Its important to tick_hunt before end to execute end progression changes
+        changes.extend(self.tick_hunt(state));
+
+        if self.is_end(state) {
+            changes.push(StateChange::Action(id, ActionChange::Remove));
+        }
+
+        (NextTick(*state.frame_i() + gameplay().ticks.hunt), changes)
+    }
+
+    fn apply(&mut self, change: HuntChange) {
+        match change {
+            HuntChange::SetStart(start) => self.start = Some(start),
+            HuntChange::SetEnd(end) => self.end = Some(end),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum HuntChange {
+    SetStart(FrameI),
+    SetEnd(FrameI),
+}
+
+pub struct HuntBuilder {
+    creature_id: CreatureId,
+    animal_id: AnimalId,
+}
+
+impl HuntBuilder {
+    pub fn new(creature_id: CreatureId, animal_id: AnimalId) -> Self {
+        Self {
+            creature_id,
+            animal_id,
+        }
+    }
+
+    pub fn build(&self) -> Action {
+        Action::Hunt(Hunt {
+            creature_id: self.creature_id,
+            animal_id: self.animal_id,
+            start: Default::default(),
+            end: Default::default(),
+        })
+    }
+}
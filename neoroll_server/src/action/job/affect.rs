@@ -1,113 +1,492 @@
+use std::cmp::Reverse;
+
 use neoroll_world::{
-    entity::{creature::CreatureChange, structure::Structure},
+    entity::{
+        creature::{CreatureChange, CreatureId},
+        structure::Structure,
+    },
     gameplay::{
-        job::Job,
+        build::{construction::Construction, Buildable, TryBuild},
+        config::gameplay,
+        job::{requirement::JobRequirement, Job},
         target::{ComputedTarget, Target, WaitingReason},
-        tribe::TribeId,
+        tribe::{migration::Migration, TribeId},
+    },
+    space::{
+        world::{StructureChange, WorldChange},
+        AbsoluteWorldPoint,
     },
-    space::world::WorldChange,
 };
 
 use crate::{
-    action::{Action, ActionId, BodyTick, NextTick},
-    run::TICK_BASE_PERIOD,
+    action::{demolish::Demolish, Action, ActionChange, ActionId, BodyTick, NextTick},
     state::{
-        game::{GameChange, WaitingChange},
+        game::{ConstructionChange, GameChange, MigrationChange, WaitingChange},
         State, StateChange,
     },
+    storage::StorageFinder,
     utils::CreaturesJobUtils,
 };
 
-const TICK_FREQUENCY: u64 = TICK_BASE_PERIOD * 5;
+use super::realize::migrate::left_stocks;
+
+/// Maximum distance from campfire of construction sites placed for targets
+const BUILD_RADIUS: u32 = 10;
 
 #[derive(Debug, PartialEq)]
 pub struct AffectJob {
     tribe_id: TribeId,
 }
 impl AffectJob {
-    fn solve_target(&self, state: &State, target: &ComputedTarget) -> Vec<StateChange> {
+    /// Ensure each tribe construction have a builder. Constructions take idle
+    /// creatures first, then creatures working for targets. Return affected
+    /// creature ids with changes.
+    fn solve_constructions(&self, state: &State) -> (Vec<CreatureId>, Vec<StateChange>) {
+        let game = state.game();
+        let world = state.world();
+        let creatures = world.tribe_creatures(&self.tribe_id);
+        let mut affected = vec![];
         let mut changes = vec![];
 
-        if target.covered() {
-            return self.disband_target_worker(target, state);
+        for construction in game.tribe_constructions(&self.tribe_id) {
+            let job = Job::Build(*construction.point());
+            if !creatures.filter_job(&job).is_empty() {
+                continue;
+            }
+
+            let available = creatures
+                .iter()
+                .filter(|c| {
+                    !affected.contains(c.id())
+                        && !matches!(c.job(), Job::Build(_))
+                        && game.order(c.id()).is_none()
+                        && c.age().is_adult()
+                })
+                .collect::<Vec<_>>();
+            let builder = available
+                .iter()
+                .find(|c| c.job() == &Job::Idle)
+                .or(available.first());
+
+            if let Some(builder) = builder {
+                affected.push(*builder.id());
+                changes.extend(vec![
+                    StateChange::World(WorldChange::Creature(
+                        *builder.id(),
+                        CreatureChange::SetJob(job),
+                    )),
+                    StateChange::Game(GameChange::WorkerTarget(*builder.id(), None)),
+                ]);
+            }
         }
 
-        if let Some(waitings) = self.global_waitings(state, target.target()) {
-            return vec![StateChange::Game(GameChange::Waiting(
+        (affected, changes)
+    }
+
+    /// While tribe is migrating, place new home storages (as many as left ones)
+    /// and affect available creatures to stocks transport. Migration ends when
+    /// new campfire and storages are built and they can't receive more of left
+    /// stocks. Return None if tribe is not migrating.
+    fn solve_migration(&self, state: &State, reserved: &[CreatureId]) -> Option<Vec<StateChange>> {
+        let game = state.game();
+        let world = state.world();
+        let migration = game.migration(&self.tribe_id)?.clone();
+        let mut changes = vec![];
+
+        let left_storages = game
+            .tribe_structures(&self.tribe_id, Some(Structure::Storage))
+            .iter()
+            .filter(|own| migration.is_left(own.point()))
+            .count();
+        let new_storages = game
+            .tribe_structures(&self.tribe_id, Some(Structure::Storage))
+            .iter()
+            .filter(|own| !migration.is_left(own.point()))
+            .count();
+        let settled = game
+            .structure_own(migration.to())
+            .map(|own| own.type_() == &Structure::Campfire)
+            .unwrap_or(false);
+
+        if settled && new_storages >= left_storages {
+            let finder = StorageFinder::new(state, self.tribe_id);
+            let receivable = left_stocks(state, &self.tribe_id)
+                .iter()
+                .any(|(point, material)| !finder.drop_off(point, material).is_empty());
+            if !receivable {
+                return Some(self.end_migration(&migration));
+            }
+        }
+
+        let constructing = game
+            .tribe_constructions(&self.tribe_id)
+            .iter()
+            .filter(|c| c.buildable() == &Buildable::Storage)
+            .count();
+        if new_storages + constructing < left_storages {
+            if let Some(point) = self.find_build_place(state, &Buildable::Storage) {
+                changes.extend(vec![
+                    StateChange::Game(GameChange::Construction(ConstructionChange::New(
+                        Construction::new(Buildable::Storage, self.tribe_id, point),
+                    ))),
+                    StateChange::World(WorldChange::Structure(
+                        point,
+                        StructureChange::Set(Some(Structure::Construction(Buildable::Storage))),
+                    )),
+                ]);
+            }
+        }
+
+        for creature in world.tribe_creatures(&self.tribe_id) {
+            if !reserved.contains(creature.id())
+                && !matches!(creature.job(), Job::Build(_) | Job::Migrate)
+                && game.order(creature.id()).is_none()
+                && creature.age().is_adult()
+            {
+                changes.extend(vec![
+                    StateChange::World(WorldChange::Creature(
+                        *creature.id(),
+                        CreatureChange::SetJob(Job::Migrate),
+                    )),
+                    StateChange::Game(GameChange::WorkerTarget(*creature.id(), None)),
+                ]);
+            }
+        }
+
+        Some(changes)
+    }
+
+    /// Dismantle left structures (remaining content is dropped around them)
+    fn end_migration(&self, migration: &Migration) -> Vec<StateChange> {
+        let mut changes = migration
+            .left()
+            .iter()
+            .map(|point| {
+                StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::Demolish(Demolish::new(self.tribe_id, *point))),
+                )
+            })
+            .collect::<Vec<StateChange>>();
+        changes.push(StateChange::Game(GameChange::Migration(
+            self.tribe_id,
+            MigrationChange::End,
+        )));
+
+        changes
+    }
+
+    /// Solve each tribe target by priority and urgency order. Targets which
+    /// need workers share available creatures (idle ones and ones already
+    /// working for a target) proportionally to their rank and urgency.
+    fn solve_targets(&self, state: &State, reserved: &[CreatureId]) -> Vec<StateChange> {
+        let game = state.game();
+        let world = state.world();
+        let mut changes = vec![];
+
+        let targets = allocation_order(
+            game.tribe_targets()
+                .get(&self.tribe_id)
+                .map(|targets| targets.iter().collect::<Vec<&ComputedTarget>>())
+                .unwrap_or_default(),
+        );
+
+        // Targets which need workers, in allocation order
+        let mut working: Vec<(&ComputedTarget, Job)> = vec![];
+        let knowledge = game.knowledge(&self.tribe_id);
+        for target in &targets {
+            if !knowledge.can_target(target.target()) {
+                changes.push(StateChange::Game(GameChange::Waiting(
+                    self.tribe_id,
+                    WaitingChange::Set(*target.id(), vec![WaitingReason::NotDiscovered]),
+                )));
+                continue;
+            }
+
+            if target.covered() {
+                changes.push(StateChange::Game(GameChange::Waiting(
+                    self.tribe_id,
+                    WaitingChange::Set(*target.id(), vec![]),
+                )));
+                continue;
+            }
+
+            // Workers can't do anything useful for this target (like after storage demolition)
+            if let Some(waitings) = self.global_waitings(state, target.target()) {
+                changes.push(StateChange::Game(GameChange::Waiting(
+                    self.tribe_id,
+                    WaitingChange::Set(*target.id(), waitings),
+                )));
+                continue;
+            }
+
+            match target.target().job() {
+                Some(job) => working.push((target, job)),
+                // Targets without job are realized through construction sites
+                None => changes.extend(self.solve_construction_target(state, target)),
+            }
+        }
+
+        // Creatures which can be (re)affected: all except builders (which are
+        // managed by construction sites), creatures following an order and children
+        let available = world
+            .tribe_creatures(&self.tribe_id)
+            .into_iter()
+            .filter(|c| {
+                !reserved.contains(c.id())
+                    && !matches!(c.job(), Job::Build(_))
+                    && game.order(c.id()).is_none()
+                    && c.age().is_adult()
+            })
+            .collect::<Vec<_>>();
+
+        let mut shares = Share::from_targets(
+            &working
+                .iter()
+                .map(|(target, _)| *target)
+                .collect::<Vec<&ComputedTarget>>(),
+        );
+        // Workers can't be more than ones able to satisfy job requirements
+        for (share, (_, job)) in shares.iter_mut().zip(&working) {
+            if let Some(equippable) = self.equippable(state, job) {
+                share.max = Some(share.max.unwrap_or(usize::MAX).min(equippable));
+            }
+        }
+        let desired = distribute(available.len(), &shares);
+
+        // Keep creatures on their current target (several targets can share
+        // a same job) while it is wanted
+        let mut kept = vec![0; working.len()];
+        let mut free = vec![];
+        for creature in &available {
+            let target_id = game.worker_target(creature.id());
+            match working
+                .iter()
+                .position(|(target, job)| Some(target.id()) == target_id && job == creature.job())
+            {
+                Some(i) if kept[i] < desired[i] => kept[i] += 1,
+                _ => free.push(*creature),
+            }
+        }
+        // Idle creatures are affected first, to interrupt as few works as possible
+        free.sort_by_key(|c| c.job() != &Job::Idle);
+
+        let mut free = free.into_iter();
+        for (i, (target, job)) in working.iter().enumerate() {
+            for _ in kept[i]..desired[i] {
+                if let Some(creature) = free.next() {
+                    changes.extend(vec![
+                        StateChange::World(WorldChange::Creature(
+                            *creature.id(),
+                            CreatureChange::SetJob(job.clone()),
+                        )),
+                        StateChange::Game(GameChange::WorkerTarget(
+                            *creature.id(),
+                            Some(*target.id()),
+                        )),
+                    ]);
+                }
+            }
+
+            let waitings = if desired[i] < shares[i].minimum() {
+                vec![WaitingReason::NotEnoughWorker]
+            } else {
+                vec![]
+            };
+            changes.push(StateChange::Game(GameChange::Waiting(
                 self.tribe_id,
                 WaitingChange::Set(*target.id(), waitings),
-            ))];
+            )));
         }
 
-        let world = state.world();
-        let creatures = world.tribe_creatures(&self.tribe_id);
-        let idles = creatures.filter_job(&Job::Idle);
+        // Remaining creatures are not required by any target
+        for creature in free {
+            if creature.job() != &Job::Idle {
+                changes.push(StateChange::World(WorldChange::Creature(
+                    *creature.id(),
+                    CreatureChange::SetJob(Job::Idle),
+                )));
+            }
+            if game.worker_target(creature.id()).is_some() {
+                changes.push(StateChange::Game(GameChange::WorkerTarget(
+                    *creature.id(),
+                    None,
+                )));
+            }
+        }
 
-        if idles.is_empty() {
+        changes
+    }
+
+    /// Place a new construction site if target count is not reached by built
+    /// structures and current construction sites
+    fn solve_construction_target(
+        &self,
+        state: &State,
+        target: &ComputedTarget,
+    ) -> Vec<StateChange> {
+        let (buildable, count) = match target.target() {
+            Target::KeepBuilt(buildable, count) => (*buildable, *count),
+            _ => return vec![],
+        };
+
+        let game = state.game();
+        let built = game
+            .tribe_structures(&self.tribe_id, Some(buildable.into()))
+            .len();
+        let constructing = game
+            .tribe_constructions(&self.tribe_id)
+            .iter()
+            .filter(|c| c.buildable() == &buildable)
+            .count();
+        if built + constructing >= count {
+            // Builders of current construction sites set their own waitings
+            if constructing > 0 {
+                return vec![];
+            }
             return vec![StateChange::Game(GameChange::Waiting(
                 self.tribe_id,
-                WaitingChange::Set(*target.id(), vec![WaitingReason::NotEnoughWorker]),
+                WaitingChange::Set(*target.id(), vec![]),
             ))];
         }
 
-        // Try to affect
-        let job = Job::from(target.target());
-        for human in world.tribe_creatures(&self.tribe_id).filter_job(&Job::Idle) {
-            changes.extend(vec![StateChange::World(WorldChange::Creature(
-                *human.id(),
-                CreatureChange::SetJob(job.clone()),
-            ))])
+        match self.find_build_place(state, &buildable) {
+            Some(point) => vec![
+                StateChange::Game(GameChange::Waiting(
+                    self.tribe_id,
+                    WaitingChange::Set(*target.id(), vec![]),
+                )),
+                StateChange::Game(GameChange::Construction(ConstructionChange::New(
+                    Construction::new(buildable, self.tribe_id, point),
+                ))),
+                StateChange::World(WorldChange::Structure(
+                    point,
+                    StructureChange::Set(Some(Structure::Construction(buildable))),
+                )),
+            ],
+            None => vec![StateChange::Game(GameChange::Waiting(
+                self.tribe_id,
+                WaitingChange::Set(*target.id(), vec![WaitingReason::NoPlaceToBuild(buildable)]),
+            ))],
         }
+    }
 
-        changes
+    /// Nearest place around tribe home (but not next to it) where buildable can be built
+    fn find_build_place(&self, state: &State, buildable: &Buildable) -> Option<AbsoluteWorldPoint> {
+        let campfire = state.game().tribe_home(&self.tribe_id)?;
+        let world = state.world();
+        let distance = |point: &AbsoluteWorldPoint| point.squared_distance(&campfire);
+
+        let mut points = campfire
+            .around(BUILD_RADIUS)
+            .into_iter()
+            .filter(|point| distance(point) > 2)
+            .collect::<Vec<AbsoluteWorldPoint>>();
+        points.sort_by_key(distance);
+
+        points
+            .into_iter()
+            .find(|point| TryBuild::new(&world).try_(buildable, point).is_ok())
     }
 
     pub fn global_waitings(&self, state: &State, target: &Target) -> Option<Vec<WaitingReason>> {
-        match target {
-            Target::KeepStock(_, _) => {
-                if state
-                    .game()
-                    .tribe_structures(&self.tribe_id, Some(Structure::Storage))
-                    .first()
-                    .is_none()
-                {
-                    return Some(vec![WaitingReason::NeedOwnedStructure(Structure::Storage)]);
-                }
+        let required = match target {
+            Target::KeepStock(_, _) => vec![Structure::Storage],
+            Target::KeepBuilt(_, _)
+            | Target::Explore(_)
+            | Target::Plant(_)
+            | Target::Scripted(_) => {
+                vec![Structure::Campfire]
+            }
+            Target::Produce(_, _) | Target::Farm => vec![Structure::Campfire, Structure::Storage],
+        };
+        let game = state.game();
+        let missing = required
+            .into_iter()
+            .filter(|structure| {
+                game.tribe_structures(&self.tribe_id, Some(structure.clone()))
+                    .is_empty()
+            })
+            .map(WaitingReason::NeedOwnedStructure)
+            .collect::<Vec<WaitingReason>>();
+        if !missing.is_empty() {
+            return Some(missing);
+        }
+
+        if let Some(job) = target.job() {
+            // Workers are released to other targets until season comes back
+            if !job.in_season(&state.calendar().season()) {
+                return Some(vec![WaitingReason::OutOfSeason]);
+            }
+
+            let waitings = self.requirements_waitings(state, &job);
+            if !waitings.is_empty() {
+                return Some(waitings);
             }
         }
 
         None
     }
 
-    fn disband_target_worker(&self, target: &ComputedTarget, state: &State) -> Vec<StateChange> {
+    /// Job requirements which can't be satisfied for any worker
+    fn requirements_waitings(&self, state: &State, job: &Job) -> Vec<WaitingReason> {
+        job.requirements()
+            .into_iter()
+            .filter(|requirement| self.satisfiable(state, requirement) == 0)
+            .map(|requirement| match requirement {
+                JobRequirement::Material(material, _) => WaitingReason::NotEnoughMaterial(material),
+            })
+            .collect()
+    }
+
+    /// Count of workers which can satisfy all given job requirements (None if
+    /// job has no requirement)
+    fn equippable(&self, state: &State, job: &Job) -> Option<usize> {
+        job.requirements()
+            .iter()
+            .map(|requirement| self.satisfiable(state, requirement))
+            .min()
+    }
+
+    /// Count of workers which can satisfy given requirement: creatures already
+    /// carrying enough of it and ones which can take enough of it from tribe
+    /// storages (each worker needs its own)
+    fn satisfiable(&self, state: &State, requirement: &JobRequirement) -> usize {
+        let game = state.game();
         let world = state.world();
-        let target_job = Job::from(target.target());
-        let mut changes = vec![];
 
-        for human in world
-            .tribe_creatures(&self.tribe_id)
-            .filter_job(&target_job)
-        {
-            changes.push(StateChange::World(WorldChange::Creature(
-                *human.id(),
-                CreatureChange::SetJob(Job::Idle),
-            )));
-        }
+        match requirement {
+            JobRequirement::Material(material, quantity) => {
+                let carriers = world
+                    .tribe_creatures(&self.tribe_id)
+                    .iter()
+                    .filter(|c| c.carrying_quantity(*material).0 >= quantity.0)
+                    .count();
+                let stored = game
+                    .tribe_structures(&self.tribe_id, Some(Structure::Storage))
+                    .iter()
+                    .map(|s| world.material_quantity(s.point(), *material).0)
+                    .sum::<u64>();
 
-        changes
+                carriers + (stored / quantity.0.max(1)) as usize
+            }
+        }
     }
 }
 
 impl BodyTick<AffectJobChange> for AffectJob {
     fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
-        let mut changes = vec![];
-        let game = state.game();
-
-        for target in game.tribe_targets().get(&self.tribe_id).unwrap_or(&vec![]) {
-            changes.extend(self.solve_target(state, target))
+        let (reserved, mut changes) = self.solve_constructions(state);
+        // Targets are suspended while tribe is migrating
+        match self.solve_migration(state, &reserved) {
+            Some(migration_changes) => changes.extend(migration_changes),
+            None => changes.extend(self.solve_targets(state, &reserved)),
         }
 
-        (NextTick(*state.frame_i() + TICK_FREQUENCY), changes)
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.affect_job),
+            changes,
+        )
     }
 
     fn apply(&mut self, _change: AffectJobChange) {}
@@ -116,6 +495,109 @@ impl BodyTick<AffectJobChange> for AffectJob {
 #[derive(Debug)]
 pub enum AffectJobChange {}
 
+struct Share {
+    weight: u64,
+    min: usize,
+    max: Option<usize>,
+}
+
+impl Share {
+    /// Shares of given targets (in allocation order): first targets and most
+    /// urgent ones weight more
+    fn from_targets(targets: &[&ComputedTarget]) -> Vec<Share> {
+        targets
+            .iter()
+            .enumerate()
+            .map(|(rank, target)| Share {
+                weight: (targets.len() - rank) as u64 * target.urgency().max(1) as u64,
+                min: target.min_workers(),
+                max: target.max_workers(),
+            })
+            .collect()
+    }
+
+    /// Workers count required before sharing remaining ones
+    fn minimum(&self) -> usize {
+        self.min.min(self.room(0))
+    }
+
+    fn room(&self, value: usize) -> usize {
+        self.max
+            .map(|max| max.saturating_sub(value))
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/// Targets in workers allocation order: by priority, then most urgent first.
+/// Paused targets are excluded (they don't receive any worker)
+fn allocation_order(mut targets: Vec<&ComputedTarget>) -> Vec<&ComputedTarget> {
+    targets.retain(|t| t.enabled());
+    targets.sort_by_key(|t| (t.priority(), Reverse(t.urgency())));
+    targets
+}
+
+/// Share count between shares. Each share receive its minimum by order while
+/// count allows it, then remaining is shared proportionally to weights.
+/// Rounding leftovers go to largest rounding remainders (first shares on tie).
+/// Maximums are never exceeded, so some of count can stay not distributed.
+fn distribute(count: usize, shares: &[Share]) -> Vec<usize> {
+    let mut values = vec![0; shares.len()];
+    let mut remaining = count;
+
+    for (value, share) in values.iter_mut().zip(shares) {
+        let minimum = share.minimum().min(remaining);
+        *value = minimum;
+        remaining -= minimum;
+    }
+
+    let total: u64 = values
+        .iter()
+        .zip(shares)
+        .filter(|(value, share)| share.room(**value) > 0)
+        .map(|(_, share)| share.weight)
+        .sum();
+    if total == 0 {
+        return values;
+    }
+
+    let to_share = remaining;
+    let mut remainders = vec![];
+    for (i, (value, share)) in values.iter_mut().zip(shares).enumerate() {
+        if share.room(*value) == 0 {
+            continue;
+        }
+        let exact = to_share as u64 * share.weight;
+        let part = ((exact / total) as usize).min(share.room(*value));
+        *value += part;
+        remaining -= part;
+        remainders.push((exact % total, i));
+    }
+
+    remainders.sort_by_key(|(remainder, i)| (Reverse(*remainder), *i));
+    for (_, i) in remainders {
+        if remaining > 0 && shares[i].room(values[i]) > 0 {
+            values[i] += 1;
+            remaining -= 1;
+        }
+    }
+
+    while remaining > 0 {
+        let mut given = false;
+        for (value, share) in values.iter_mut().zip(shares) {
+            if remaining > 0 && share.room(*value) > 0 {
+                *value += 1;
+                remaining -= 1;
+                given = true;
+            }
+        }
+        if !given {
+            break;
+        }
+    }
+
+    values
+}
+
 pub struct AffectJobBuilder {
     tribe_id: TribeId,
 }
@@ -131,3 +613,562 @@ impl AffectJobBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::gameplay::{
+        calendar::{DAYS_PER_SEASON, FRAMES_PER_HOUR, HOURS_PER_DAY},
+        material::{Material, Resource},
+        order::Order,
+        target::{TargetId, TargetQuantity},
+        Quantity,
+    };
+    use rstest::*;
+
+    use crate::{
+        state::game::GameState,
+        testing::{self, apply, creature, own, point, run},
+    };
+
+    use super::*;
+
+    fn target(priority: usize, urgency: u8) -> ComputedTarget {
+        ComputedTarget::new(
+            TargetId::new(),
+            Target::Explore(10),
+            false,
+            0,
+            vec![],
+            priority,
+            urgency,
+        )
+    }
+
+    /// Workers count of each given target
+    fn allocate(count: usize, targets: &[ComputedTarget]) -> Vec<usize> {
+        let ordered = allocation_order(targets.iter().collect());
+        let desired = distribute(count, &Share::from_targets(&ordered));
+        targets
+            .iter()
+            .map(|target| {
+                ordered
+                    .iter()
+                    .position(|t| t.id() == target.id())
+                    .map(|i| desired[i])
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec![(1, 50), (0, 50)], vec![1, 0])]
+    #[case(vec![(2, 50), (0, 50), (1, 50)], vec![1, 2, 0])]
+    #[case(vec![(0, 20), (0, 80)], vec![1, 0])]
+    #[case(vec![(0, 20), (1, 80), (0, 80)], vec![2, 0, 1])]
+    fn test_allocation_order(#[case] targets: Vec<(usize, u8)>, #[case] expected: Vec<usize>) {
+        // Given
+        let targets = targets
+            .into_iter()
+            .map(|(priority, urgency)| target(priority, urgency))
+            .collect::<Vec<ComputedTarget>>();
+
+        // When
+        let ordered = allocation_order(targets.iter().collect());
+
+        // Then
+        let expected = expected
+            .into_iter()
+            .map(|i| targets[i].id())
+            .collect::<Vec<&TargetId>>();
+        assert_eq!(
+            ordered.iter().map(|t| t.id()).collect::<Vec<&TargetId>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    // Priority ordering: first targets receive workers first, then weight more
+    #[case(1, vec![(1, 50), (0, 50)], vec![0, 1])]
+    #[case(2, vec![(1, 50), (0, 50)], vec![1, 1])]
+    #[case(5, vec![(1, 50), (0, 50)], vec![2, 3])]
+    // Urgency tie-breaks targets of same priority
+    #[case(1, vec![(0, 20), (0, 80)], vec![0, 1])]
+    #[case(6, vec![(0, 20), (0, 80)], vec![1, 5])]
+    // More workers than shares
+    #[case(10, vec![(0, 50), (1, 50), (2, 50)], vec![5, 3, 2])]
+    #[case(3, vec![(0, 0)], vec![3])]
+    // Zero workers
+    #[case(0, vec![(0, 50), (1, 50)], vec![0, 0])]
+    #[case(4, vec![], vec![])]
+    fn test_distribute_by_priority_and_urgency(
+        #[case] count: usize,
+        #[case] targets: Vec<(usize, u8)>,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Given
+        let targets = targets
+            .into_iter()
+            .map(|(priority, urgency)| target(priority, urgency))
+            .collect::<Vec<ComputedTarget>>();
+
+        // When
+        let workers = allocate(count, &targets);
+
+        // Then
+        assert_eq!(workers, expected);
+    }
+
+    #[rstest]
+    // Minimum not reachable: first targets take all available workers
+    #[case(2, vec![(3, None), (0, None)], vec![2, 0])]
+    #[case(4, vec![(3, None), (2, None)], vec![3, 1])]
+    // Minimums are given before sharing remaining workers
+    #[case(6, vec![(0, None), (4, None)], vec![1, 5])]
+    #[case(6, vec![(2, None), (2, None)], vec![3, 3])]
+    // No minimum: targets only receive their proportional share
+    #[case(2, vec![(0, None), (0, None), (0, None)], vec![1, 1, 0])]
+    // Maximum caps spill remaining workers to next targets
+    #[case(5, vec![(0, Some(1)), (0, None)], vec![1, 4])]
+    #[case(5, vec![(0, Some(2)), (0, Some(1))], vec![2, 1])]
+    // Maximum takes precedence over minimum
+    #[case(5, vec![(3, Some(2)), (0, None)], vec![2, 3])]
+    #[case(3, vec![(0, Some(0)), (0, None)], vec![0, 3])]
+    fn test_distribute_with_workers_limits(
+        #[case] count: usize,
+        #[case] limits: Vec<(usize, Option<usize>)>,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Given
+        let targets = limits
+            .into_iter()
+            .enumerate()
+            .map(|(priority, (min, max))| target(priority, 50).with_workers(min, max))
+            .collect::<Vec<ComputedTarget>>();
+
+        // When
+        let workers = allocate(count, &targets);
+
+        // Then
+        assert_eq!(workers, expected);
+    }
+
+    #[rstest]
+    #[case(3, vec![false, true], vec![0, 3])]
+    #[case(3, vec![true, false, true], vec![2, 0, 1])]
+    #[case(3, vec![false], vec![0])]
+    fn test_paused_targets_have_no_worker(
+        #[case] count: usize,
+        #[case] enabled: Vec<bool>,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Given
+        let targets = enabled
+            .into_iter()
+            .enumerate()
+            .map(|(priority, enabled)| target(priority, 50).with_enabled(enabled))
+            .collect::<Vec<ComputedTarget>>();
+
+        // When
+        let workers = allocate(count, &targets);
+
+        // Then
+        assert_eq!(workers, expected);
+    }
+
+    #[rstest]
+    fn test_workers_kept_on_their_target_when_targets_share_job() {
+        // Given
+        let tribe_id = TribeId::new();
+        let (first, second) = (TargetId::new(), TargetId::new());
+        let mut worker = creature(tribe_id, point(0, 0));
+        let worker_id = *worker.id();
+        worker.set_job(Job::Explore(10));
+        let idle = creature(tribe_id, point(0, 0));
+        let idle_id = *idle.id();
+        let mut world = testing::world(3, 3, vec![worker, idle]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        let targets = [(first, 0), (second, 1)]
+            .into_iter()
+            .map(|(id, priority)| {
+                ComputedTarget::new(id, Target::Explore(10), false, 0, vec![], priority, 50)
+                    .with_workers(0, Some(1))
+            })
+            .collect();
+        game.set_tribe_targets(tribe_id, targets);
+        game.set_worker_target(worker_id, Some(second));
+        let mut state = testing::state(world, game);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_targets(&state, &[]);
+        apply(&mut state, changes);
+
+        // Then
+        let game = state.game();
+        assert_eq!(game.worker_target(&worker_id), Some(&second));
+        assert_eq!(game.worker_target(&idle_id), Some(&first));
+        let world = state.world();
+        let idle = world.creatures().get(&idle_id).unwrap();
+        assert_eq!(idle.job(), &Job::Explore(10));
+    }
+
+    #[rstest]
+    fn test_targets_without_minimum_can_have_no_worker() {
+        // Given
+        let tribe_id = TribeId::new();
+        let mut world = testing::world(3, 3, vec![creature(tribe_id, point(0, 0))]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        let targets = (0..3)
+            .map(|priority| {
+                ComputedTarget::new(
+                    TargetId::new(),
+                    Target::Explore(10),
+                    false,
+                    0,
+                    vec![],
+                    priority,
+                    50,
+                )
+            })
+            .collect::<Vec<ComputedTarget>>();
+        let ids = targets.iter().map(|t| *t.id()).collect::<Vec<TargetId>>();
+        game.set_tribe_targets(tribe_id, targets);
+        let state = testing::state(world, game);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_targets(&state, &[]);
+
+        // Then
+        let affected = changes
+            .iter()
+            .filter_map(|change| match change {
+                StateChange::Game(GameChange::WorkerTarget(_, Some(id))) => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<TargetId>>();
+        assert_eq!(affected, vec![ids[0]]);
+        // Only targets with a minimum wait for workers
+        assert!(!changes.iter().any(|change| matches!(
+            change,
+            StateChange::Game(GameChange::Waiting(_, WaitingChange::Set(_, waitings)))
+                if !waitings.is_empty()
+        )));
+    }
+
+    #[rstest]
+    #[case(0, 0, vec![WaitingReason::NotEnoughMaterial(Material::Resource(Resource::RawFlint))])]
+    #[case(1, 1, vec![])]
+    #[case(5, 3, vec![])]
+    fn test_workers_limited_by_required_tools(
+        #[case] flints: u64,
+        #[case] expected_workers: usize,
+        #[case] expected_waitings: Vec<WaitingReason>,
+    ) {
+        // Given
+        let tribe_id = TribeId::new();
+        let creatures = (0..3).map(|_| creature(tribe_id, point(0, 0))).collect();
+        let mut world = testing::world(3, 3, creatures);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(2, 2),
+        );
+        if flints > 0 {
+            world
+                .add_material(
+                    point(2, 2),
+                    Material::Resource(Resource::RawFlint),
+                    Quantity::units(flints),
+                )
+                .unwrap();
+        }
+        let branches = Material::Resource(Resource::Branches);
+        let target = ComputedTarget::new(
+            TargetId::new(),
+            Target::KeepStock(branches, TargetQuantity::Fixed(Quantity::cubic_meters(10))),
+            false,
+            0,
+            vec![],
+            0,
+            50,
+        );
+        game.set_tribe_targets(tribe_id, vec![target]);
+        let state = testing::state(world, game);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_targets(&state, &[]);
+
+        // Then
+        let workers = changes
+            .iter()
+            .filter(|change| {
+                matches!(
+                    change,
+                    StateChange::World(WorldChange::Creature(
+                        _,
+                        CreatureChange::SetJob(Job::SearchResource(Resource::Branches))
+                    ))
+                )
+            })
+            .count();
+        assert_eq!(workers, expected_workers);
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StateChange::Game(GameChange::Waiting(_, WaitingChange::Set(_, waitings)))
+                if waitings == &expected_waitings
+        )));
+    }
+
+    #[rstest]
+    fn test_ordered_creatures_keep_their_job() {
+        // Given
+        let tribe_id = TribeId::new();
+        let ordered = creature(tribe_id, point(0, 0));
+        let ordered_id = *ordered.id();
+        let mut world = testing::world(3, 3, vec![ordered]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        game.set_tribe_targets(tribe_id, vec![target(0, 50)]);
+        game.set_order(ordered_id, Order::Idle);
+        let state = testing::state(world, game);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_targets(&state, &[]);
+
+        // Then
+        assert!(!changes.iter().any(|change| matches!(
+            change,
+            StateChange::World(WorldChange::Creature(id, CreatureChange::SetJob(_)))
+                if id == &ordered_id
+        )));
+    }
+
+    /// Tribe migrating from (1, 1) to (8, 8), leaving its campfire and a storage
+    /// at (1, 3) containing given food. Given new structures are built around
+    /// new home.
+    fn migrating(food: u64, new: Vec<(Structure, AbsoluteWorldPoint)>) -> (State, TribeId) {
+        let tribe_id = TribeId::new();
+        let creatures = (0..2).map(|_| creature(tribe_id, point(1, 2))).collect();
+        let mut world = testing::world(10, 10, creatures);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(1, 3),
+        );
+        if food > 0 {
+            let material = Material::Resource(Resource::Food);
+            world
+                .add_material(point(1, 3), material, material.quantity(food))
+                .unwrap();
+        }
+        for (structure, point) in new {
+            own(&mut world, &mut game, tribe_id, structure, point);
+        }
+        game.set_migration(
+            tribe_id,
+            Migration::new(point(1, 1), point(8, 8), vec![point(1, 1), point(1, 3)]),
+        );
+
+        (testing::state(world, game), tribe_id)
+    }
+
+    fn ended(changes: &[StateChange]) -> bool {
+        changes.iter().any(|change| {
+            matches!(
+                change,
+                StateChange::Game(GameChange::Migration(_, MigrationChange::End))
+            )
+        })
+    }
+
+    #[rstest]
+    // New campfire not built yet
+    #[case(0, vec![], false)]
+    // Not as many new storages as left ones
+    #[case(0, vec![(Structure::Campfire, point(8, 8))], false)]
+    // Left stocks can still be brought to new storages
+    #[case(
+        10,
+        vec![(Structure::Campfire, point(8, 8)), (Structure::Storage, point(8, 6))],
+        false
+    )]
+    #[case(
+        0,
+        vec![(Structure::Campfire, point(8, 8)), (Structure::Storage, point(8, 6))],
+        true
+    )]
+    fn test_migration_end(
+        #[case] food: u64,
+        #[case] new: Vec<(Structure, AbsoluteWorldPoint)>,
+        #[case] expected: bool,
+    ) {
+        // Given
+        let (state, tribe_id) = migrating(food, new);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_migration(&state, &[]).unwrap();
+
+        // Then
+        assert_eq!(ended(&changes), expected);
+    }
+
+    #[rstest]
+    fn test_migration_end_demolish_left_structures() {
+        // Given
+        let new = vec![
+            (Structure::Campfire, point(8, 8)),
+            (Structure::Storage, point(8, 6)),
+        ];
+        let (mut state, tribe_id) = migrating(0, new);
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_migration(&state, &[]).unwrap();
+        apply(&mut state, changes);
+        run(&mut state, 2);
+
+        // Then
+        let game = state.game();
+        assert!(game.migration(&tribe_id).is_none());
+        assert!(game.structure_own(&point(1, 1)).is_none());
+        assert!(game.structure_own(&point(1, 3)).is_none());
+        assert!(game.structure_own(&point(8, 6)).is_some());
+    }
+
+    #[rstest]
+    fn test_migration_reassign_creatures() {
+        // Given
+        let (state, tribe_id) = migrating(10, vec![(Structure::Campfire, point(8, 8))]);
+        let creatures = state
+            .world()
+            .tribe_creatures(&tribe_id)
+            .iter()
+            .map(|c| *c.id())
+            .collect::<Vec<CreatureId>>();
+        let reserved = creatures[0];
+
+        // When
+        let changes = AffectJob { tribe_id }
+            .solve_migration(&state, &[reserved])
+            .unwrap();
+
+        // Then
+        let migrating = changes
+            .iter()
+            .filter_map(|change| match change {
+                StateChange::World(WorldChange::Creature(id, CreatureChange::SetJob(job))) => {
+                    Some((*id, job.clone()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<(CreatureId, Job)>>();
+        assert_eq!(migrating, vec![(creatures[1], Job::Migrate)]);
+        // A new storage is placed to receive left stocks
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StateChange::Game(GameChange::Construction(ConstructionChange::New(construction)))
+                if construction.buildable() == &Buildable::Storage
+        )));
+    }
+
+    #[rstest]
+    #[case(0, vec![], Job::SearchResource(Resource::Food))]
+    #[case(3, vec![WaitingReason::OutOfSeason], Job::Explore(10))]
+    fn test_out_of_season_target_release_workers(
+        #[case] season: u64,
+        #[case] expected_waitings: Vec<WaitingReason>,
+        #[case] expected_job: Job,
+    ) {
+        // Given
+        let tribe_id = TribeId::new();
+        let mut worker = creature(tribe_id, point(0, 0));
+        let worker_id = *worker.id();
+        worker.set_job(Job::SearchResource(Resource::Food));
+        let mut world = testing::world(3, 3, vec![worker]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(1, 1),
+        );
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(2, 2),
+        );
+        let food = ComputedTarget::new(
+            TargetId::new(),
+            Target::KeepStock(
+                Material::Resource(Resource::Food),
+                TargetQuantity::Fixed(Quantity::kilograms(10)),
+            ),
+            false,
+            0,
+            vec![],
+            0,
+            50,
+        );
+        let food_id = *food.id();
+        game.set_tribe_targets(tribe_id, vec![food, target(1, 50)]);
+        game.set_worker_target(worker_id, Some(food_id));
+        let mut state = testing::state(world, game);
+        let season_frames = FRAMES_PER_HOUR * HOURS_PER_DAY * DAYS_PER_SEASON;
+        for _ in 0..season * season_frames {
+            state.increment();
+        }
+
+        // When
+        let changes = AffectJob { tribe_id }.solve_targets(&state, &[]);
+        apply(&mut state, changes);
+
+        // Then
+        let game = state.game();
+        let waitings = game.tribe_waitings().get(&tribe_id).unwrap();
+        assert_eq!(waitings[&food_id], expected_waitings);
+        let world = state.world();
+        let worker = world.creatures().get(&worker_id).unwrap();
+        assert_eq!(worker.job(), &expected_job);
+    }
+}
@@ -0,0 +1,364 @@
+use neoroll_world::{
+    entity::creature::{Creature, CreatureChange},
+    gameplay::{
+        behavior::Behavior,
+        build::construction::Construction,
+        job::Job,
+        material::{Material, Resource},
+        target::{Target, TargetId, WaitingReason},
+        tribe::structure::StructureOwn,
+        Quantity,
+    },
+    map::find::AroundTileFinder,
+    space::{
+        world::{MaterialChange, StructureChange, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::{
+    action::{
+        collect::CollectBuilder, construct::ConstructBuilder, drop::DropOff, move_::MoveTo,
+        pick::PickUp, Action, ActionChange, ActionId,
+    },
+    state::{
+        game::{ConstructionChange, GameChange, WaitingChange},
+        State, StateChange,
+    },
+    storage::StorageFinder,
+};
+
+pub struct RealizeBuild<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+    point: AbsoluteWorldPoint,
+}
+
+impl<'a> RealizeBuild<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State, point: AbsoluteWorldPoint) -> Self {
+        Self {
+            creature,
+            state,
+            point,
+        }
+    }
+
+    fn new_action(&self, action: Action) -> Vec<StateChange> {
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    fn delivered(&self, material: &Material) -> Quantity {
+        self.state.world().material_quantity(&self.point, *material)
+    }
+
+    /// Materials still missing on construction site
+    fn missing(&self, construction: &Construction) -> Vec<(Material, Quantity)> {
+        construction
+            .buildable()
+            .materials()
+            .into_iter()
+            .filter_map(|(material, required)| {
+                let delivered = self.delivered(&material);
+                if delivered.0 < required.0 {
+                    Some((material, material.quantity(required.0 - delivered.0)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Target which asked for given construction
+    fn target_id(&self, construction: &Construction) -> Option<TargetId> {
+        self.state
+            .game()
+            .tribe_targets()
+            .get(construction.tribe_id())?
+            .iter()
+            .find(|target| {
+                matches!(
+                    target.target(),
+                    Target::KeepBuilt(buildable, _) if buildable == construction.buildable()
+                )
+            })
+            .map(|target| *target.id())
+    }
+
+    /// Changes to set construction target waitings (only if they changed)
+    fn waitings(
+        &self,
+        construction: &Construction,
+        waitings: Vec<WaitingReason>,
+    ) -> Vec<StateChange> {
+        let target_id = match self.target_id(construction) {
+            Some(target_id) => target_id,
+            None => return vec![],
+        };
+        let current = self
+            .state
+            .game()
+            .tribe_waitings()
+            .get(construction.tribe_id())
+            .and_then(|waitings| waitings.get(&target_id))
+            .cloned()
+            .unwrap_or_default();
+        if current == waitings {
+            return vec![];
+        }
+
+        vec![StateChange::Game(GameChange::Waiting(
+            *construction.tribe_id(),
+            WaitingChange::Set(target_id, waitings),
+        ))]
+    }
+
+    /// Return changes to bring given material on construction site
+    fn bring(&self, material: &Material, missing: &Quantity) -> Vec<StateChange> {
+        if self.creature.carrying_quantity(*material).0 > 0 {
+            return self.new_action(Action::DropOff(DropOff::new(
+                *self.creature.id(),
+                self.point,
+                *material,
+            )));
+        }
+
+        // Take it from nearest tribe storage first
+        let finder = StorageFinder::new(self.state, *self.creature.tribe_id());
+        if let Some(storage) = finder
+            .pick_up(self.creature.point(), material, &material.quantity(1))
+            .first()
+        {
+            let stored = finder.stored(storage, material);
+            return self.new_action(Action::PickUp(PickUp::new(
+                *self.creature.id(),
+                *storage,
+                *material,
+                material.quantity(missing.0.min(stored.0)),
+            )));
+        }
+
+        // Or collect it directly if no tool is required
+        match material {
+            Material::Resource(resource) => {
+                if Job::SearchResource(*resource).requirements().is_empty() {
+                    return self.collect(*resource);
+                }
+            }
+            // Crafted and hunted materials can only be taken from storages
+            Material::Crafted(_) | Material::Hunted(_) => {}
+        }
+
+        // Nothing to do while required material is not available
+        vec![]
+    }
+
+    fn collect(&self, resource: Resource) -> Vec<StateChange> {
+        let world = self.state.world();
+        if world.can_collect(self.creature.point(), resource.into()) {
+            return self.new_action(CollectBuilder::new(*self.creature.id(), resource).build());
+        }
+
+        if let Some(point) = AroundTileFinder::new(&world, *self.creature.point())
+            .collect(Some(resource.into()))
+            .search()
+        {
+            return self.new_action(Action::MoveTo(MoveTo::new(*self.creature.id(), point)));
+        }
+
+        vec![]
+    }
+
+    /// Consume materials and replace construction site by the built structure
+    fn complete(&self, construction: &Construction) -> Vec<StateChange> {
+        let mut changes = vec![];
+
+        for (material, quantity) in construction.buildable().materials() {
+            changes.push(StateChange::World(WorldChange::Material(
+                self.point,
+                MaterialChange::Remove(material, quantity),
+            )));
+        }
+        changes.extend(vec![
+            StateChange::Game(GameChange::Construction(ConstructionChange::Remove(
+                self.point,
+            ))),
+            StateChange::World(WorldChange::Structure(
+                self.point,
+                StructureChange::SetOwned(StructureOwn::new(
+                    (*construction.buildable()).into(),
+                    *construction.tribe_id(),
+                    self.point,
+                )),
+            )),
+            StateChange::World(WorldChange::Creature(
+                *self.creature.id(),
+                CreatureChange::SetJob(Job::Idle),
+            )),
+        ]);
+
+        changes
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        let construction = match self.state.game().construction(&self.point) {
+            Some(construction) => construction.clone(),
+            // Construction has been cancelled or finished
+            None => {
+                return vec![StateChange::World(WorldChange::Creature(
+                    *self.creature.id(),
+                    CreatureChange::SetJob(Job::Idle),
+                ))]
+            }
+        };
+
+        if construction.is_complete() {
+            return self.complete(&construction);
+        }
+
+        if let Some((material, missing)) = self.missing(&construction).first() {
+            let bring = self.bring(material, missing);
+            // Nothing can be done while material is not in storages (tool not crafted, ...)
+            let waitings = if bring.is_empty() {
+                vec![WaitingReason::NotEnoughMaterial(*material)]
+            } else {
+                vec![]
+            };
+            let mut changes = self.waitings(&construction, waitings);
+            changes.extend(bring);
+            return changes;
+        }
+
+        if self.creature.point() != &self.point {
+            return self.new_action(Action::MoveTo(MoveTo::new(*self.creature.id(), self.point)));
+        }
+
+        self.new_action(ConstructBuilder::new(*self.creature.id(), self.point).build())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::{creature::CreatureId, structure::Structure},
+        gameplay::{build::Buildable, target::ComputedTarget, tribe::TribeId},
+    };
+    use rstest::*;
+
+    use crate::{
+        state::game::GameState,
+        testing::{self, creature, point},
+    };
+
+    use super::*;
+
+    /// State with a campfire construction (and its target) on a 1x1 world where
+    /// nothing can be collected, and its builder carrying given branches
+    fn state(branches: u64, waitings: Vec<WaitingReason>) -> (State, CreatureId, TargetId) {
+        let tribe_id = TribeId::new();
+        let target_id = TargetId::new();
+        let point = point(0, 0);
+        let mut creature = creature(tribe_id, point);
+        let creature_id = *creature.id();
+        creature.set_job(Job::Build(point));
+        if branches > 0 {
+            creature
+                .add_to_carrying(
+                    Material::Resource(Resource::Branches),
+                    Quantity::cubic_meters(branches),
+                )
+                .unwrap();
+        }
+        let mut world = testing::world(1, 1, vec![creature]);
+        world.set_structure(point, Some(Structure::Construction(Buildable::Campfire)));
+        let mut game = GameState::default();
+        game.new_construction(Construction::new(Buildable::Campfire, tribe_id, point));
+        game.set_tribe_targets(
+            tribe_id,
+            vec![ComputedTarget::new(
+                target_id,
+                Target::KeepBuilt(Buildable::Campfire, 1),
+                false,
+                0,
+                vec![],
+                0,
+                0,
+            )],
+        );
+        game.set_waitings(&tribe_id, &target_id, waitings);
+
+        (testing::state(world, game), creature_id, target_id)
+    }
+
+    fn waitings(changes: &[StateChange]) -> Vec<(TargetId, Vec<WaitingReason>)> {
+        changes
+            .iter()
+            .filter_map(|change| match change {
+                StateChange::Game(GameChange::Waiting(_, WaitingChange::Set(id, waitings))) => {
+                    Some((*id, waitings.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_bring_tool_required_material_waiting() {
+        // Given
+        let (state, creature_id, target_id) = state(0, vec![]);
+        let creature = state.world().creatures().get(&creature_id).unwrap().clone();
+        let point = *creature.point();
+
+        // When
+        let changes = RealizeBuild::new(&creature, &state, point).changes();
+
+        // Then
+        assert_eq!(
+            waitings(&changes),
+            vec![(
+                target_id,
+                vec![WaitingReason::NotEnoughMaterial(Material::Resource(
+                    Resource::Branches
+                ))]
+            )]
+        );
+        assert_eq!(changes.len(), 1);
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(
+        vec![WaitingReason::NotEnoughMaterial(Material::Resource(Resource::Branches))],
+        vec![vec![]]
+    )]
+    fn test_bring_carried_material_clear_waiting(
+        #[case] current: Vec<WaitingReason>,
+        #[case] expected: Vec<Vec<WaitingReason>>,
+    ) {
+        // Given
+        let (state, creature_id, target_id) = state(1, current);
+        let creature = state.world().creatures().get(&creature_id).unwrap().clone();
+        let point = *creature.point();
+
+        // When
+        let changes = RealizeBuild::new(&creature, &state, point).changes();
+
+        // Then
+        let expected = expected
+            .into_iter()
+            .map(|waitings| (target_id, waitings))
+            .collect::<Vec<_>>();
+        assert_eq!(waitings(&changes), expected);
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StateChange::Action(_, ActionChange::New(Action::DropOff(_)))
+        )));
+    }
+}
@@ -0,0 +1,115 @@
+use neoroll_world::{
+    entity::creature::{Creature, CreatureChange},
+    gameplay::{
+        behavior::Behavior,
+        job::Job,
+        material::{Crafted, Material},
+        target::Target,
+    },
+    space::world::WorldChange,
+};
+
+use crate::{
+    action::{
+        craft::CraftBuilder, drop::DropOff, move_::MoveTo, pick::PickUp, Action, ActionChange,
+        ActionId,
+    },
+    state::{State, StateChange},
+    storage::StorageFinder,
+};
+
+pub struct RealizeCraft<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+    crafted: Crafted,
+}
+
+impl<'a> RealizeCraft<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State, crafted: Crafted) -> Self {
+        Self {
+            creature,
+            state,
+            crafted,
+        }
+    }
+
+    fn new_action(&self, action: Action) -> Vec<StateChange> {
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    /// Bring produced units to nearest storage
+    fn store(&self, product: &Material) -> Vec<StateChange> {
+        if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+            .drop_off(self.creature.point(), product)
+            .first()
+        {
+            return self.new_action(Action::DropOff(DropOff::new(
+                *self.creature.id(),
+                *storage,
+                *product,
+            )));
+        }
+
+        vec![]
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        let product = Material::Crafted(self.crafted);
+        let carrying_product = self.creature.carrying_quantity(product);
+        if carrying_product.0 >= Target::carrying_enough_quantity(&product).0
+            || self.creature.cant_carry_more(&product)
+        {
+            return self.store(&product);
+        }
+
+        for (material, quantity) in self.crafted.recipe() {
+            let carrying = self.creature.carrying_quantity(material);
+            if carrying.0 >= quantity.0 {
+                continue;
+            }
+
+            let missing = material.quantity(quantity.0 - carrying.0);
+            if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+                .pick_up(self.creature.point(), &material, &missing)
+                .first()
+            {
+                return self.new_action(Action::PickUp(PickUp::new(
+                    *self.creature.id(),
+                    *storage,
+                    material,
+                    missing,
+                )));
+            }
+
+            // Nothing more to craft, store what have been produced
+            if carrying_product.0 > 0 {
+                return self.store(&product);
+            }
+            return vec![];
+        }
+
+        let campfire = match self.state.game().tribe_campfire(self.creature.tribe_id()) {
+            Some(campfire) => campfire,
+            None => {
+                return vec![StateChange::World(WorldChange::Creature(
+                    *self.creature.id(),
+                    CreatureChange::SetJob(Job::Idle),
+                ))]
+            }
+        };
+
+        if self.creature.point() != &campfire {
+            return self.new_action(Action::MoveTo(MoveTo::new(*self.creature.id(), campfire)));
+        }
+
+        self.new_action(CraftBuilder::new(*self.creature.id(), self.crafted).build())
+    }
+}
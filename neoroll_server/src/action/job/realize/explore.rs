@@ -0,0 +1,120 @@
+use neoroll_world::{
+    entity::creature::{Creature, CreatureChange},
+    gameplay::{behavior::Behavior, job::Job},
+    space::{
+        world::{World, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::{
+    action::{move_::MoveTo, Action, ActionChange, ActionId},
+    state::{game::GameChange, State, StateChange},
+};
+
+/// Radius of tiles explored around a walking creature
+const SIGHT_RADIUS: u32 = 3;
+/// Destinations tried by tick. Unreachable ones are considered as explored.
+const MAX_TRIES: usize = 5;
+
+/// Walkable tiles which must be explored for given radius around given center
+pub fn exploration_area(
+    world: &World,
+    center: &AbsoluteWorldPoint,
+    radius: u32,
+) -> Vec<AbsoluteWorldPoint> {
+    center
+        .around(radius)
+        .into_iter()
+        .filter(|point| world.contains(point) && world.can_walk(point))
+        .collect()
+}
+
+pub struct RealizeExplore<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+    radius: u32,
+}
+
+impl<'a> RealizeExplore<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State, radius: u32) -> Self {
+        Self {
+            creature,
+            state,
+            radius,
+        }
+    }
+
+    fn distance(&self, point: &AbsoluteWorldPoint) -> isize {
+        let from = self.creature.point();
+        let rows = point.row_i().0 - from.row_i().0;
+        let cols = point.col_i().0 - from.col_i().0;
+        rows * rows + cols * cols
+    }
+
+    /// Tiles seen by the creature and not yet explored by its tribe
+    fn sight(&self) -> Vec<AbsoluteWorldPoint> {
+        let game = self.state.game();
+        self.creature
+            .point()
+            .around(SIGHT_RADIUS)
+            .into_iter()
+            .filter(|point| !game.is_explored(self.creature.tribe_id(), point))
+            .collect()
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        let tribe_id = *self.creature.tribe_id();
+        let mut changes = vec![];
+
+        let seen = self.sight();
+        if !seen.is_empty() {
+            changes.push(StateChange::Game(GameChange::Explored(tribe_id, seen)));
+        }
+
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return changes;
+        }
+
+        let campfire = match self.state.game().tribe_campfire(&tribe_id) {
+            Some(campfire) => campfire,
+            None => {
+                changes.push(StateChange::World(WorldChange::Creature(
+                    *self.creature.id(),
+                    CreatureChange::SetJob(Job::Idle),
+                )));
+                return changes;
+            }
+        };
+
+        let world = self.state.world();
+        let game = self.state.game();
+        let mut unexplored = exploration_area(&world, &campfire, self.radius)
+            .into_iter()
+            .filter(|point| !game.is_explored(&tribe_id, point))
+            .collect::<Vec<AbsoluteWorldPoint>>();
+        unexplored.sort_by_key(|point| self.distance(point));
+
+        let mut unreachable = vec![];
+        for point in unexplored.into_iter().take(MAX_TRIES) {
+            if world.find_path(self.creature.point(), &point).is_some() {
+                changes.push(StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::MoveTo(MoveTo::new(*self.creature.id(), point))),
+                ));
+                break;
+            }
+            unreachable.push(point);
+        }
+
+        if !unreachable.is_empty() {
+            changes.push(StateChange::Game(GameChange::Explored(
+                tribe_id,
+                unreachable,
+            )));
+        }
+
+        changes
+    }
+}
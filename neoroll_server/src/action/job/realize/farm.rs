@@ -0,0 +1,167 @@
+use neoroll_world::{
+    entity::{
+        creature::{Creature, CreatureChange},
+        floor::Floor,
+        Filled,
+    },
+    gameplay::{
+        behavior::Behavior,
+        job::Job,
+        material::{Material, Resource},
+        skill::Skill,
+        target::Target,
+        tribe::TribeId,
+    },
+    space::{
+        world::{FloorChange, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::{
+    action::{
+        collect::CollectBuilder, drop::DropOff, move_::MoveTo, Action, ActionChange, ActionId,
+    },
+    state::{State, StateChange},
+    storage::StorageFinder,
+};
+
+const PRACTICE_EXPERIENCE: u32 = 5;
+/// Destinations tried by tick
+const MAX_TRIES: usize = 5;
+/// Fruit bushes are harvested when filled at least at this level
+const RIPE: Filled = Filled(200);
+
+/// Tribe fields tiles which must be sown or harvested
+pub fn field_works(state: &State, tribe_id: &TribeId) -> Vec<AbsoluteWorldPoint> {
+    let world = state.world();
+    state
+        .game()
+        .tribe_fields(tribe_id)
+        .iter()
+        .flat_map(|field| field.points())
+        .filter(|point| world.can_sow(point) || ripe(state, point))
+        .collect()
+}
+
+fn ripe(state: &State, point: &AbsoluteWorldPoint) -> bool {
+    matches!(state.world().floor(point), Some(Floor::FruitBush(filled)) if filled.0 >= RIPE.0)
+}
+
+pub struct RealizeFarm<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+}
+
+impl<'a> RealizeFarm<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State) -> Self {
+        Self { creature, state }
+    }
+
+    fn done(&self) -> Vec<StateChange> {
+        vec![StateChange::World(WorldChange::Creature(
+            *self.creature.id(),
+            CreatureChange::SetJob(Job::Idle),
+        ))]
+    }
+
+    fn in_field(&self, point: &AbsoluteWorldPoint) -> bool {
+        self.state
+            .game()
+            .tribe_fields(self.creature.tribe_id())
+            .iter()
+            .any(|field| field.contains(point))
+    }
+
+    fn carrying(&self) -> bool {
+        self.creature
+            .carrying_quantity(Material::Resource(Resource::Food))
+            .0
+            > 0
+    }
+
+    fn carrying_enough(&self) -> bool {
+        let material = Material::Resource(Resource::Food);
+        self.creature.carrying_quantity(material).0 >= Target::carrying_enough_quantity(&material).0
+            || self.creature.cant_carry_more(&material)
+    }
+
+    fn sow(&self) -> Vec<StateChange> {
+        vec![
+            StateChange::World(WorldChange::Floor(
+                *self.creature.point(),
+                FloorChange::Set(Floor::Sown(Filled::empty())),
+            )),
+            StateChange::World(WorldChange::Creature(
+                *self.creature.id(),
+                CreatureChange::Practice(Skill::Gathering, PRACTICE_EXPERIENCE),
+            )),
+        ]
+    }
+
+    fn harvest(&self) -> Vec<StateChange> {
+        let action = CollectBuilder::new(*self.creature.id(), Resource::Food).build();
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    /// Bring harvest to a storage, or None if no storage can receive it
+    fn drop_off(&self) -> Option<Vec<StateChange>> {
+        let material = Material::Resource(Resource::Food);
+        StorageFinder::new(self.state, *self.creature.tribe_id())
+            .drop_off(self.creature.point(), &material)
+            .first()
+            .map(|storage| {
+                let action = Action::DropOff(DropOff::new(*self.creature.id(), *storage, material));
+                vec![StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(action),
+                )]
+            })
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        if self.carrying_enough() {
+            return self.drop_off().unwrap_or_default();
+        }
+
+        let point = self.creature.point();
+        if self.in_field(point) {
+            if self.state.world().can_sow(point) {
+                return self.sow();
+            }
+            if ripe(self.state, point) {
+                return self.harvest();
+            }
+        }
+
+        let world = self.state.world();
+        let mut points = field_works(self.state, self.creature.tribe_id());
+        points.sort_by_key(|point_| point_.squared_distance(point));
+
+        for point_ in points.into_iter().take(MAX_TRIES) {
+            if world.find_path(point, &point_).is_some() {
+                return vec![StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::MoveTo(MoveTo::new(*self.creature.id(), point_))),
+                )];
+            }
+        }
+
+        // Nothing more to do in fields, bring back last harvest
+        if self.carrying() {
+            if let Some(changes) = self.drop_off() {
+                return changes;
+            }
+        }
+
+        self.done()
+    }
+}
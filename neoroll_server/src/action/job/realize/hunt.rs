@@ -0,0 +1,83 @@
+use neoroll_world::{
+    entity::creature::Creature,
+    gameplay::{
+        behavior::Behavior,
+        material::{Hunted, Material},
+        target::Target,
+    },
+};
+
+use crate::{
+    action::{drop::DropOff, hunt::HuntBuilder, move_::MoveTo, Action, ActionChange, ActionId},
+    state::{State, StateChange},
+    storage::StorageFinder,
+};
+
+pub struct RealizeHunt<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+}
+
+impl<'a> RealizeHunt<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State) -> Self {
+        Self { creature, state }
+    }
+
+    fn new_action(&self, action: Action) -> Vec<StateChange> {
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    /// Bring the first carried material to nearest storage
+    fn store(&self) -> Vec<StateChange> {
+        for (material, _) in self.creature.carrying() {
+            if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+                .drop_off(self.creature.point(), material)
+                .first()
+            {
+                return self.new_action(Action::DropOff(DropOff::new(
+                    *self.creature.id(),
+                    *storage,
+                    *material,
+                )));
+            }
+        }
+
+        vec![]
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        let hide = Material::Hunted(Hunted::Hide);
+        if self.creature.carrying_quantity(hide).0 >= Target::carrying_enough_quantity(&hide).0
+            || self.creature.cant_carry_more(&hide)
+        {
+            return self.store();
+        }
+
+        let point = self.creature.point();
+        let world = self.state.world();
+        let animal = world
+            .animals()
+            .values()
+            .min_by_key(|animal| animal.point().squared_distance(point));
+
+        match animal {
+            Some(animal) if animal.point().squared_distance(point) <= 2 => {
+                self.new_action(HuntBuilder::new(*self.creature.id(), *animal.id()).build())
+            }
+            Some(animal) => self.new_action(Action::MoveTo(MoveTo::new(
+                *self.creature.id(),
+                *animal.point(),
+            ))),
+            // No more game, store what have been hunted
+            None => self.store(),
+        }
+    }
+}
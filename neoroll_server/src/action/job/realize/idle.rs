@@ -0,0 +1,40 @@
+use neoroll_world::{entity::creature::Creature, gameplay::behavior::Behavior};
+
+use crate::{
+    action::{drop::DropOff, Action, ActionChange, ActionId},
+    state::{State, StateChange},
+    storage::StorageFinder,
+};
+
+pub struct RealizeIdle<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+}
+
+impl<'a> RealizeIdle<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State) -> Self {
+        Self { creature, state }
+    }
+
+    /// Idle creatures bring back to storage what they carry (like previous job
+    /// required tools)
+    pub fn changes(&self) -> Vec<StateChange> {
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        if let Some((material, _)) = self.creature.carrying().iter().find(|(_, q)| q.0 > 0) {
+            if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+                .drop_off(self.creature.point(), material)
+                .first()
+            {
+                let action_id = ActionId::new();
+                let action =
+                    Action::DropOff(DropOff::new(*self.creature.id(), *storage, *material));
+                return vec![StateChange::Action(action_id, ActionChange::New(action))];
+            }
+        }
+
+        vec![]
+    }
+}
@@ -0,0 +1,103 @@
+use neoroll_world::{
+    entity::creature::{Creature, CreatureChange},
+    gameplay::{behavior::Behavior, job::Job, material::Material, tribe::TribeId},
+    space::{world::WorldChange, AbsoluteWorldPoint},
+};
+
+use crate::{
+    action::{drop::DropOff, pick::PickUp, Action, ActionChange, ActionId},
+    state::{State, StateChange},
+    storage::StorageFinder,
+};
+
+/// Carry transportable stocks of storages left by the tribe to its new home storages
+pub struct RealizeMigrate<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+}
+
+impl<'a> RealizeMigrate<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State) -> Self {
+        Self { creature, state }
+    }
+
+    fn new_action(&self, action: Action) -> Vec<StateChange> {
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    fn done(&self) -> Vec<StateChange> {
+        vec![StateChange::World(WorldChange::Creature(
+            *self.creature.id(),
+            CreatureChange::SetJob(Job::Idle),
+        ))]
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        // Migration is over or canceled
+        if self
+            .state
+            .game()
+            .migration(self.creature.tribe_id())
+            .is_none()
+        {
+            return self.done();
+        }
+
+        let finder = StorageFinder::new(self.state, *self.creature.tribe_id());
+        if let Some((material, _)) = self.creature.carrying().iter().find(|(_, q)| q.0 > 0) {
+            // Left storages are excluded by finder, so only new home storages are used
+            if let Some(storage) = finder.drop_off(self.creature.point(), material).first() {
+                return self.new_action(Action::DropOff(DropOff::new(
+                    *self.creature.id(),
+                    *storage,
+                    *material,
+                )));
+            }
+
+            // Wait new home storages are built (or have room)
+            return vec![];
+        }
+
+        // Only take what new home storages can receive
+        for (point, material) in left_stocks(self.state, self.creature.tribe_id()) {
+            if !finder.drop_off(&point, &material).is_empty() {
+                return self.new_action(Action::PickUp(PickUp::new(
+                    *self.creature.id(),
+                    point,
+                    material,
+                    self.creature.can_carry(&material),
+                )));
+            }
+        }
+
+        self.done()
+    }
+}
+
+/// Transportable stocks remaining in structures left by migrating tribe
+pub fn left_stocks(state: &State, tribe_id: &TribeId) -> Vec<(AbsoluteWorldPoint, Material)> {
+    let game = state.game();
+    let world = state.world();
+
+    game.migration(tribe_id)
+        .map(|migration| migration.left().to_vec())
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|point| {
+            world
+                .materials_on(point, None)
+                .iter()
+                .filter(|(material, quantity)| material.transportable() && quantity.0 > 0)
+                .map(|(material, _)| (*point, *material))
+                .collect::<Vec<(AbsoluteWorldPoint, Material)>>()
+        })
+        .collect()
+}
@@ -1,35 +1,98 @@
-use neoroll_world::{entity::creature::CreatureId, gameplay::job::Job};
+use build::RealizeBuild;
+use craft::RealizeCraft;
+use explore::RealizeExplore;
+use farm::RealizeFarm;
+use hunt::RealizeHunt;
+use idle::RealizeIdle;
+use migrate::RealizeMigrate;
+use neoroll_world::{
+    entity::creature::CreatureId,
+    gameplay::{config::gameplay, job::Job},
+};
+use order::RealizeOrder;
+use plant::RealizePlant;
+use scripted::RealizeScripted;
 use search::RealizeSearchResource;
 
 use crate::{
     action::{Action, ActionId, BodyTick, NextTick},
-    run::TICK_BASE_PERIOD,
     state::{State, StateChange},
 };
 
+pub mod build;
+pub mod craft;
+pub mod explore;
+pub mod farm;
+pub mod hunt;
+pub mod idle;
+pub mod migrate;
+pub mod order;
+pub mod plant;
+pub mod scripted;
 pub mod search;
 
-const TICK_FREQUENCY: u64 = TICK_BASE_PERIOD;
-
 #[derive(Debug, PartialEq)]
 pub struct RealizeJob {
     creature_id: CreatureId,
 }
 
+impl RealizeJob {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+}
+
 impl BodyTick<RealizeJobChange> for RealizeJob {
     fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
         let mut changes = vec![];
         let world = state.world();
         let creature = world.creatures().get(&self.creature_id).unwrap();
 
+        // Player direct orders override the job, even outside work hours
+        if let Some(order) = state.game().order(&self.creature_id) {
+            changes.extend(RealizeOrder::new(creature, state, order).changes());
+            return (
+                NextTick(*state.frame_i() + gameplay().ticks.realize_job),
+                changes,
+            );
+        }
+
+        // Outside work hours, creatures put their stuff away and rest
+        if !state.calendar().is_work_hour() {
+            changes.extend(RealizeIdle::new(creature, state).changes());
+            return (
+                NextTick(*state.frame_i() + gameplay().ticks.realize_job),
+                changes,
+            );
+        }
+
         match creature.job() {
-            Job::Idle => {}
+            Job::Idle => changes.extend(RealizeIdle::new(creature, state).changes()),
             Job::SearchResource(resource) => {
                 changes.extend(RealizeSearchResource::new(creature, state, *resource).changes())
             }
+            Job::Build(point) => {
+                changes.extend(RealizeBuild::new(creature, state, *point).changes())
+            }
+            Job::Explore(radius) => {
+                changes.extend(RealizeExplore::new(creature, state, *radius).changes())
+            }
+            Job::Craft(crafted) => {
+                changes.extend(RealizeCraft::new(creature, state, *crafted).changes())
+            }
+            Job::Hunt => changes.extend(RealizeHunt::new(creature, state).changes()),
+            Job::Migrate => changes.extend(RealizeMigrate::new(creature, state).changes()),
+            Job::Plant => changes.extend(RealizePlant::new(creature, state).changes()),
+            Job::Farm => changes.extend(RealizeFarm::new(creature, state).changes()),
+            Job::Scripted(name) => {
+                changes.extend(RealizeScripted::new(creature, state, name).changes())
+            }
         }
 
-        (NextTick(*state.frame_i() + TICK_FREQUENCY), changes)
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.realize_job),
+            changes,
+        )
     }
 
     fn apply(&mut self, _change: RealizeJobChange) {}
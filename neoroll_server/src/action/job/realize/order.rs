@@ -0,0 +1,273 @@
+use neoroll_world::{
+    entity::creature::Creature,
+    gameplay::{
+        behavior::Behavior,
+        material::{Material, Resource},
+        order::Order,
+        CollectType,
+    },
+    space::AbsoluteWorldPoint,
+    utils::Direction,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    action::{
+        collect::CollectBuilder, drop::DropOff, move_::MoveTo, Action, ActionChange, ActionId,
+    },
+    state::{
+        game::{GameChange, OrderChange},
+        State, StateChange,
+    },
+};
+
+/// Realize player direct order instead of creature job
+pub struct RealizeOrder<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+    order: &'a Order,
+}
+
+impl<'a> RealizeOrder<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State, order: &'a Order) -> Self {
+        Self {
+            creature,
+            state,
+            order,
+        }
+    }
+
+    fn new_action(&self, action: Action) -> Vec<StateChange> {
+        vec![StateChange::Action(
+            ActionId::new(),
+            ActionChange::New(action),
+        )]
+    }
+
+    /// Order is completed (or can't be completed): creature go back to its job
+    fn done(&self) -> Vec<StateChange> {
+        vec![StateChange::Game(GameChange::Order(
+            *self.creature.id(),
+            OrderChange::Remove,
+        ))]
+    }
+
+    fn move_to(&self, point: &AbsoluteWorldPoint) -> Vec<StateChange> {
+        let reachable = self
+            .state
+            .world()
+            .find_path(self.creature.point(), point)
+            .is_some();
+        if !reachable {
+            return self.done();
+        }
+
+        self.new_action(Action::MoveTo(MoveTo::new(*self.creature.id(), *point)))
+    }
+
+    fn collect(&self, point: &AbsoluteWorldPoint, resource: Resource) -> Vec<StateChange> {
+        let collect_type: CollectType = resource.into();
+        let creature_point = self.creature.point();
+        let (collectable, stand) = {
+            let world = self.state.world();
+            let collectable = world.can_collect(point, collect_type);
+            // Water resources are collected from an adjacent walkable tile
+            let stand = if world.can_walk(point) {
+                Some(*point)
+            } else if collect_type.from_adjacent() {
+                Direction::iter()
+                    .map(|direction| point.next(&direction))
+                    .filter(|point| world.can_walk(point))
+                    .min_by_key(|point| point.squared_distance(creature_point))
+            } else {
+                None
+            };
+            (collectable, stand)
+        };
+
+        if !collectable || self.creature.cant_carry_more(&Material::Resource(resource)) {
+            return self.done();
+        }
+
+        match stand {
+            Some(stand) if &stand == creature_point => {
+                self.new_action(CollectBuilder::new(*self.creature.id(), resource).build())
+            }
+            Some(stand) => self.move_to(&stand),
+            None => self.done(),
+        }
+    }
+
+    fn drop_off(&self, storage: &AbsoluteWorldPoint) -> Vec<StateChange> {
+        // Only materials which fit in storage can be dropped off
+        let material = self
+            .creature
+            .carrying()
+            .iter()
+            .map(|(material, _)| *material)
+            .find(|material| {
+                self.state
+                    .world()
+                    .storage_room(storage, material)
+                    .map(|room| room.0 > 0)
+                    .unwrap_or(false)
+            });
+
+        match material {
+            Some(material) => self.new_action(Action::DropOff(DropOff::new(
+                *self.creature.id(),
+                *storage,
+                material,
+            ))),
+            None => self.done(),
+        }
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        match self.order {
+            Order::MoveTo(point) => {
+                if self.creature.point() == point {
+                    return self.done();
+                }
+                self.move_to(point)
+            }
+            Order::Collect(point, resource) => self.collect(point, *resource),
+            Order::DropOff(storage) => self.drop_off(storage),
+            Order::Idle => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::{creature::CreatureId, ground::Ground, Filled},
+        gameplay::{job::Job, tribe::TribeId},
+    };
+    use rstest::*;
+
+    use crate::{
+        action::{job::realize::RealizeJob, BodyTick},
+        state::game::GameState,
+        testing::{self, apply, creature, point, run},
+    };
+
+    use super::*;
+
+    /// State with a flint searcher at (0, 0) of a one line world having flint at its end
+    fn state() -> (State, CreatureId) {
+        let mut creature = creature(TribeId::new(), point(0, 0));
+        let creature_id = *creature.id();
+        creature.set_job(Job::SearchResource(Resource::RawFlint));
+        let mut world = testing::world(1, 5, vec![creature]);
+        world.set_ground(point(0, 4), Ground::SoilFlint(Filled::full()));
+
+        (testing::state(world, GameState::default()), creature_id)
+    }
+
+    fn set_order(state: &mut State, creature_id: CreatureId, order: OrderChange) {
+        apply(
+            state,
+            vec![StateChange::Game(GameChange::Order(creature_id, order))],
+        );
+    }
+
+    fn realize(state: &State, creature_id: CreatureId) -> Vec<StateChange> {
+        RealizeJob { creature_id }.tick(ActionId::new(), state).1
+    }
+
+    fn new_actions(changes: &[StateChange]) -> Vec<&Action> {
+        changes
+            .iter()
+            .filter_map(|change| match change {
+                StateChange::Action(_, ActionChange::New(action)) => Some(action),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_order_overrides_job() {
+        // Given
+        let (mut state, creature_id) = state();
+        set_order(&mut state, creature_id, OrderChange::Set(Order::Idle));
+
+        // When
+        let changes = realize(&state, creature_id);
+
+        // Then
+        assert!(changes.is_empty());
+    }
+
+    #[rstest]
+    fn test_order_completed() {
+        // Given
+        let (mut state, creature_id) = state();
+        set_order(
+            &mut state,
+            creature_id,
+            OrderChange::Set(Order::MoveTo(point(0, 2))),
+        );
+        apply(
+            &mut state,
+            vec![StateChange::Action(
+                ActionId::new(),
+                ActionChange::New(Action::RealizeJob(RealizeJob { creature_id })),
+            )],
+        );
+
+        // When
+        run(&mut state, 300);
+
+        // Then
+        let world = state.world();
+        let creature = world.creatures().get(&creature_id).unwrap();
+        assert!(state.game().order(&creature_id).is_none());
+        // Creature went back to its job
+        assert_eq!(creature.job(), &Job::SearchResource(Resource::RawFlint));
+        assert_ne!(creature.point(), &point(0, 0));
+    }
+
+    #[rstest]
+    fn test_order_done_when_unreachable() {
+        // Given
+        let (mut state, creature_id) = state();
+        set_order(
+            &mut state,
+            creature_id,
+            OrderChange::Set(Order::MoveTo(point(5, 5))),
+        );
+
+        // When
+        let changes = realize(&state, creature_id);
+
+        // Then
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StateChange::Game(GameChange::Order(_, OrderChange::Remove))
+        )));
+    }
+
+    #[rstest]
+    fn test_order_canceled() {
+        // Given
+        let (mut state, creature_id) = state();
+        set_order(&mut state, creature_id, OrderChange::Set(Order::Idle));
+
+        // When
+        set_order(&mut state, creature_id, OrderChange::Remove);
+        let changes = realize(&state, creature_id);
+
+        // Then
+        assert!(state.game().order(&creature_id).is_none());
+        assert!(matches!(
+            new_actions(&changes)[..],
+            [Action::MoveTo(_)] | [Action::Collect(_)]
+        ));
+    }
+}
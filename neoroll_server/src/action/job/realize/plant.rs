@@ -0,0 +1,225 @@
+use neoroll_world::{
+    entity::{
+        creature::{Creature, CreatureChange},
+        structure::Structure,
+        Filled,
+    },
+    gameplay::{behavior::Behavior, job::Job, skill::Skill},
+    space::{
+        world::{StructureChange, World, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::{
+    action::{move_::MoveTo, Action, ActionChange, ActionId},
+    state::{State, StateChange},
+};
+
+/// Radius around campfire where tribe plants its forest
+pub const PLANT_RADIUS: u32 = 10;
+const PRACTICE_EXPERIENCE: u32 = 5;
+/// Destinations tried by tick
+const MAX_TRIES: usize = 5;
+
+/// Trees (saplings included) around given center, in [PLANT_RADIUS]
+pub fn trees_around(world: &World, center: &AbsoluteWorldPoint) -> usize {
+    center
+        .around(PLANT_RADIUS)
+        .iter()
+        .filter(|point| {
+            world
+                .structure(point)
+                .as_ref()
+                .map(|s| s.is_tree())
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+pub struct RealizePlant<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+}
+
+impl<'a> RealizePlant<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State) -> Self {
+        Self { creature, state }
+    }
+
+    fn done(&self) -> Vec<StateChange> {
+        vec![StateChange::World(WorldChange::Creature(
+            *self.creature.id(),
+            CreatureChange::SetJob(Job::Idle),
+        ))]
+    }
+
+    fn plant(&self) -> Vec<StateChange> {
+        vec![
+            StateChange::World(WorldChange::Structure(
+                *self.creature.point(),
+                StructureChange::Set(Some(Structure::Sapling(Filled::empty()))),
+            )),
+            StateChange::World(WorldChange::Creature(
+                *self.creature.id(),
+                CreatureChange::Practice(Skill::Gathering, PRACTICE_EXPERIENCE),
+            )),
+        ]
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        let campfire = match self.state.game().tribe_campfire(self.creature.tribe_id()) {
+            Some(campfire) => campfire,
+            None => return self.done(),
+        };
+
+        let world = self.state.world();
+        // Trees are not planted next to the campfire to let place to other structures
+        let plantable = |point: &AbsoluteWorldPoint| {
+            point.squared_distance(&campfire) > 2 && world.can_plant(point)
+        };
+
+        if plantable(self.creature.point()) {
+            return self.plant();
+        }
+
+        let mut points = campfire
+            .around(PLANT_RADIUS)
+            .into_iter()
+            .filter(plantable)
+            .collect::<Vec<AbsoluteWorldPoint>>();
+        points.sort_by_key(|point| point.squared_distance(self.creature.point()));
+
+        for point in points.into_iter().take(MAX_TRIES) {
+            if world.find_path(self.creature.point(), &point).is_some() {
+                return vec![StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::MoveTo(MoveTo::new(*self.creature.id(), point))),
+                )];
+            }
+        }
+
+        self.done()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{entity::creature::CreatureId, gameplay::tribe::TribeId};
+    use rstest::*;
+
+    use crate::{
+        state::game::GameState,
+        testing::{self, creature, own, point},
+    };
+
+    use super::*;
+
+    const SIZE: usize = 7;
+
+    fn world(creatures: Vec<Creature>) -> World {
+        testing::world(SIZE, SIZE, creatures)
+    }
+
+    /// State with a creature of a tribe having (or not) its campfire at center
+    fn state(creature_point: AbsoluteWorldPoint, campfire: bool) -> (State, CreatureId) {
+        let tribe_id = TribeId::new();
+        let mut creature = creature(tribe_id, creature_point);
+        let creature_id = *creature.id();
+        creature.set_job(Job::Plant);
+        let mut world = world(vec![creature]);
+        let mut game = GameState::default();
+        if campfire {
+            own(
+                &mut world,
+                &mut game,
+                tribe_id,
+                Structure::Campfire,
+                point(3, 3),
+            );
+        }
+
+        (testing::state(world, game), creature_id)
+    }
+
+    #[test]
+    fn test_trees_around() {
+        // Given
+        let mut world = world(vec![]);
+        world.set_structure(point(0, 0), Some(Structure::BigLeafTree(Filled::full())));
+        world.set_structure(
+            point(1, 2),
+            Some(Structure::FruitTree(Filled::full(), Filled::full())),
+        );
+        world.set_structure(point(5, 6), Some(Structure::Sapling(Filled::empty())));
+        world.set_structure(point(4, 4), Some(Structure::Stump));
+        world.set_structure(point(3, 3), Some(Structure::Campfire));
+
+        // When
+        let trees = trees_around(&world, &point(3, 3));
+
+        // Then
+        assert_eq!(trees, 3);
+    }
+
+    #[rstest]
+    // Far enough from campfire: plant here
+    #[case(point(3, 5), Some(point(3, 5)), None)]
+    // Too close from campfire: move to nearest plantable point
+    #[case(point(3, 4), None, Some(point(3, 5)))]
+    #[case(point(2, 2), None, Some(point(1, 2)))]
+    fn test_plant_target(
+        #[case] creature_point: AbsoluteWorldPoint,
+        #[case] expected_plant: Option<AbsoluteWorldPoint>,
+        #[case] expected_move: Option<AbsoluteWorldPoint>,
+    ) {
+        // Given
+        let (state, creature_id) = state(creature_point, true);
+        let creature = state.world().creatures().get(&creature_id).unwrap().clone();
+
+        // When
+        let changes = RealizePlant::new(&creature, &state).changes();
+
+        // Then
+        let plant = changes.iter().find_map(|change| match change {
+            StateChange::World(WorldChange::Structure(
+                point,
+                StructureChange::Set(Some(Structure::Sapling(_))),
+            )) => Some(*point),
+            _ => None,
+        });
+        let move_ = changes.iter().find_map(|change| match change {
+            StateChange::Action(_, ActionChange::New(action)) => Some(action),
+            _ => None,
+        });
+        assert_eq!(plant, expected_plant);
+        assert_eq!(
+            move_,
+            expected_move
+                .map(|point| Action::MoveTo(MoveTo::new(creature_id, point)))
+                .as_ref()
+        );
+    }
+
+    #[test]
+    fn test_plant_without_campfire() {
+        // Given
+        let (state, creature_id) = state(point(3, 5), false);
+        let creature = state.world().creatures().get(&creature_id).unwrap().clone();
+
+        // When
+        let changes = RealizePlant::new(&creature, &state).changes();
+
+        // Then
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            changes[0],
+            StateChange::World(WorldChange::Creature(_, CreatureChange::SetJob(Job::Idle)))
+        ));
+    }
+}
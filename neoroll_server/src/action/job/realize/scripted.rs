@@ -0,0 +1,30 @@
+use neoroll_world::{entity::creature::Creature, gameplay::behavior::Behavior};
+
+use crate::state::{State, StateChange};
+
+/// Work for a scenario script target : its job function decides what to do
+pub struct RealizeScripted<'a> {
+    creature: &'a Creature,
+    state: &'a State,
+    name: &'a str,
+}
+
+impl<'a> RealizeScripted<'a> {
+    pub fn new(creature: &'a Creature, state: &'a State, name: &'a str) -> Self {
+        Self {
+            creature,
+            state,
+            name,
+        }
+    }
+
+    pub fn changes(&self) -> Vec<StateChange> {
+        // Wait current action end
+        if !matches!(self.creature.behavior(), Behavior::Idle) {
+            return vec![];
+        }
+
+        let scripts = self.state.game().scripts();
+        scripts.work(self.state, self.creature, self.name)
+    }
+}
@@ -1,13 +1,10 @@
-use std::sync::RwLockReadGuard;
-
 use neoroll_world::{
-    entity::{creature::Creature, structure::Structure},
+    entity::creature::Creature,
     gameplay::{
         behavior::Behavior,
-        job::Job,
+        job::{requirement::JobRequirement, Job},
         material::{Material, Resource},
         target::Target,
-        tribe::{structure::StructureOwn, TribeId},
     },
     map::find::AroundTileFinder,
     space::AbsoluteWorldPoint,
@@ -15,9 +12,11 @@ use neoroll_world::{
 
 use crate::{
     action::{
-        collect::CollectBuilder, drop::DropOff, move_::MoveTo, Action, ActionChange, ActionId,
+        collect::CollectBuilder, drop::DropOff, move_::MoveTo, pick::PickUp, Action, ActionChange,
+        ActionId,
     },
-    state::{game::GameState, State, StateChange},
+    state::{State, StateChange},
+    storage::StorageFinder,
 };
 
 pub struct RealizeSearchResource<'a> {
@@ -38,20 +37,60 @@ impl<'a> RealizeSearchResource<'a> {
     fn can_collect(&self) -> bool {
         self.state
             .world()
-            .can_collect(self.creature.point(), self.resource.into())
+            .collect_point(self.creature.point(), self.resource.into())
+            .is_some()
+    }
+
+    /// Fruits can't be found during some seasons
+    fn in_season(&self) -> bool {
+        Job::SearchResource(self.resource).in_season(&self.state.calendar().season())
     }
 
-    fn solving(&self) -> bool {
-        for _requirement in Job::SearchResource(self.resource).requirements() {
-            // FIXME: for SearchQualityWood, must own or going to pick up axe
+    /// Return changes to apply to satisfy job requirements, or None if all
+    /// requirements are already satisfied (creature carry required materials)
+    fn solving(&self) -> Option<Vec<StateChange>> {
+        for requirement in Job::SearchResource(self.resource).requirements() {
+            match requirement {
+                JobRequirement::Material(material, quantity) => {
+                    let carrying = self.creature.carrying_quantity(material);
+                    if carrying.0 >= quantity.0 {
+                        continue;
+                    }
+
+                    if self.picking_up() || self.moving_to() || self.dropping_off() {
+                        return Some(vec![]);
+                    }
+
+                    let missing = material.quantity(quantity.0 - carrying.0);
+                    if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+                        .pick_up(self.creature.point(), &material, &missing)
+                        .first()
+                    {
+                        let action_id = ActionId::new();
+                        let action = Action::PickUp(PickUp::new(
+                            *self.creature.id(),
+                            *storage,
+                            material,
+                            missing,
+                        ));
+                        return Some(vec![StateChange::Action(
+                            action_id,
+                            ActionChange::New(action),
+                        )]);
+                    }
+
+                    // Nothing to do while required material is not available
+                    return Some(vec![]);
+                }
+            }
         }
 
-        true
+        None
     }
 
     fn carrying(&self) -> bool {
         self.creature
-            .carrying_quantity(Some(Material::Resource(self.resource)))
+            .carrying_quantity(Material::Resource(self.resource))
             .0
             > 0
     }
@@ -64,6 +103,10 @@ impl<'a> RealizeSearchResource<'a> {
         matches!(self.creature.behavior(), Behavior::DropOff)
     }
 
+    fn picking_up(&self) -> bool {
+        matches!(self.creature.behavior(), Behavior::PickUp)
+    }
+
     fn moving_to(&self) -> bool {
         matches!(self.creature.behavior(), Behavior::MoveTo)
     }
@@ -71,23 +114,15 @@ impl<'a> RealizeSearchResource<'a> {
     fn carrying_enough(&self) -> bool {
         let carrying_quantity = self
             .creature
-            .carrying_quantity(Some(Material::Resource(self.resource)))
+            .carrying_quantity(Material::Resource(self.resource))
             .0;
         let enough_quantity =
-            Target::KeepStock(Material::Resource(self.resource), Default::default())
-                .carrying_enough_quantity()
-                .0;
+            Target::carrying_enough_quantity(&Material::Resource(self.resource)).0;
 
         carrying_quantity >= enough_quantity
-    }
-
-    pub fn nearest_storages(
-        &'a self,
-        tribe_id: &TribeId,
-        game: &'a RwLockReadGuard<GameState>,
-    ) -> Vec<&StructureOwn> {
-        // FIXME BS NOW: order by distance
-        game.tribe_structures(tribe_id, Some(Structure::Storage))
+            || self
+                .creature
+                .cant_carry_more(&Material::Resource(self.resource))
     }
 
     pub fn find_collect_tile_point(&self) -> Option<AbsoluteWorldPoint> {
@@ -97,7 +132,10 @@ impl<'a> RealizeSearchResource<'a> {
     }
 
     pub fn changes(&self) -> Vec<StateChange> {
-        let _solving = self.solving();
+        if let Some(changes) = self.solving() {
+            return changes;
+        }
+
         let carrying = self.carrying();
         let collecting = self.collecting();
         let moving_to = self.moving_to();
@@ -105,19 +143,25 @@ impl<'a> RealizeSearchResource<'a> {
         let carrying_enough = self.carrying_enough();
 
         if !dropping_off && carrying && carrying_enough && !moving_to {
-            let tribe_id = self.creature.tribe_id();
-            let game = self.state.game();
-            if let Some(storage) = self.nearest_storages(tribe_id, &game).first() {
+            let material = Material::Resource(self.resource);
+            if let Some(storage) = StorageFinder::new(self.state, *self.creature.tribe_id())
+                .drop_off(self.creature.point(), &material)
+                .first()
+            {
                 let action_id = ActionId::new();
                 let action = Action::DropOff(DropOff::new(
                     *self.creature.id(),
-                    *storage.point(),
+                    *storage,
                     Material::Resource(self.resource),
                 ));
                 return vec![StateChange::Action(action_id, ActionChange::New(action))];
             }
         }
 
+        if !self.in_season() {
+            return vec![];
+        }
+
         if self.can_collect() && !collecting && !dropping_off && !moving_to {
             let action_id = ActionId::new();
             let action = CollectBuilder::new(*self.creature.id(), self.resource).build();
@@ -136,3 +180,107 @@ impl<'a> RealizeSearchResource<'a> {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::{structure::Structure, Filled},
+        gameplay::{tribe::TribeId, Quantity},
+    };
+    use rstest::*;
+
+    use crate::{
+        state::game::GameState,
+        testing::{self, apply, creature, own, point, run},
+    };
+
+    use super::*;
+
+    fn flint() -> Material {
+        Material::Resource(Resource::RawFlint)
+    }
+
+    fn new_action(changes: &[StateChange]) -> Option<&Action> {
+        changes.iter().find_map(|change| match change {
+            StateChange::Action(_, ActionChange::New(action)) => Some(action),
+            _ => None,
+        })
+    }
+
+    #[rstest]
+    fn test_fetch_tool_then_work() {
+        // Given
+        let tribe_id = TribeId::new();
+        let mut creature = creature(tribe_id, point(0, 2));
+        let creature_id = *creature.id();
+        creature.set_job(Job::SearchResource(Resource::Branches));
+        let mut world = testing::world(1, 6, vec![creature]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(0, 0),
+        );
+        world
+            .add_material(point(0, 0), flint(), Quantity::units(1))
+            .unwrap();
+        world.set_structure(point(0, 5), Some(Structure::BigLeafTree(Filled::full())));
+        let mut state = testing::state(world, game);
+        let changes = |state: &State| {
+            let world = state.world();
+            let creature = world.creatures().get(&creature_id).unwrap();
+            RealizeSearchResource::new(creature, state, Resource::Branches).changes()
+        };
+
+        // When
+        let fetch = changes(&state);
+
+        // Then
+        assert!(matches!(
+            new_action(&fetch),
+            Some(Action::PickUp(pick_up)) if pick_up.point() == &point(0, 0)
+        ));
+
+        // When
+        apply(&mut state, fetch);
+        run(&mut state, 500);
+        let work = changes(&state);
+
+        // Then
+        let world = state.world();
+        let creature = world.creatures().get(&creature_id).unwrap();
+        assert_eq!(creature.carrying_quantity(flint()), Quantity::units(1));
+        assert_eq!(world.material_quantity(&point(0, 0), flint()).0, 0);
+        assert!(matches!(
+            new_action(&work),
+            Some(Action::MoveTo(_)) | Some(Action::Collect(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_wait_for_missing_tool() {
+        // Given
+        let tribe_id = TribeId::new();
+        let mut creature = creature(tribe_id, point(0, 2));
+        creature.set_job(Job::SearchResource(Resource::Branches));
+        let mut world = testing::world(1, 6, vec![creature.clone()]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(0, 0),
+        );
+        world.set_structure(point(0, 5), Some(Structure::BigLeafTree(Filled::full())));
+        let state = testing::state(world, game);
+
+        // When
+        let changes = RealizeSearchResource::new(&creature, &state, Resource::Branches).changes();
+
+        // Then
+        assert!(changes.is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+use neoroll_world::{
+    entity::{
+        creature::{Creature, CreatureChange},
+        structure::Structure,
+    },
+    gameplay::{
+        calendar::{FRAMES_PER_HOUR, HOURS_PER_DAY},
+        lifecycle::{daily_food, foods, Age, Hunger},
+        material::Material,
+        tribe::TribeId,
+    },
+    space::{
+        world::{MaterialChange, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+use rand::Rng;
+
+use crate::{
+    shortcut,
+    state::{State, StateChange},
+};
+
+use super::{ActionChange, ActionId, BodyTick, NextTick};
+
+/// Lifecycle is resolved once per in game day
+const TICK_PERIOD: u64 = FRAMES_PER_HOUR * HOURS_PER_DAY;
+/// Daily chance for an old creature to die
+const OLD_AGE_DEATH_CHANCE: f64 = 0.01;
+/// Daily chance of a birth in a tribe which can afford it
+const BIRTH_CHANCE: f64 = 0.05;
+/// Days of food per creature which must remain in storages to give birth
+const BIRTH_FOOD_DAYS: u64 = 10;
+
+/// Make tribe creatures grow old, eat, die and give birth
+// TODO: make it an action per tribe
+#[derive(Debug, PartialEq)]
+pub struct Lifecycle;
+
+impl Lifecycle {
+    /// Food available in tribe storages
+    fn food_stocks(
+        &self,
+        tribe_id: &TribeId,
+        state: &State,
+    ) -> Vec<(AbsoluteWorldPoint, Material, u64)> {
+        let world = state.world();
+        let mut stocks = vec![];
+
+        for storage in state
+            .game()
+            .tribe_structures(tribe_id, Some(Structure::Storage))
+        {
+            for food in foods() {
+                let quantity: u64 = world
+                    .materials_on(storage.point(), Some(food))
+                    .iter()
+                    .map(|(_, quantity)| quantity.0)
+                    .sum();
+                if quantity > 0 {
+                    stocks.push((*storage.point(), food, quantity));
+                }
+            }
+        }
+
+        stocks
+    }
+
+    /// Eat carried food, or food from storages. Return false if there is nothing to eat.
+    fn eat(
+        &self,
+        creature: &Creature,
+        stocks: &mut [(AbsoluteWorldPoint, Material, u64)],
+        changes: &mut Vec<StateChange>,
+    ) -> bool {
+        let ration = daily_food();
+
+        if let Some(food) = foods()
+            .into_iter()
+            .find(|food| creature.carrying_quantity(*food).0 >= ration.0)
+        {
+            changes.push(StateChange::World(WorldChange::Creature(
+                *creature.id(),
+                CreatureChange::RemoveFromCarrying(food, ration),
+            )));
+            return true;
+        }
+
+        if let Some((point, food, quantity)) = stocks
+            .iter_mut()
+            .find(|(_, _, quantity)| *quantity >= ration.0)
+        {
+            *quantity -= ration.0;
+            changes.push(StateChange::World(WorldChange::Material(
+                *point,
+                MaterialChange::Remove(*food, ration),
+            )));
+            return true;
+        }
+
+        false
+    }
+
+    fn die(&self, creature: &Creature, state: &State) -> Vec<StateChange> {
+        let mut changes = vec![];
+
+        // Running actions of the creature are stopped before it disappears
+        for id in state.creature_actions(creature.id()) {
+            changes.push(StateChange::Action(id, ActionChange::Remove));
+        }
+
+        // Carried materials are left on the ground
+        for (material, quantity) in creature.carrying() {
+            if quantity.0 > 0 {
+                changes.push(StateChange::World(WorldChange::Material(
+                    *creature.point(),
+                    MaterialChange::Add(*material, quantity.clone()),
+                )));
+            }
+        }
+        changes.push(StateChange::World(WorldChange::Creature(
+            *creature.id(),
+            CreatureChange::Remove,
+        )));
+
+        changes
+    }
+
+    fn tick_tribe(&self, tribe_id: &TribeId, state: &State) -> Vec<StateChange> {
+        let mut rng = rand::thread_rng();
+        let mut changes = vec![];
+        let mut stocks = self.food_stocks(tribe_id, state);
+        let world = state.world();
+        let mut alive = 0;
+        let mut adults = 0;
+
+        for creature in world.tribe_creatures(tribe_id) {
+            let age = creature.age().older();
+            let hunger = if self.eat(creature, &mut stocks, &mut changes) {
+                Hunger(0)
+            } else {
+                Hunger(creature.hunger().0 + 1)
+            };
+
+            if hunger.starved() || (age.is_old() && rng.gen_bool(OLD_AGE_DEATH_CHANCE)) {
+                changes.extend(self.die(creature, state));
+                continue;
+            }
+
+            changes.push(StateChange::World(WorldChange::Creature(
+                *creature.id(),
+                CreatureChange::SetAge(age),
+            )));
+            if &hunger != creature.hunger() {
+                changes.push(StateChange::World(WorldChange::Creature(
+                    *creature.id(),
+                    CreatureChange::SetHunger(hunger),
+                )));
+            }
+
+            alive += 1;
+            if age.is_adult() {
+                adults += 1;
+            }
+        }
+
+        // A birth needs two adults and enough food to feed everyone
+        let food: u64 = stocks.iter().map(|(_, _, quantity)| quantity).sum();
+        let food_required = BIRTH_FOOD_DAYS * daily_food().0 * (alive + 1);
+        if adults >= 2 && food >= food_required && rng.gen_bool(BIRTH_CHANCE) {
+            if let Some(home) = state.game().tribe_home(tribe_id) {
+                changes.extend(shortcut::creature::new_creature(
+                    *tribe_id,
+                    home,
+                    Age::default(),
+                ));
+            }
+        }
+
+        changes
+    }
+}
+
+impl BodyTick<LifecycleChange> for Lifecycle {
+    fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let mut changes = vec![];
+
+        for tribe_id in state.game().tribe_ids() {
+            changes.extend(self.tick_tribe(&tribe_id, state))
+        }
+
+        (NextTick(*state.frame_i() + TICK_PERIOD), changes)
+    }
+
+    fn apply(&mut self, _change: LifecycleChange) {}
+}
+
+#[derive(Debug)]
+pub enum LifecycleChange {}
+
+#[cfg(test)]
+mod test {
+    use rstest::*;
+
+    use crate::{
+        action::{
+            move_::{MoveTo, MoveToChange},
+            Action, UpdateAction,
+        },
+        state::FrameI,
+        testing::{self, apply, point},
+    };
+
+    use super::*;
+
+    #[rstest]
+    fn test_die_with_running_action() {
+        // Given
+        let point = point(0, 0);
+        let creature = testing::creature(TribeId::new(), point);
+        let creature_id = *creature.id();
+        let world = testing::world(1, 1, vec![creature.clone()]);
+        let mut state = testing::state(world, Default::default());
+        let action_id = ActionId::new();
+        apply(
+            &mut state,
+            vec![StateChange::Action(
+                action_id,
+                ActionChange::New(Action::MoveTo(MoveTo::new(creature_id, point))),
+            )],
+        );
+
+        // When
+        let mut changes = Lifecycle.die(&creature, &state);
+        // Changes of same batch produced by the action tick
+        changes.extend(vec![
+            StateChange::Action(
+                action_id,
+                ActionChange::SetNextTick(NextTick::new(FrameI(1))),
+            ),
+            StateChange::Action(
+                action_id,
+                ActionChange::Update(UpdateAction::MoveTo(MoveToChange::SetPath(None))),
+            ),
+            StateChange::Action(
+                ActionId::new(),
+                ActionChange::New(Action::MoveTo(MoveTo::new(creature_id, point))),
+            ),
+        ]);
+        apply(&mut state, changes);
+
+        // Then
+        assert!(state.world().creatures().get(&creature_id).is_none());
+        assert!(state.creature_actions(&creature_id).is_empty());
+    }
+}
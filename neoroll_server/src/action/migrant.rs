@@ -1,14 +1,17 @@
-use neoroll_world::{entity::structure::Structure, gameplay::tribe::TribeId};
+use std::ops::Range;
+
+use neoroll_world::gameplay::{config::gameplay, lifecycle::Age, tribe::TribeId};
+use rand::Rng;
 
 use crate::{
-    run::TICK_BASE_PERIOD,
     shortcut,
     state::{State, StateChange},
 };
 
 use super::{ActionId, BodyTick, NextTick};
 
-const TICK_PERIOD: u64 = TICK_BASE_PERIOD * 5;
+/// Migrants are young adults
+const MIGRANT_YEARS: Range<u64> = 16..30;
 
 // TODO: make it an action per tribe
 #[derive(Debug, PartialEq)]
@@ -17,7 +20,8 @@ pub struct IncomingMigrant;
 impl IncomingMigrant {
     fn tick_tribe(&self, tribe_id: &TribeId, state: &State) -> Vec<StateChange> {
         let mut changes = vec![];
-        let max_population = 1; // Later, it will be computed by complex algorithm
+        // A founding couple, able to give birth. Later, it will be computed by complex algorithm
+        let max_population = 2;
         let population_count = state
             .world()
             .tribe_creature_ids(tribe_id)
@@ -25,15 +29,10 @@ impl IncomingMigrant {
             .len();
 
         if population_count < max_population {
-            if let Some(campfire) = state
-                .game()
-                .tribe_structures(tribe_id, Some(Structure::Campfire))
-                .first()
-            {
-                changes.extend(shortcut::creature::new_creature(
-                    *tribe_id,
-                    *campfire.point(),
-                ));
+            // Migrants join the tribe where it lives (or where it goes)
+            if let Some(home) = state.game().tribe_home(tribe_id) {
+                let age = Age::from_years(rand::thread_rng().gen_range(MIGRANT_YEARS));
+                changes.extend(shortcut::creature::new_creature(*tribe_id, home, age));
             }
         }
 
@@ -49,7 +48,10 @@ impl BodyTick<IncomingMigrantChange> for IncomingMigrant {
             changes.extend(self.tick_tribe(&tribe_id, state))
         }
 
-        (NextTick(*state.frame_i() + TICK_PERIOD), changes)
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.migrant),
+            changes,
+        )
     }
 
     fn apply(&mut self, _change: IncomingMigrantChange) {}
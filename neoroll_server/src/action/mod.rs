@@ -1,17 +1,37 @@
+pub mod construct;
+pub mod craft;
+pub mod demolish;
 pub mod drop;
+pub mod hunt;
+pub mod lifecycle;
+pub mod persist;
+pub mod pick;
+pub mod regrowth;
+pub mod script;
 pub mod target;
+pub mod wildlife;
 use client::{ComputeAndSendClientStates, ComputeAndSendClientStatesChange};
 use collect::{CollectChange, CollectResource};
+use construct::{Construct, ConstructChange};
+use craft::{Craft, CraftChange};
+use demolish::{Demolish, DemolishChange};
 use drop::{DropOff, DropOffChange};
+use hunt::{Hunt, HuntChange};
 use job::{
     affect::{AffectJob, AffectJobChange},
     realize::{RealizeJob, RealizeJobChange},
 };
+use lifecycle::{Lifecycle, LifecycleChange};
 use migrant::{IncomingMigrant, IncomingMigrantChange};
 use move_::{MoveRandomly, MoveRandomlyChange, MoveTo, MoveToChange};
-use neoroll_world::space::world::WorldChange;
+use neoroll_world::{entity::creature::CreatureId, space::world::WorldChange};
+use persist::{Persist, PersistChange};
+use pick::{PickUp, PickUpChange};
+use regrowth::{Regrowth, RegrowthChange};
+use script::{RunScripts, RunScriptsChange};
 use target::{ComputeTargets, ComputeTargetsChange};
 use uuid::Uuid;
+use wildlife::{Wildlife, WildlifeChange};
 
 use crate::state::{FrameI, State, StateChange};
 
@@ -36,6 +56,16 @@ pub enum Action {
     RealizeJob(RealizeJob),
     Collect(CollectResource),
     DropOff(DropOff),
+    PickUp(PickUp),
+    Regrowth(Regrowth),
+    Construct(Construct),
+    Demolish(Demolish),
+    Craft(Craft),
+    Hunt(Hunt),
+    Wildlife(Wildlife),
+    Lifecycle(Lifecycle),
+    Persist(Persist),
+    RunScripts(RunScripts),
 }
 
 impl Action {
@@ -51,6 +81,42 @@ impl Action {
             Action::RealizeJob(body) => body.tick(id, state),
             Action::Collect(body) => body.tick(id, state),
             Action::DropOff(body) => body.tick(id, state),
+            Action::PickUp(body) => body.tick(id, state),
+            Action::Regrowth(body) => body.tick(id, state),
+            Action::Construct(body) => body.tick(id, state),
+            Action::Demolish(body) => body.tick(id, state),
+            Action::Craft(body) => body.tick(id, state),
+            Action::Hunt(body) => body.tick(id, state),
+            Action::Wildlife(body) => body.tick(id, state),
+            Action::Lifecycle(body) => body.tick(id, state),
+            Action::Persist(body) => body.tick(id, state),
+            Action::RunScripts(body) => body.tick(id, state),
+        }
+    }
+
+    /// Creature driven by this action, if any
+    pub fn creature_id(&self) -> Option<&CreatureId> {
+        match self {
+            Action::MoveTo(body) => Some(body.creature_id()),
+            Action::MoveRandomly(body) => Some(body.creature_id()),
+            Action::RealizeJob(body) => Some(body.creature_id()),
+            Action::Collect(body) => Some(body.creature_id()),
+            Action::DropOff(body) => Some(body.creature_id()),
+            Action::PickUp(body) => Some(body.creature_id()),
+            Action::Construct(body) => Some(body.creature_id()),
+            Action::Craft(body) => Some(body.creature_id()),
+            Action::Hunt(body) => Some(body.creature_id()),
+            Action::SayHello(_)
+            | Action::ComputeTargets(_)
+            | Action::ComputeAndSendClientStates(_)
+            | Action::IncomingMigrant(_)
+            | Action::AffectJob(_)
+            | Action::Regrowth(_)
+            | Action::Demolish(_)
+            | Action::Wildlife(_)
+            | Action::Lifecycle(_)
+            | Action::Persist(_)
+            | Action::RunScripts(_) => None,
         }
     }
 
@@ -66,6 +132,16 @@ impl Action {
             Action::RealizeJob(body) => body.stamp(),
             Action::Collect(body) => body.stamp(),
             Action::DropOff(body) => body.stamp(),
+            Action::PickUp(body) => body.stamp(),
+            Action::Regrowth(body) => body.stamp(),
+            Action::Construct(body) => body.stamp(),
+            Action::Demolish(body) => body.stamp(),
+            Action::Craft(body) => body.stamp(),
+            Action::Hunt(body) => body.stamp(),
+            Action::Wildlife(body) => body.stamp(),
+            Action::Lifecycle(body) => body.stamp(),
+            Action::Persist(body) => body.stamp(),
+            Action::RunScripts(body) => body.stamp(),
         }
     }
 
@@ -81,6 +157,16 @@ impl Action {
             Action::RealizeJob(body) => body.take_off(),
             Action::Collect(body) => body.take_off(),
             Action::DropOff(body) => body.take_off(),
+            Action::PickUp(body) => body.take_off(),
+            Action::Regrowth(body) => body.take_off(),
+            Action::Construct(body) => body.take_off(),
+            Action::Demolish(body) => body.take_off(),
+            Action::Craft(body) => body.take_off(),
+            Action::Hunt(body) => body.take_off(),
+            Action::Wildlife(body) => body.take_off(),
+            Action::Lifecycle(body) => body.take_off(),
+            Action::Persist(body) => body.take_off(),
+            Action::RunScripts(body) => body.take_off(),
         }
     }
 
@@ -136,6 +222,56 @@ impl Action {
                     body.apply(change)
                 }
             }
+            Action::PickUp(body) => {
+                if let UpdateAction::PickUp(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Regrowth(body) => {
+                if let UpdateAction::Regrowth(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Construct(body) => {
+                if let UpdateAction::Construct(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Demolish(body) => {
+                if let UpdateAction::Demolish(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Craft(body) => {
+                if let UpdateAction::Craft(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Hunt(body) => {
+                if let UpdateAction::Hunt(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Wildlife(body) => {
+                if let UpdateAction::Wildlife(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Lifecycle(body) => {
+                if let UpdateAction::Lifecycle(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::Persist(body) => {
+                if let UpdateAction::Persist(change) = change {
+                    body.apply(change)
+                }
+            }
+            Action::RunScripts(body) => {
+                if let UpdateAction::RunScripts(change) = change {
+                    body.apply(change)
+                }
+            }
         }
     }
 }
@@ -175,6 +311,16 @@ pub enum UpdateAction {
     RealizeJob(RealizeJobChange),
     Collect(CollectChange),
     DropOff(DropOffChange),
+    PickUp(PickUpChange),
+    Regrowth(RegrowthChange),
+    Construct(ConstructChange),
+    Demolish(DemolishChange),
+    Craft(CraftChange),
+    Hunt(HuntChange),
+    Wildlife(WildlifeChange),
+    Lifecycle(LifecycleChange),
+    Persist(PersistChange),
+    RunScripts(RunScriptsChange),
 }
 
 // TODO: move T into Self::Type
@@ -3,21 +3,102 @@ use strum::IntoEnumIterator;
 
 use neoroll_world::{
     entity::creature::{CreatureChange, CreatureId},
-    gameplay::behavior::Behavior,
+    gameplay::{behavior::Behavior, config::gameplay},
     space::{world::WorldChange, AbsoluteWorldPoint},
     utils::Direction,
 };
 
-use crate::{
-    run::TICK_BASE_PERIOD,
-    state::{State, StateChange},
-};
+use crate::state::{State, StateChange};
 
 use super::{Action, ActionChange, ActionId, BodyTick, NextTick, UpdateAction};
 
-const TICK_PERIOD: u64 = TICK_BASE_PERIOD;
+/// Result of one walking step toward an action destination
+pub enum Walk {
+    /// Creature is (or waits to be) on its way, or can't reach destination
+    Step(NextTick, Vec<StateChange>),
+    /// Creature is on destination
+    Arrived,
+}
+
+/// Walking step shared by actions which lead a creature to a destination
+/// before doing something there (moving, bringing to or taking from storage).
+/// Given path is the action one, updated through given `set_path` action
+/// change. Action is removed if destination can't be reached.
+pub fn walk(
+    id: ActionId,
+    state: &State,
+    creature_id: &CreatureId,
+    destination: &AbsoluteWorldPoint,
+    path: &Option<Vec<AbsoluteWorldPoint>>,
+    set_path: fn(Option<Vec<AbsoluteWorldPoint>>) -> UpdateAction,
+) -> Walk {
+    let walk_tick = NextTick(*state.frame_i() + gameplay().ticks.walk);
+
+    if let Some(path) = path {
+        if let Some(try_point) = path.iter().next() {
+            let mut meta = state.meta_mut();
+            let world = state.world();
+
+            if world.can_walk(try_point) {
+                if let Some(next_point) = meta.book(try_point) {
+                    let new_path = path[1..].to_vec();
+                    Walk::Step(
+                        walk_tick,
+                        vec![
+                            StateChange::World(WorldChange::Creature(
+                                *creature_id,
+                                CreatureChange::SetPoint(next_point),
+                            )),
+                            StateChange::Action(id, ActionChange::Update(set_path(Some(new_path)))),
+                        ],
+                    )
+                } else {
+                    // Place is busy, wait next tick
+                    Walk::Step(walk_tick, vec![])
+                }
+            } else {
+                // Path seems corrupted, try another one
+                Walk::Step(
+                    walk_tick,
+                    vec![StateChange::Action(
+                        id,
+                        ActionChange::Update(set_path(None)),
+                    )],
+                )
+            }
+        } else {
+            Walk::Arrived
+        }
+
+    // If path found, use it at next step
+    } else if let Some(path) = find_path(state, creature_id, destination) {
+        Walk::Step(
+            walk_tick,
+            vec![StateChange::Action(
+                id,
+                ActionChange::Update(set_path(Some(path))),
+            )],
+        )
+
+    // If path cant be find, cancel this action
+    } else {
+        Walk::Step(
+            NextTick(*state.frame_i()),
+            vec![StateChange::Action(id, ActionChange::Remove)],
+        )
+    }
+}
+
+fn find_path(
+    state: &State,
+    creature_id: &CreatureId,
+    destination: &AbsoluteWorldPoint,
+) -> Option<Vec<AbsoluteWorldPoint>> {
+    let world = state.world();
+    let creature = world.creatures().get(creature_id).unwrap();
+    world.find_path(creature.point(), destination).map(|p| p.0)
+}
 
-// TODO: Probable duplicate code with DropOff
 #[derive(Debug, PartialEq)]
 pub struct MoveTo {
     creature_id: CreatureId,
@@ -26,6 +107,10 @@ pub struct MoveTo {
 }
 
 impl MoveTo {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
     pub fn new(creature_id: CreatureId, point: AbsoluteWorldPoint) -> Self {
         Self {
             creature_id,
@@ -33,15 +118,6 @@ impl MoveTo {
             path: None,
         }
     }
-
-    pub fn find_path(&self, state: &State) -> Option<Vec<AbsoluteWorldPoint>> {
-        let world = state.world();
-        let creature = world.creatures().get(&self.creature_id).unwrap();
-        state
-            .world()
-            .find_path(creature.point(), &self.point)
-            .map(|p| p.0)
-    }
 }
 
 impl BodyTick<MoveToChange> for MoveTo {
@@ -58,68 +134,22 @@ impl BodyTick<MoveToChange> for MoveTo {
             CreatureChange::SetBehavior(Behavior::Idle),
         )]
     }
-    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
-        if let Some(path) = &self.path {
-            if let Some(try_point) = path.iter().next() {
-                let mut meta = state.meta_mut();
-                let world = state.world();
-
-                if world.can_walk(try_point) {
-                    if let Some(next_point) = meta.book(try_point) {
-                        let new_path = path[1..].to_vec();
-                        (
-                            NextTick(*state.frame_i() + TICK_PERIOD),
-                            vec![
-                                StateChange::World(WorldChange::Creature(
-                                    self.creature_id,
-                                    CreatureChange::SetPoint(next_point),
-                                )),
-                                StateChange::Action(
-                                    id,
-                                    ActionChange::Update(UpdateAction::MoveTo(
-                                        MoveToChange::SetPath(Some(new_path)),
-                                    )),
-                                ),
-                            ],
-                        )
-                    } else {
-                        // Place is busy, wait next tick
-                        (NextTick(*state.frame_i() + TICK_PERIOD), vec![])
-                    }
-                } else {
-                    // Path seems corrupted, try another one
-                    (
-                        NextTick(*state.frame_i() + TICK_PERIOD),
-                        vec![StateChange::Action(
-                            id,
-                            ActionChange::Update(UpdateAction::MoveTo(MoveToChange::SetPath(None))),
-                        )],
-                    )
-                }
-            } else {
-                // Drop + remove this action
-                (
-                    NextTick(*state.frame_i()),
-                    vec![StateChange::Action(id, ActionChange::Remove)],
-                )
-            }
 
-        // If path found, use it at next step
-        } else if let Some(path) = self.find_path(state) {
-            (
-                NextTick(*state.frame_i() + TICK_PERIOD),
-                vec![StateChange::Action(
-                    id,
-                    ActionChange::Update(UpdateAction::MoveTo(MoveToChange::SetPath(Some(path)))),
-                )],
-            )
-
-        // If path cant be find, cancel this action
-        } else {
-            (
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let set_path = |path| UpdateAction::MoveTo(MoveToChange::SetPath(path));
+        match walk(
+            id,
+            state,
+            &self.creature_id,
+            &self.point,
+            &self.path,
+            set_path,
+        ) {
+            Walk::Step(next, changes) => (next, changes),
+            Walk::Arrived => (
                 NextTick(*state.frame_i()),
                 vec![StateChange::Action(id, ActionChange::Remove)],
-            )
+            ),
         }
     }
 
@@ -143,6 +173,10 @@ pub struct MoveRandomly {
 }
 
 impl MoveRandomly {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
     pub fn new(creature_id: CreatureId) -> Self {
         Self { creature_id }
     }
@@ -160,7 +194,7 @@ impl BodyTick<MoveRandomlyChange> for MoveRandomly {
             if let Some(new_point) = meta.book(&creature.point().next(&direction)) {
                 if state.world().can_walk(&new_point) {
                     return (
-                        NextTick(*state.frame_i() + TICK_PERIOD),
+                        NextTick(*state.frame_i() + gameplay().ticks.walk),
                         vec![
                             StateChange::World(WorldChange::Creature(
                                 self.creature_id,
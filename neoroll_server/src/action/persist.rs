@@ -0,0 +1,83 @@
+use neoroll_world::gameplay::calendar::FRAMES_PER_HOUR;
+
+use crate::state::{game::GameChange, State, StateChange};
+
+use super::{ActionId, BodyTick, NextTick};
+
+/// World is saved once per in game hour
+const TICK_PERIOD: u64 = FRAMES_PER_HOUR;
+
+/// Periodically save changed world chunks into world directory and unload
+/// chunks far from clients, creatures and animals
+#[derive(Debug, PartialEq)]
+pub struct Persist;
+
+impl BodyTick<PersistChange> for Persist {
+    fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        (
+            NextTick(*state.frame_i() + TICK_PERIOD),
+            vec![StateChange::Game(GameChange::Persist)],
+        )
+    }
+
+    fn apply(&mut self, _change: PersistChange) {}
+}
+
+#[derive(Debug)]
+pub enum PersistChange {}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use neoroll_world::{
+        entity::floor::Floor,
+        gameplay::tribe::TribeId,
+        space::{
+            chunk::ChunkPoint,
+            world::{FloorChange, World, WorldChange},
+        },
+    };
+    use rstest::*;
+
+    use crate::testing::{self, point};
+
+    use super::*;
+
+    fn directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("neoroll_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[rstest]
+    fn test_persist_save_and_unload() {
+        // Given
+        let directory = directory();
+        let creature = testing::creature(TribeId::new(), point(0, 0));
+        testing::world(130, 70, vec![creature])
+            .save(&directory)
+            .unwrap();
+        let world = World::open(&directory).unwrap();
+        world.ground(&point(0, 0));
+        world.ground(&point(70, 0));
+        world.ground(&point(0, 65));
+        let mut state = testing::state(world, Default::default());
+        testing::apply(
+            &mut state,
+            vec![StateChange::World(WorldChange::Floor(
+                point(129, 0),
+                FloorChange::Set(Floor::ShortGrass),
+            ))],
+        );
+
+        // When
+        testing::apply(&mut state, vec![StateChange::Game(GameChange::Persist)]);
+
+        // Then
+        assert_eq!(state.world().loaded_chunks(), vec![ChunkPoint(0, 0)]);
+        let reopened = World::open(&directory).unwrap();
+        assert_eq!(reopened.floor(&point(129, 0)), Some(&Floor::ShortGrass));
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use neoroll_world::{
+    entity::creature::{CreatureChange, CreatureId},
+    gameplay::{behavior::Behavior, material::Material, Quantity},
+    space::{
+        world::{MaterialChange, WorldChange},
+        AbsoluteWorldPoint,
+    },
+};
+
+use crate::state::{State, StateChange};
+
+use super::{
+    move_::{walk, Walk},
+    ActionChange, ActionId, BodyTick, NextTick, UpdateAction,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct PickUp {
+    creature_id: CreatureId,
+    point: AbsoluteWorldPoint,
+    material: Material,
+    quantity: Quantity,
+    path: Option<Vec<AbsoluteWorldPoint>>,
+}
+
+impl PickUp {
+    pub fn creature_id(&self) -> &CreatureId {
+        &self.creature_id
+    }
+
+    /// Storage where creature goes
+    pub fn point(&self) -> &AbsoluteWorldPoint {
+        &self.point
+    }
+
+    pub fn new(
+        creature_id: CreatureId,
+        point: AbsoluteWorldPoint,
+        material: Material,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            creature_id,
+            point,
+            material,
+            quantity,
+            path: None,
+        }
+    }
+
+    fn pick(&self, id: ActionId, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let available = world.material_quantity(&self.point, self.material);
+        let creature = world.creatures().get(&self.creature_id).unwrap();
+        let room = creature.can_carry(&self.material);
+        // Material can have been taken by another creature meanwhile
+        let quantity = self
+            .material
+            .quantity(self.quantity.0.min(available.0).min(room.0));
+
+        let mut changes = vec![];
+        if quantity.0 > 0 {
+            changes.extend(vec![
+                StateChange::World(WorldChange::Material(
+                    self.point,
+                    MaterialChange::Remove(self.material, quantity.clone()),
+                )),
+                StateChange::World(WorldChange::Creature(
+                    self.creature_id,
+                    CreatureChange::AddToCarrying(self.material, quantity),
+                )),
+            ]);
+        }
+        changes.push(StateChange::Action(id, ActionChange::Remove));
+
+        changes
+    }
+}
+
+impl BodyTick<PickUpChange> for PickUp {
+    fn stamp(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::PickUp),
+        )]
+    }
+
+    fn take_off(&self) -> Vec<WorldChange> {
+        vec![WorldChange::Creature(
+            self.creature_id,
+            CreatureChange::SetBehavior(Behavior::Idle),
+        )]
+    }
+
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let set_path = |path| UpdateAction::PickUp(PickUpChange::SetPath(path));
+        match walk(
+            id,
+            state,
+            &self.creature_id,
+            &self.point,
+            &self.path,
+            set_path,
+        ) {
+            Walk::Step(next, changes) => (next, changes),
+            // Pick + remove this action
+            Walk::Arrived => (NextTick(*state.frame_i()), self.pick(id, state)),
+        }
+    }
+
+    fn apply(&mut self, change: PickUpChange) {
+        match change {
+            PickUpChange::SetPath(path) => self.path = path,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PickUpChange {
+    SetPath(Option<Vec<AbsoluteWorldPoint>>),
+}
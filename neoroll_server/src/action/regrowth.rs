@@ -0,0 +1,194 @@
+use neoroll_world::{
+    entity::{floor::Floor, structure::Structure, Filled},
+    gameplay::{
+        config::{gameplay, IntoCollect},
+        CollectType,
+    },
+    space::{
+        chunk::CHUNK_SIZE,
+        world::{FloorChange, GroundChange, StructureChange, WorldChange},
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
+    utils::Direction,
+};
+use rand::{seq::SliceRandom, Rng};
+use strum::IntoEnumIterator;
+
+use crate::state::{State, StateChange};
+
+use super::{ActionId, BodyTick, NextTick};
+
+/// Random tiles (of loaded chunks) looked at by tick to find seeding trees
+const SEEDING_SAMPLES: usize = 500;
+/// Chance for a sampled tree to seed an adjacent tile
+const SEEDING_CHANCE: f64 = 0.05;
+
+/// Make depleted collectables (bushes, trees, ...) slowly regrow, saplings and
+/// sown bushes grow and trees seed around them
+#[derive(Debug, PartialEq)]
+pub struct Regrowth;
+
+impl Regrowth {
+    /// Trees of some random tiles drop a seed which become a sapling on an adjacent tile
+    fn seeding(&self, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let mut rng = rand::thread_rng();
+        let mut changes = vec![];
+
+        // Only sample loaded chunks : reading other ones would load them all
+        let chunks = world.loaded_chunks();
+
+        // Nothing grows in winter
+        if chunks.is_empty() || state.calendar().season().regrowth_factor() == 0 {
+            return changes;
+        }
+
+        let directions = Direction::iter().collect::<Vec<Direction>>();
+        for _ in 0..SEEDING_SAMPLES {
+            let start = chunks[rng.gen_range(0..chunks.len())].start();
+            let point = AbsoluteWorldPoint(
+                AbsoluteWorldRowI(start.row_i().0 + rng.gen_range(0..CHUNK_SIZE) as isize),
+                AbsoluteWorldColI(start.col_i().0 + rng.gen_range(0..CHUNK_SIZE) as isize),
+            );
+            let tree = matches!(
+                world.structure(&point),
+                Some(Structure::BigLeafTree(_)) | Some(Structure::FruitTree(_, _))
+            );
+
+            if tree && rng.gen_bool(SEEDING_CHANCE) {
+                if let Some(direction) = directions.choose(&mut rng) {
+                    let seeded = point.next(direction);
+                    if world.can_plant(&seeded) {
+                        changes.push(StateChange::World(WorldChange::Structure(
+                            seeded,
+                            StructureChange::Set(Some(Structure::Sapling(Filled::empty()))),
+                        )));
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    fn tick_point(&self, point: &AbsoluteWorldPoint, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let factor = state.calendar().season().regrowth_factor();
+        let mut changes = vec![];
+
+        if let Some(ground) = world
+            .ground(point)
+            .and_then(|g| regrown(g, factor, |_, _| None))
+        {
+            changes.push(StateChange::World(WorldChange::Ground(
+                *point,
+                GroundChange::Set(ground),
+            )));
+        }
+
+        if let Some(floor) = world
+            .floor(point)
+            .and_then(|f| regrown(f, factor, Floor::grown))
+        {
+            changes.push(StateChange::World(WorldChange::Floor(
+                *point,
+                FloorChange::Set(floor),
+            )));
+        }
+
+        if let Some(structure) = world
+            .structure(point)
+            .as_ref()
+            .and_then(|s| regrown(s, factor, Structure::grown))
+        {
+            changes.push(StateChange::World(WorldChange::Structure(
+                *point,
+                StructureChange::Set(Some(structure)),
+            )));
+        }
+
+        changes
+    }
+}
+
+/// Given layer item after one regrowth step of each collect type then one growth
+/// step (see `grown`), or None if nothing changed
+fn regrown<T: IntoCollect>(item: &T, factor: u8, grown: fn(&T, u8) -> Option<T>) -> Option<T> {
+    let mut item = item.clone();
+    let mut changed = false;
+    for type_ in CollectType::iter() {
+        if let Some(item_) = item.regrown(type_, factor) {
+            item = item_;
+            changed = true;
+        }
+    }
+    if let Some(item_) = grown(&item, factor) {
+        item = item_;
+        changed = true;
+    }
+
+    changed.then_some(item)
+}
+
+impl BodyTick<RegrowthChange> for Regrowth {
+    fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let points = state
+            .world()
+            .regrowing()
+            .iter()
+            .cloned()
+            .collect::<Vec<AbsoluteWorldPoint>>();
+
+        let mut changes = vec![];
+        for point in points {
+            changes.extend(self.tick_point(&point, state));
+        }
+        changes.extend(self.seeding(state));
+
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.regrowth),
+            changes,
+        )
+    }
+
+    fn apply(&mut self, _change: RegrowthChange) {}
+}
+
+#[derive(Debug)]
+pub enum RegrowthChange {}
+
+#[cfg(test)]
+mod test {
+    use rstest::*;
+
+    use crate::testing::{self, point};
+
+    use super::*;
+
+    #[rstest]
+    #[case(Filled::empty(), |s: &Structure| matches!(s, Structure::Sapling(g) if g.0 > 0))]
+    #[case(Filled::new(254), |s: &Structure| s == &Structure::BigLeafTree(Filled::full()))]
+    fn test_sapling_growth(#[case] growth: Filled, #[case] expected: fn(&Structure) -> bool) {
+        // Given
+        let mut world = testing::world(1, 1, vec![]);
+        world.set_structure(point(0, 0), Some(Structure::Sapling(growth)));
+        let state = testing::state(world, Default::default());
+
+        // When
+        let changes = Regrowth.tick_point(&point(0, 0), &state);
+
+        // Then
+        assert_eq!(changes.len(), 1);
+        match &changes[0] {
+            StateChange::World(WorldChange::Structure(
+                point_,
+                StructureChange::Set(Some(structure)),
+            )) => {
+                assert_eq!(point_, &point(0, 0));
+                assert!(expected(structure), "{:?}", structure);
+            }
+            change => panic!("Unexpected change {:?}", change),
+        }
+        assert!(state.world().regrowing().contains(&point(0, 0)));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    run::TICK_BASE_PERIOD,
+    state::{game::GameChange, State, StateChange},
+};
+
+use super::{ActionId, BodyTick, NextTick};
+
+const TICK_PERIOD: u64 = TICK_BASE_PERIOD;
+
+/// Apply scenario scripts events to each tribe and report scripts errors
+#[derive(Debug, PartialEq)]
+pub struct RunScripts;
+
+impl BodyTick<RunScriptsChange> for RunScripts {
+    fn tick(&self, _id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let frame_i = *state.frame_i();
+        let scripts = state.game().scripts();
+
+        // Only one tick happens between two event periods
+        let mut changes = scripts.events_changes(state, TICK_PERIOD);
+        changes.extend(
+            scripts
+                .take_errors()
+                .into_iter()
+                .map(|error| StateChange::Game(GameChange::ScriptError(error))),
+        );
+
+        (NextTick(frame_i + TICK_PERIOD), changes)
+    }
+
+    fn apply(&mut self, _change: RunScriptsChange) {}
+}
+
+#[derive(Debug)]
+pub enum RunScriptsChange {}
@@ -1,7 +1,6 @@
-use neoroll_world::gameplay::tribe::TribeId;
+use neoroll_world::gameplay::{config::gameplay, tribe::TribeId};
 
 use crate::{
-    run::TICK_BASE_PERIOD,
     state::{
         game::{ComputedTargetChange, GameChange},
         State, StateChange,
@@ -11,8 +10,6 @@ use crate::{
 
 use super::{ActionId, BodyTick, NextTick};
 
-const TICK_PERIOD: u64 = TICK_BASE_PERIOD * 2;
-
 #[derive(Debug, PartialEq)]
 pub struct ComputeTargets {
     tribe_id: TribeId,
@@ -29,7 +26,7 @@ impl BodyTick<ComputeTargetsChange> for ComputeTargets {
         let computed_targets = ComputedTargetBuilder::new(&state, self.tribe_id).build();
 
         (
-            NextTick(*state.frame_i() + TICK_PERIOD),
+            NextTick(*state.frame_i() + gameplay().ticks.compute_targets),
             vec![StateChange::Game(GameChange::ComputedTarget(
                 self.tribe_id,
                 ComputedTargetChange::Set(computed_targets),
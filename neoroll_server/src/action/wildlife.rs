@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use neoroll_world::{
+    entity::animal::{self, Animal, AnimalBehavior, AnimalChange, HerdId, Species},
+    gameplay::config::gameplay,
+    space::{world::WorldChange, AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI},
+    utils::Direction,
+};
+use rand::{seq::SliceRandom, Rng};
+use strum::IntoEnumIterator;
+
+use crate::state::{FrameI, State, StateChange};
+
+use super::{ActionChange, ActionId, BodyTick, NextTick, UpdateAction};
+
+/// Animals farther than this (squared) distance from their herd centre go back to it
+const HERD_SQUARED_RADIUS: isize = 9;
+/// New herds never appear closer than this (squared) distance from a campfire
+const RESPAWN_SQUARED_DISTANCE: isize = 30 * 30;
+/// Chance for a wandering animal to move at each tick
+const WANDER_CHANCE: f64 = 0.3;
+
+/// Move wild animals (wander, flee humans, stay with their herd) and slowly
+/// respawn herds of species under their world capacity
+#[derive(Debug, PartialEq)]
+pub struct Wildlife {
+    next_respawn: FrameI,
+}
+
+impl Wildlife {
+    pub fn new() -> Self {
+        Self {
+            next_respawn: FrameI(0),
+        }
+    }
+
+    fn herd_centers(&self, state: &State) -> HashMap<HerdId, AbsoluteWorldPoint> {
+        let mut sums: HashMap<HerdId, (isize, isize, isize)> = HashMap::new();
+        for animal in state.world().animals().values() {
+            let sum = sums.entry(*animal.herd()).or_default();
+            sum.0 += animal.point().row_i().0;
+            sum.1 += animal.point().col_i().0;
+            sum.2 += 1;
+        }
+
+        sums.into_iter()
+            .map(|(herd, (rows, cols, count))| {
+                (
+                    herd,
+                    AbsoluteWorldPoint(
+                        AbsoluteWorldRowI(rows / count),
+                        AbsoluteWorldColI(cols / count),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Walkable neighbour points of given point
+    fn steps(&self, state: &State, point: &AbsoluteWorldPoint) -> Vec<AbsoluteWorldPoint> {
+        let world = state.world();
+        Direction::iter()
+            .map(|direction| point.next(&direction))
+            .filter(|point| world.can_walk(point))
+            .collect()
+    }
+
+    fn tick_animal(
+        &self,
+        state: &State,
+        animal: &Animal,
+        herd_center: Option<&AbsoluteWorldPoint>,
+    ) -> Vec<StateChange> {
+        let point = animal.point();
+        let flee_radius = animal.species().flee_radius() as isize;
+        let threat = state
+            .world()
+            .creatures()
+            .values()
+            .map(|creature| *creature.point())
+            .filter(|creature| creature.squared_distance(point) <= flee_radius * flee_radius)
+            .min_by_key(|creature| creature.squared_distance(point));
+
+        let (behavior, step) = if let Some(threat) = threat {
+            let step = self
+                .steps(state, point)
+                .into_iter()
+                .max_by_key(|step| step.squared_distance(&threat));
+            (AnimalBehavior::Flee, step)
+        } else if let Some(center) =
+            herd_center.filter(|center| center.squared_distance(point) > HERD_SQUARED_RADIUS)
+        {
+            let step = self
+                .steps(state, point)
+                .into_iter()
+                .min_by_key(|step| step.squared_distance(center));
+            (AnimalBehavior::Herd, step)
+        } else {
+            let mut rng = rand::thread_rng();
+            let step = if rng.gen_bool(WANDER_CHANCE) {
+                self.steps(state, point).choose(&mut rng).copied()
+            } else {
+                None
+            };
+            (AnimalBehavior::Wander, step)
+        };
+
+        let mut changes = vec![];
+        if &behavior != animal.behavior() {
+            changes.push(StateChange::World(WorldChange::Animal(
+                *animal.id(),
+                AnimalChange::SetBehavior(behavior),
+            )));
+        }
+        if let Some(step) = step {
+            changes.push(StateChange::World(WorldChange::Animal(
+                *animal.id(),
+                AnimalChange::SetPoint(step),
+            )));
+        }
+
+        changes
+    }
+
+    fn respawn(&self, state: &State) -> Vec<StateChange> {
+        let world = state.world();
+        let game = state.game();
+        let campfires = game
+            .tribe_ids()
+            .iter()
+            .filter_map(|tribe_id| game.tribe_campfire(tribe_id))
+            .collect::<Vec<AbsoluteWorldPoint>>();
+        let mut changes = vec![];
+
+        for species in Species::iter() {
+            let population = world
+                .animals()
+                .values()
+                .filter(|animal| animal.species() == &species)
+                .count();
+            if population + species.herd_size() > species.capacity(&world) {
+                continue;
+            }
+
+            if let Some(point) = animal::spawn_point(&world, |point| {
+                campfires
+                    .iter()
+                    .all(|campfire| campfire.squared_distance(point) > RESPAWN_SQUARED_DISTANCE)
+            }) {
+                for animal in animal::new_herd(species, point) {
+                    changes.push(StateChange::World(WorldChange::Animal(
+                        *animal.id(),
+                        AnimalChange::New(animal),
+                    )));
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+impl Default for Wildlife {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BodyTick<WildlifeChange> for Wildlife {
+    fn tick(&self, id: ActionId, state: &State) -> (NextTick, Vec<StateChange>) {
+        let herd_centers = self.herd_centers(state);
+        let animals = state
+            .world()
+            .animals()
+            .values()
+            .cloned()
+            .collect::<Vec<Animal>>();
+
+        let mut changes = vec![];
+        for animal in &animals {
+            changes.extend(self.tick_animal(state, animal, herd_centers.get(animal.herd())));
+        }
+
+        if state.frame_i() >= &self.next_respawn {
+            changes.extend(self.respawn(state));
+            changes.push(StateChange::Action(
+                id,
+                ActionChange::Update(UpdateAction::Wildlife(WildlifeChange::SetNextRespawn(
+                    *state.frame_i() + gameplay().ticks.wildlife_respawn,
+                ))),
+            ));
+        }
+
+        (
+            NextTick(*state.frame_i() + gameplay().ticks.wildlife),
+            changes,
+        )
+    }
+
+    fn apply(&mut self, change: WildlifeChange) {
+        match change {
+            WildlifeChange::SetNextRespawn(frame_i) => self.next_respawn = frame_i,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WildlifeChange {
+    SetNextRespawn(FrameI),
+}
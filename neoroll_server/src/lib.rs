@@ -2,9 +2,13 @@ pub mod action;
 pub mod gateway;
 pub mod meta;
 pub mod run;
+pub mod script;
 pub mod server;
 pub mod shortcut;
 pub mod state;
+pub mod storage;
 pub mod subscriptions;
 pub mod target;
+#[cfg(test)]
+pub mod testing;
 pub mod utils;
@@ -9,8 +9,8 @@ use rayon::{ThreadPool, ThreadPoolBuilder};
 
 use crate::{
     action::{
-        client::ComputeAndSendClientStates, migrant::IncomingMigrant, Action, ActionChange,
-        ActionId,
+        client::ComputeAndSendClientStates, lifecycle::Lifecycle, migrant::IncomingMigrant,
+        persist::Persist, regrowth::Regrowth, wildlife::Wildlife, Action, ActionChange, ActionId,
     },
     gateway::Gateways,
     state::{State, StateChange},
@@ -76,6 +76,19 @@ impl Runner {
                     ActionId::new(),
                     ActionChange::New(Action::IncomingMigrant(IncomingMigrant)),
                 ),
+                StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::Regrowth(Regrowth)),
+                ),
+                StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::Wildlife(Wildlife::new())),
+                ),
+                StateChange::Action(
+                    ActionId::new(),
+                    ActionChange::New(Action::Lifecycle(Lifecycle)),
+                ),
+                StateChange::Action(ActionId::new(), ActionChange::New(Action::Persist(Persist))),
             ],
         );
 
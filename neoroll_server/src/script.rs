@@ -0,0 +1,811 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use neoroll_world::{
+    entity::{
+        creature::{Creature, CreatureId},
+        structure::Structure,
+    },
+    gameplay::{
+        lifecycle::Age,
+        material::{Crafted, Hunted, Material, Resource},
+        tech::Technology,
+        tribe::TribeId,
+        Quantity,
+    },
+    space::{
+        world::{MaterialChange, WorldChange},
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
+};
+use rhai::{
+    module_resolvers::DummyModuleResolver, Array, CallFnOptions, Dynamic, Engine, Map, Scope, AST,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    action::{move_::MoveTo, Action, ActionChange, ActionId},
+    shortcut,
+    state::{game::GameChange, State, StateChange},
+    storage::StorageFinder,
+};
+
+/// Directory (from working directory) where scenario scripts (`.rhai` files) are
+/// loaded. Scripts of a save are loaded from this directory in the world directory.
+pub const SCRIPTS_DIRECTORY: &str = "scripts";
+/// Age of creatures joining a tribe by script
+const NEW_MIGRANT_YEARS: u64 = 20;
+/// Operations allowed for one script call (endless scripts are stopped)
+const MAX_OPERATIONS: u64 = 100_000;
+const MAX_CALL_LEVELS: usize = 32;
+const MAX_STRING_SIZE: usize = 10_000;
+const MAX_COLLECTION_SIZE: usize = 10_000;
+
+/// Scenario scripts, evaluated in a sandboxed engine: scripts only read state
+/// through the views given to their functions (copied values) and only change it
+/// through the effects they return, which are applied like any other state change.
+///
+/// A script declares, with top level variables :
+///  - `events`: `#{ every: <frames>, run: "<fn(tribe)>" }` maps. Function is called
+///    for each tribe every given frames count and returns an array of effects.
+///  - `targets`: `#{ name: "<name>", progress: "<fn(tribe)>", job: "<fn(creature)>" }`
+///    maps. Progress function returns the satisfaction of the target, from 0.0 to 1.0.
+///    Job function is called for each idle worker of the target and returns an
+///    array of effects.
+pub struct Scripts {
+    engine: Engine,
+    scripts: Vec<Script>,
+    /// Errors raised by scripts calls, not yet reported to clients
+    errors: Mutex<Vec<String>>,
+}
+
+struct Script {
+    file: String,
+    ast: AST,
+    events: Vec<ScriptEvent>,
+    targets: Vec<ScriptTarget>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ScriptEvent {
+    every: u64,
+    run: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ScriptTarget {
+    name: String,
+    progress: String,
+    job: String,
+}
+
+/// Changes scripts can ask for. Creature effects are ignored outside of jobs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Effect {
+    EarnKnowledge(u64),
+    /// Quantity (in material unit) added in a tribe storage
+    AddStock(Material, u64),
+    NewMigrant,
+    /// Worker walks to this point
+    MoveTo(AbsoluteWorldPoint),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    Read(String, String),
+    Compile(String, String),
+    Declaration(String, String),
+    Runtime(String, String),
+    UnknownTarget(String),
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptError::Read(file, error)
+            | ScriptError::Compile(file, error)
+            | ScriptError::Declaration(file, error)
+            | ScriptError::Runtime(file, error) => f.write_str(&format!("{}: {}", file, error)),
+            ScriptError::UnknownTarget(name) => {
+                f.write_str(&format!("No script defines target \"{}\"", name))
+            }
+        }
+    }
+}
+
+fn materials() -> Vec<Material> {
+    Resource::iter()
+        .map(Material::Resource)
+        .chain(Crafted::iter().map(Material::Crafted))
+        .chain(Hunted::iter().map(Material::Hunted))
+        .collect()
+}
+
+fn material(name: &str) -> Result<Material, Box<rhai::EvalAltResult>> {
+    materials()
+        .into_iter()
+        .find(|material| material.to_string() == name)
+        .ok_or_else(|| format!("Unknown material \"{}\"", name).into())
+}
+
+fn positive(value: i64) -> u64 {
+    value.max(0) as u64
+}
+
+fn add_stock(name: &str, quantity: i64) -> Result<Effect, Box<rhai::EvalAltResult>> {
+    Ok(Effect::AddStock(material(name)?, positive(quantity)))
+}
+
+/// Engine without access to files or modules, and with bounded resources
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+
+    engine
+        .set_module_resolver(DummyModuleResolver::new())
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_COLLECTION_SIZE)
+        .set_max_map_size(MAX_COLLECTION_SIZE)
+        .disable_symbol("eval")
+        .on_print(|text| println!("script: {}", text));
+
+    engine
+        .register_type_with_name::<Effect>("Effect")
+        .register_fn("earn_knowledge", |points: i64| {
+            Effect::EarnKnowledge(positive(points))
+        })
+        .register_fn("add_stock", add_stock)
+        .register_fn("new_migrant", || Effect::NewMigrant)
+        .register_fn("chance", |probability: f64| {
+            rand::random::<f64>() < probability
+        })
+        .register_fn("move_to", |row: i64, col: i64| {
+            Effect::MoveTo(AbsoluteWorldPoint(
+                AbsoluteWorldRowI(row as isize),
+                AbsoluteWorldColI(col as isize),
+            ))
+        });
+
+    engine
+}
+
+impl Default for Scripts {
+    fn default() -> Self {
+        Self {
+            engine: engine(),
+            scripts: vec![],
+            errors: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl Scripts {
+    /// Load all scripts of given directories. Invalid scripts are not loaded,
+    /// their errors are returned with loaded scripts.
+    pub fn load(directories: &[PathBuf]) -> (Self, Vec<ScriptError>) {
+        let mut scripts = Self::default();
+        let mut errors = vec![];
+
+        for directory in directories {
+            for path in script_paths(directory) {
+                let file = path.display().to_string();
+                let script = fs::read_to_string(&path)
+                    .map_err(|error| ScriptError::Read(file.clone(), error.to_string()))
+                    .and_then(|content| scripts.compile(&file, &content));
+                match script {
+                    Ok(script) => scripts.scripts.push(script),
+                    Err(error) => errors.push(error),
+                }
+            }
+        }
+
+        (scripts, errors)
+    }
+
+    fn compile(&self, file: &str, content: &str) -> Result<Script, ScriptError> {
+        let error = |error: String| ScriptError::Declaration(file.to_string(), error);
+        let ast = self
+            .engine
+            .compile(content)
+            .map_err(|error| ScriptError::Compile(file.to_string(), error.to_string()))?;
+
+        // Top level statements only declare script content
+        let mut scope = Scope::new();
+        self.engine
+            .run_ast_with_scope(&mut scope, &ast)
+            .map_err(|error| ScriptError::Runtime(file.to_string(), error.to_string()))?;
+
+        let mut events = vec![];
+        for event in declarations(&scope, "events").map_err(error)? {
+            let every = integer(&event, "every").map_err(error)?;
+            if every <= 0 {
+                return Err(error("event period must be positive".to_string()));
+            }
+            events.push(ScriptEvent {
+                every: every as u64,
+                run: string(&event, "run").map_err(error)?,
+            });
+        }
+
+        let mut targets = vec![];
+        for target in declarations(&scope, "targets").map_err(error)? {
+            let name = string(&target, "name").map_err(error)?;
+            if self.target(&name).is_some() || targets.iter().any(|t: &ScriptTarget| t.name == name)
+            {
+                return Err(error(format!("target \"{}\" is already defined", name)));
+            }
+            targets.push(ScriptTarget {
+                name,
+                progress: string(&target, "progress").map_err(error)?,
+                job: string(&target, "job").map_err(error)?,
+            });
+        }
+
+        let functions = events.iter().map(|event| &event.run).chain(
+            targets
+                .iter()
+                .flat_map(|target| [&target.progress, &target.job]),
+        );
+        for function in functions {
+            if !ast
+                .iter_functions()
+                .any(|f| f.name == function && f.params.len() == 1)
+            {
+                return Err(error(format!(
+                    "function \"{}\" with one parameter is missing",
+                    function
+                )));
+            }
+        }
+
+        Ok(Script {
+            file: file.to_string(),
+            ast,
+            events,
+            targets,
+        })
+    }
+
+    fn target(&self, name: &str) -> Option<(&Script, &ScriptTarget)> {
+        self.scripts.iter().find_map(|script| {
+            script
+                .targets
+                .iter()
+                .find(|target| target.name == name)
+                .map(|target| (script, target))
+        })
+    }
+
+    /// Names of targets defined by scripts
+    pub fn target_names(&self) -> Vec<String> {
+        self.scripts
+            .iter()
+            .flat_map(|script| script.targets.iter().map(|target| target.name.clone()))
+            .collect()
+    }
+
+    fn call(&self, script: &Script, function: &str, argument: Map) -> Result<Dynamic, ScriptError> {
+        self.engine
+            .call_fn_with_options::<Dynamic>(
+                CallFnOptions::new().eval_ast(false),
+                &mut Scope::new(),
+                &script.ast,
+                function,
+                (argument,),
+            )
+            .map_err(|error| ScriptError::Runtime(script.file.clone(), error.to_string()))
+    }
+
+    /// Keep error to report it to clients (see `take_errors`)
+    fn report(&self, error: ScriptError) {
+        self.errors.lock().unwrap().push(error.to_string());
+    }
+
+    /// Errors raised by scripts calls since last call
+    pub fn take_errors(&self) -> Vec<String> {
+        std::mem::take(&mut *self.errors.lock().unwrap())
+    }
+
+    /// Changes of events which must run at given frame (events are run when frame
+    /// is in the first `period` frames of their period)
+    pub fn events_changes(&self, state: &State, period: u64) -> Vec<StateChange> {
+        let frame_i = state.frame_i().0;
+        let mut changes = vec![];
+
+        for script in &self.scripts {
+            for event in &script.events {
+                if frame_i % event.every >= period {
+                    continue;
+                }
+
+                for tribe_id in state.game().tribe_ids() {
+                    match self
+                        .call(script, &event.run, tribe_view(state, &tribe_id))
+                        .and_then(|result| effects(&script.file, result))
+                    {
+                        Ok(effects) => changes.extend(
+                            effects
+                                .iter()
+                                .flat_map(|effect| effect.changes(state, &tribe_id, None)),
+                        ),
+                        Err(error) => self.report(error),
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Satisfaction of given script target, from 0.0 to 1.0 (0.0 if script fails)
+    pub fn progress(&self, state: &State, tribe_id: &TribeId, name: &str) -> f32 {
+        let (script, target) = match self.target(name) {
+            Some(target) => target,
+            None => {
+                self.report(ScriptError::UnknownTarget(name.to_string()));
+                return 0.;
+            }
+        };
+
+        let result = self
+            .call(script, &target.progress, tribe_view(state, tribe_id))
+            .and_then(|result| {
+                result
+                    .as_float()
+                    .or_else(|_| result.as_int().map(|value| value as f64))
+                    .map_err(|type_| {
+                        ScriptError::Runtime(
+                            script.file.clone(),
+                            format!(
+                                "\"{}\" must return a number, not {}",
+                                target.progress, type_
+                            ),
+                        )
+                    })
+            });
+
+        match result {
+            Ok(progress) => (progress as f32).clamp(0., 1.),
+            Err(error) => {
+                self.report(error);
+                0.
+            }
+        }
+    }
+
+    /// Changes asked by given script target job for given (idle) worker
+    pub fn work(&self, state: &State, creature: &Creature, name: &str) -> Vec<StateChange> {
+        let (script, target) = match self.target(name) {
+            Some(target) => target,
+            None => {
+                self.report(ScriptError::UnknownTarget(name.to_string()));
+                return vec![];
+            }
+        };
+
+        match self
+            .call(script, &target.job, creature_view(state, creature))
+            .and_then(|result| effects(&script.file, result))
+        {
+            Ok(effects) => effects
+                .iter()
+                .flat_map(|effect| effect.changes(state, creature.tribe_id(), Some(creature)))
+                .collect(),
+            Err(error) => {
+                self.report(error);
+                vec![]
+            }
+        }
+    }
+}
+
+/// `.rhai` files of given directory (which is optional)
+fn script_paths(directory: &Path) -> Vec<PathBuf> {
+    let mut paths = match fs::read_dir(directory) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map(|e| e == "rhai").unwrap_or(false))
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    paths.sort();
+    paths
+}
+
+/// Maps of given top level array variable (no variable means no declaration)
+fn declarations(scope: &Scope, name: &str) -> Result<Vec<Map>, String> {
+    let values = match scope.get_value::<Dynamic>(name) {
+        Some(value) => value
+            .try_cast::<Array>()
+            .ok_or(format!("\"{}\" must be an array", name))?,
+        None => return Ok(vec![]),
+    };
+
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .try_cast::<Map>()
+                .ok_or(format!("\"{}\" items must be maps", name))
+        })
+        .collect()
+}
+
+fn integer(map: &Map, key: &str) -> Result<i64, String> {
+    map.get(key)
+        .and_then(|value| value.as_int().ok())
+        .ok_or(format!("\"{}\" integer is missing", key))
+}
+
+fn string(map: &Map, key: &str) -> Result<String, String> {
+    map.get(key)
+        .and_then(|value| value.clone().into_string().ok())
+        .ok_or(format!("\"{}\" string is missing", key))
+}
+
+/// Effects returned by a script function (nothing can be returned as `()`)
+fn effects(file: &str, result: Dynamic) -> Result<Vec<Effect>, ScriptError> {
+    if result.is_unit() {
+        return Ok(vec![]);
+    }
+
+    let error = || ScriptError::Runtime(file.to_string(), "must return effects".to_string());
+    result
+        .try_cast::<Array>()
+        .ok_or_else(error)?
+        .into_iter()
+        .map(|value| value.try_cast::<Effect>().ok_or_else(error))
+        .collect()
+}
+
+fn point_view(point: &AbsoluteWorldPoint) -> Map {
+    let mut view = Map::new();
+    view.insert("row".into(), (point.row_i().0 as i64).into());
+    view.insert("col".into(), (point.col_i().0 as i64).into());
+    view
+}
+
+/// Tribe values given to scripts
+fn tribe_view(state: &State, tribe_id: &TribeId) -> Map {
+    let calendar = state.calendar();
+    let game = state.game();
+    let world = state.world();
+    let mut view = Map::new();
+
+    let population = world
+        .tribe_creature_ids(tribe_id)
+        .map(|ids| ids.len())
+        .unwrap_or(0);
+    let mut stock = Map::new();
+    for material in materials() {
+        let quantity: u64 = game
+            .tribe_structures(tribe_id, Some(Structure::Storage))
+            .iter()
+            .map(|own| world.material_quantity(own.point(), material).0)
+            .sum();
+        stock.insert(material.to_string().into(), (quantity as i64).into());
+    }
+    let knowledge = game.knowledge(tribe_id);
+    let technologies = Technology::iter()
+        .filter(|technology| knowledge.knows(technology))
+        .map(|technology| technology.to_string().into())
+        .collect::<Array>();
+
+    view.insert("frame".into(), (state.frame_i().0 as i64).into());
+    view.insert("year".into(), (calendar.year() as i64).into());
+    view.insert("season".into(), calendar.season().to_string().into());
+    view.insert("day".into(), (calendar.day() as i64).into());
+    view.insert("population".into(), (population as i64).into());
+    view.insert("stock".into(), stock.into());
+    view.insert("technologies".into(), technologies.into());
+    view.insert(
+        "home".into(),
+        game.tribe_home(tribe_id)
+            .map(|home| point_view(&home).into())
+            .unwrap_or(Dynamic::UNIT),
+    );
+
+    view
+}
+
+/// Creature (and its tribe) values given to scripts
+fn creature_view(state: &State, creature: &Creature) -> Map {
+    let mut view = point_view(creature.point());
+    view.insert("name".into(), creature.name().into());
+    view.insert(
+        "tribe".into(),
+        tribe_view(state, creature.tribe_id()).into(),
+    );
+    view
+}
+
+impl Effect {
+    /// Effects which can't be applied (like adding stock to a tribe without
+    /// storage) are ignored
+    fn changes(
+        &self,
+        state: &State,
+        tribe_id: &TribeId,
+        creature: Option<&Creature>,
+    ) -> Vec<StateChange> {
+        let home = state.game().tribe_home(tribe_id);
+
+        match self {
+            Effect::EarnKnowledge(points) => {
+                vec![StateChange::Game(GameChange::EarnKnowledge(
+                    *tribe_id, *points,
+                ))]
+            }
+            Effect::AddStock(material, quantity) => match home.and_then(|home| {
+                StorageFinder::new(state, *tribe_id)
+                    .drop_off(&home, material)
+                    .first()
+                    .copied()
+            }) {
+                Some(storage) => vec![StateChange::World(WorldChange::Material(
+                    storage,
+                    MaterialChange::Add(*material, Quantity(*quantity, material.unit())),
+                ))],
+                None => vec![],
+            },
+            Effect::NewMigrant => match home {
+                Some(home) => shortcut::creature::new_creature(
+                    *tribe_id,
+                    home,
+                    Age::from_years(NEW_MIGRANT_YEARS),
+                ),
+                None => vec![],
+            },
+            Effect::MoveTo(point) => match creature {
+                Some(creature) if state.world().can_walk(point) => {
+                    vec![move_to(*creature.id(), *point)]
+                }
+                _ => vec![],
+            },
+        }
+    }
+}
+
+fn move_to(creature_id: CreatureId, point: AbsoluteWorldPoint) -> StateChange {
+    StateChange::Action(
+        ActionId::new(),
+        ActionChange::New(Action::MoveTo(MoveTo::new(creature_id, point))),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::gameplay::tribe::Tribe;
+    use rstest::*;
+
+    use crate::{
+        state::game::GameState,
+        testing::{self, point},
+    };
+
+    use super::*;
+
+    const SCRIPT: &str = r#"
+        let events = [#{ every: 100, run: "festival" }];
+        let targets = [#{ name: "Grow", progress: "grow_progress", job: "gather" }];
+
+        fn festival(tribe) {
+            if tribe.season == "Spring" && tribe.population >= 1 {
+                [earn_knowledge(5), add_stock("Food", 10)]
+            }
+        }
+
+        fn grow_progress(tribe) {
+            tribe.population / 4.0
+        }
+
+        fn gather(creature) {
+            [move_to(creature.tribe.home.row, creature.tribe.home.col + 1)]
+        }
+    "#;
+
+    fn directory(scripts: Vec<(&str, &str)>) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("neoroll_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        for (file, content) in scripts {
+            fs::write(directory.join(file), content).unwrap();
+        }
+        directory
+    }
+
+    fn scripts(content: &str) -> Scripts {
+        let directory = directory(vec![("script.rhai", content)]);
+        let (scripts, errors) = Scripts::load(std::slice::from_ref(&directory));
+        fs::remove_dir_all(directory).unwrap();
+        assert_eq!(errors, vec![]);
+        scripts
+    }
+
+    /// State with a tribe of given creatures count, owning a campfire and a storage
+    fn state(creatures: usize) -> (State, TribeId) {
+        let tribe_id = TribeId::new();
+        let creatures = (0..creatures)
+            .map(|_| testing::creature(tribe_id, point(0, 0)))
+            .collect();
+        let mut world = testing::world(5, 5, creatures);
+        let mut game = GameState::default();
+        game.new_tribe(Tribe::new(tribe_id));
+        testing::own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Campfire,
+            point(2, 2),
+        );
+        testing::own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(2, 3),
+        );
+        (testing::state(world, game), tribe_id)
+    }
+
+    #[test]
+    fn test_load() {
+        // Given
+        let directory = directory(vec![
+            ("b.rhai", SCRIPT),
+            ("compile.rhai", "let events = [;"),
+            (
+                "zero.rhai",
+                "let events = [#{ every: 0, run: \"f\" }]; fn f(t) {}",
+            ),
+            (
+                "missing.rhai",
+                "let events = [#{ every: 10, run: \"nope\" }];",
+            ),
+            ("twice.rhai", &SCRIPT.replace("events", "no_events")),
+            ("notes.txt", "not a script"),
+        ]);
+
+        // When
+        let (scripts, errors) = Scripts::load(&[directory.clone(), directory.join("nothing")]);
+
+        // Then
+        assert_eq!(scripts.scripts.len(), 1);
+        assert_eq!(scripts.target_names(), vec!["Grow".to_string()]);
+        let errors = errors
+            .iter()
+            .map(|error| match error {
+                ScriptError::Compile(file, _) => format!("compile {}", file),
+                ScriptError::Declaration(file, _) => format!("declaration {}", file),
+                error => error.to_string(),
+            })
+            .map(|error| error.replace(&directory.display().to_string(), ""))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "compile /compile.rhai",
+                "declaration /missing.rhai",
+                "declaration /twice.rhai",
+                "declaration /zero.rhai",
+            ]
+        );
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_load_example() {
+        // Given
+        let directory = PathBuf::from("../scripts.example");
+
+        // When
+        let (scripts, errors) = Scripts::load(&[directory]);
+
+        // Then
+        assert_eq!(errors, vec![]);
+        assert_eq!(
+            scripts.target_names(),
+            vec!["Gather around campfire".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_events_changes() {
+        // Given
+        let scripts = scripts(SCRIPT);
+        let (state, tribe_id) = state(1);
+
+        // When
+        let changes = scripts.events_changes(&state, 1);
+
+        // Then
+        assert!(matches!(
+            &changes[..],
+            [
+                StateChange::Game(GameChange::EarnKnowledge(tribe_id_, 5)),
+                StateChange::World(WorldChange::Material(storage, MaterialChange::Add(_, _))),
+            ] if tribe_id_ == &tribe_id && storage == &point(2, 3)
+        ));
+        assert!(scripts.take_errors().is_empty());
+    }
+
+    #[rstest]
+    #[case(0, 0.)]
+    #[case(2, 0.5)]
+    #[case(6, 1.)]
+    fn test_progress(#[case] creatures: usize, #[case] expected: f32) {
+        // Given
+        let scripts = scripts(SCRIPT);
+        let (state, tribe_id) = state(creatures);
+
+        // When
+        let progress = scripts.progress(&state, &tribe_id, "Grow");
+
+        // Then
+        assert_eq!(progress, expected);
+    }
+
+    #[test]
+    fn test_work() {
+        // Given
+        let scripts = scripts(SCRIPT);
+        let (state, _) = state(1);
+        let creature = state.world().creatures().values().next().unwrap().clone();
+
+        // When
+        let changes = scripts.work(&state, &creature, "Grow");
+
+        // Then
+        assert!(matches!(
+            &changes[..],
+            [StateChange::Action(_, ActionChange::New(Action::MoveTo(move_to)))]
+                if move_to == &MoveTo::new(*creature.id(), point(2, 3))
+        ));
+    }
+
+    #[rstest]
+    #[case("fn grow_progress(tribe) { throw \"broken\" }")]
+    #[case("fn grow_progress(tribe) { loop {} }")]
+    #[case("fn grow_progress(tribe) { import \"file\" as f; 1 }")]
+    #[case("fn grow_progress(tribe) { \"full\" }")]
+    fn test_runtime_errors_are_reported(#[case] progress: &str) {
+        // Given
+        let content = format!(
+            "let targets = [#{{ name: \"Grow\", progress: \"grow_progress\", job: \"j\" }}];
+            fn j(creature) {{}}
+            {}",
+            progress
+        );
+        let scripts = scripts(&content);
+        let (state, tribe_id) = state(1);
+
+        // When
+        let progress = scripts.progress(&state, &tribe_id, "Grow");
+
+        // Then
+        assert_eq!(progress, 0.);
+        assert_eq!(scripts.take_errors().len(), 1);
+        assert!(scripts.take_errors().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_target() {
+        // Given
+        let scripts = Scripts::default();
+        let (state, tribe_id) = state(1);
+
+        // When
+        let progress = scripts.progress(&state, &tribe_id, "Grow");
+
+        // Then
+        assert_eq!(progress, 0.);
+        assert_eq!(
+            scripts.take_errors(),
+            vec!["No script defines target \"Grow\"".to_string()]
+        );
+    }
+}
@@ -1,27 +1,48 @@
 use std::{
     fs,
+    path::{Path, PathBuf},
     sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
     thread,
 };
 
 use crate::{
     action::{
-        job::affect::AffectJobBuilder, target::ComputeTargets, Action, ActionChange, ActionId,
+        demolish::{self, Demolish},
+        job::affect::AffectJobBuilder,
+        script::RunScripts,
+        target::ComputeTargets,
+        Action, ActionChange, ActionId,
     },
     gateway::{ClientId, ClientMessageEnveloppe, Gateways},
     meta::MetaState,
     run::RunnerBuilder,
+    script::{Scripts, SCRIPTS_DIRECTORY},
     state::{
         client::ClientGameState,
-        game::{ClientGameMessage, GameChange, GameState, ServerGameMessage, TargetMessage},
+        game::{
+            ClientGameMessage, ConstructionChange, FieldChange, GameChange, GameState,
+            MigrationChange, OrderChange, ServerGameMessage, TargetMessage,
+        },
         State, StateChange,
     },
     subscriptions::{Subscriptions, SubscriptionsMessage},
 };
 use crossbeam::channel::{unbounded, Receiver, Sender};
 use neoroll_world::{
-    entity::creature::{CreatureId, PartialCreatureChange},
-    gameplay::{build::TryBuild, tribe::structure::StructureOwn},
+    entity::{
+        creature::{CreatureId, PartialCreatureChange},
+        structure::Structure,
+    },
+    gameplay::{
+        build::{construction::Construction, Buildable, TryBuild, TryBuildError},
+        config::{set_gameplay, GameplayConfig, GAMEPLAY_CONFIG_FILE},
+        job::Job,
+        tribe::{
+            field::{Field, FIELD_RADIUS},
+            migration::Migration,
+            structure::StructureOwn,
+        },
+    },
     map::{area::MapArea, patch::NewSectors, Map},
     space::{
         area::WorldArea,
@@ -32,6 +53,9 @@ use neoroll_world::{
 };
 use uuid::Uuid;
 
+/// World directory (world header and chunks), as written by `generate`
+const WORLD_DIRECTORY: &str = "world";
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ServerMessageEnveloppe {
     Broadcast(ServerMessage),
@@ -62,13 +86,33 @@ pub enum ClientMessage {
 // TODO : this part will be "server side" and network stuff
 //pub fn spawn(server_sender: Sender<ServerMessage>, client_receiver: Receiver<ClientMessage>) {
 pub fn spawn(gateways: Gateways) {
+    // Must be set before any gameplay config read (world opening included)
+    if let Ok(content) = fs::read_to_string(GAMEPLAY_CONFIG_FILE) {
+        let config = GameplayConfig::from_ron(&content)
+            .unwrap_or_else(|error| panic!("Invalid {}: {}", GAMEPLAY_CONFIG_FILE, error));
+        set_gameplay(config).expect("Gameplay config must be set once, before use");
+    }
+
     gateways.start();
 
     let gateways = Arc::new(RwLock::new(gateways));
     let subscriptions = Arc::new(RwLock::new(Subscriptions::new()));
-    let world = bincode::deserialize::<World>(&fs::read("world.bin").unwrap()).unwrap();
+
+    let world = World::open(Path::new(WORLD_DIRECTORY))
+        .unwrap_or_else(|error| panic!("Can't open world: {}", error));
     let map = bincode::deserialize::<Map>(&fs::read("map.bin").unwrap()).unwrap();
-    let game = GameState::default();
+    let mut game = GameState::default();
+
+    // Scenario scripts, then saved world scripts. Invalid scripts are not run,
+    // their errors are displayed to players
+    let (scripts, errors) = Scripts::load(&[
+        PathBuf::from(SCRIPTS_DIRECTORY),
+        Path::new(WORLD_DIRECTORY).join(SCRIPTS_DIRECTORY),
+    ]);
+    for error in errors {
+        game.add_script_error(error.to_string());
+    }
+    game.set_scripts(scripts);
 
     let world = Arc::new(RwLock::new(world));
     let map = Arc::new(RwLock::new(map));
@@ -98,7 +142,7 @@ pub fn spawn(gateways: Gateways) {
     // TODO: like in OpenCombat, permit remote (network) server instead embedded server
     thread::spawn(|| {
         RunnerBuilder::new(gateways, subscriptions, server_receiver)
-            .actions(vec![])
+            .actions(vec![(ActionId::new(), Action::RunScripts(RunScripts))])
             .build(State::new(world, map, game, meta))
             .run();
     });
@@ -108,8 +152,8 @@ pub struct Server {
     gateways: Arc<RwLock<Gateways>>,
     subscriptions: Arc<RwLock<Subscriptions>>,
     server_sender: Sender<StateChange>,
-    world: Arc<RwLock<World>>, // NOTE: Server should only read world (Runner is only allowed to write)
-    map: Arc<RwLock<Map>>, // NOTE: Server should only read map (Runner is only allowed to write)
+    world: Arc<RwLock<World>>, // NOTE: Server should only read world (Runner is only allowed to write, except streamed chunks generation)
+    map: Arc<RwLock<Map>>, // NOTE: Server should only read map (Runner is only allowed to write, except streamed chunks generation)
     game: Arc<RwLock<GameState>>,
 }
 
@@ -171,11 +215,42 @@ impl Server {
                 // TODO: Heartbeat
             }
             ClientMessage::RequireWorldArea(area, ignore_area) => {
+                // Streamed world chunks are generated (by the runner, which is the
+                // only world writer) the first time they are required
+                if !self.world.read().unwrap().missing_chunks(&area).is_empty() {
+                    self.server_sender
+                        .send(StateChange::Game(GameChange::SendWorldLayers(
+                            client_id,
+                            area,
+                            ignore_area,
+                        )))
+                        .unwrap();
+                    return;
+                }
+
                 let new_layers =
                     NewLayers::from_world_area(&self.world.read().unwrap(), &area, &ignore_area);
                 self.send_to_client(client_id, ServerMessage::NewWorldLayers(area, new_layers));
             }
             ClientMessage::RequireMapArea(area, ignore_area) => {
+                // Sectors of streamed world chunks exist once these chunks are generated
+                if !self
+                    .world
+                    .read()
+                    .unwrap()
+                    .missing_chunks(&WorldArea::from(&area))
+                    .is_empty()
+                {
+                    self.server_sender
+                        .send(StateChange::Game(GameChange::SendMapSectors(
+                            client_id,
+                            area,
+                            ignore_area,
+                        )))
+                        .unwrap();
+                    return;
+                }
+
                 let new_sectors =
                     NewSectors::from_map_area(&self.map.read().unwrap(), &area, &ignore_area);
                 self.send_to_client(client_id, ServerMessage::NewMapSectors(area, new_sectors));
@@ -219,8 +294,19 @@ impl Server {
                 ClientGameMessage::TryBuild(buildable, point) => {
                     let game = self.game();
                     let tribe_id = game.client_tribe_id(&client_id).unwrap();
-                    match TryBuild::new(&self.world.read().unwrap()).try_(buildable, point) {
-                        Ok(_) => {
+                    let try_ = if game.knowledge(tribe_id).can_build(buildable) {
+                        TryBuild::new(&self.world.read().unwrap()).try_(buildable, point)
+                    } else {
+                        Err(TryBuildError::NotDiscovered)
+                    };
+                    match try_ {
+                        // First campfire found the tribe: there is nobody to build it
+                        Ok(_)
+                            if buildable == &Buildable::Campfire
+                                && game
+                                    .tribe_structures(tribe_id, Some(Structure::Campfire))
+                                    .is_empty() =>
+                        {
                             self.server_sender
                                 .send(StateChange::World(WorldChange::Structure(
                                     *point,
@@ -232,6 +318,21 @@ impl Server {
                                 )))
                                 .unwrap();
                         }
+                        Ok(_) => {
+                            self.server_sender
+                                .send(StateChange::Game(GameChange::Construction(
+                                    ConstructionChange::New(Construction::new(
+                                        *buildable, *tribe_id, *point,
+                                    )),
+                                )))
+                                .unwrap();
+                            self.server_sender
+                                .send(StateChange::World(WorldChange::Structure(
+                                    *point,
+                                    StructureChange::Set(Some(Structure::Construction(*buildable))),
+                                )))
+                                .unwrap();
+                        }
                         Err(error) => self
                             .gateways
                             .read()
@@ -245,6 +346,92 @@ impl Server {
                             .unwrap(),
                     }
                 }
+                ClientGameMessage::CancelConstruction(point) => {
+                    let game = self.game();
+                    let tribe_id = game.client_tribe_id(&client_id).unwrap();
+                    // Migration new campfire is canceled with migration
+                    let migrating_to = game
+                        .migration(tribe_id)
+                        .map(|migration| migration.to() == point)
+                        .unwrap_or(false);
+                    if !migrating_to
+                        && game
+                            .construction(point)
+                            .map(|c| c.tribe_id() == tribe_id)
+                            .unwrap_or(false)
+                    {
+                        // Brought materials stay on the ground
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Construction(
+                                ConstructionChange::Remove(*point),
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::World(WorldChange::Structure(
+                                *point,
+                                StructureChange::Set(None),
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
+                ClientGameMessage::Demolish(point) => {
+                    let game = self.game();
+                    let tribe_id = game.client_tribe_id(&client_id).unwrap();
+                    let world = self.world.read().unwrap();
+                    match demolish::check(&world, &game, tribe_id, point) {
+                        Ok(_) => self
+                            .server_sender
+                            .send(StateChange::Action(
+                                ActionId::new(),
+                                ActionChange::New(Action::Demolish(Demolish::new(
+                                    *tribe_id, *point,
+                                ))),
+                            ))
+                            .unwrap(),
+                        Err(error) => self
+                            .gateways
+                            .read()
+                            .unwrap()
+                            .send(ServerMessageEnveloppe::To(
+                                client_id,
+                                ServerMessage::Game(ServerGameMessage::DemolishError(
+                                    message, error,
+                                )),
+                            ))
+                            .unwrap(),
+                    }
+                }
+                ClientGameMessage::StorageAccept(point, material, value) => {
+                    let game = self.game();
+                    let tribe_id = *game.client_tribe_id(&client_id).unwrap();
+                    let owned = game
+                        .structure_own(point)
+                        .map(|own| own.tribe_id() == &tribe_id)
+                        .unwrap_or(false);
+                    // Required because read game as mut line after
+                    drop(game);
+
+                    if owned {
+                        self.game_mut()
+                            .tribe_settings_mut()
+                            .entry(tribe_id)
+                            .or_default()
+                            .storages_mut()
+                            .entry(*point)
+                            .or_default()
+                            .set_accept(*material, *value);
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
                 ClientGameMessage::RequestServerSpeed(speed) => {
                     self.game_mut()
                         .set_client_speed_request(client_id, *speed.min(&200).max(&1));
@@ -278,6 +465,11 @@ impl Server {
                         let tribe_id = *game.client_tribe_id(&client_id).unwrap();
                         // Required because read game as mut line after
 
+                        // Check if tribe knows how to realize this target
+                        if !game.knowledge(&tribe_id).can_target(new_target.target()) {
+                            return;
+                        }
+
                         // Check if this target already exist
                         for target_ in game.tribe_targets().get(&tribe_id).unwrap_or(&vec![]) {
                             if target_.target().is_same(new_target.target()) {
@@ -300,7 +492,215 @@ impl Server {
                             ))
                             .unwrap();
                     }
+                    TargetMessage::Remove => {
+                        let tribe_id = *self.game().client_tribe_id(&client_id).unwrap();
+                        self.game_mut().remove_target(&tribe_id, id);
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                    TargetMessage::Pause | TargetMessage::Resume => {
+                        let tribe_id = *self.game().client_tribe_id(&client_id).unwrap();
+                        let enabled = message == &TargetMessage::Resume;
+
+                        let mut game = self.game_mut();
+                        match game
+                            .tribe_settings_mut()
+                            .get_mut(&tribe_id)
+                            .and_then(|settings| settings.target_mut(id))
+                        {
+                            Some(target) => target.set_enabled(enabled),
+                            // Target can have been removed meanwhile
+                            None => return,
+                        }
+                        // Paused targets don't wait for anything
+                        game.clear_waitings(&tribe_id, id);
+                        drop(game);
+
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
                 },
+                ClientGameMessage::Order(creature_id, _)
+                | ClientGameMessage::CancelOrder(creature_id) => {
+                    let tribe_id = *self.game().client_tribe_id(&client_id).unwrap();
+                    // Players can only give orders to their own creatures
+                    if self
+                        .world
+                        .read()
+                        .unwrap()
+                        .creatures()
+                        .get(creature_id)
+                        .map(|creature| creature.tribe_id() == &tribe_id)
+                        .unwrap_or(false)
+                    {
+                        let change = match &message {
+                            ClientGameMessage::Order(_, order) => OrderChange::Set(order.clone()),
+                            _ => OrderChange::Remove,
+                        };
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Order(*creature_id, change)))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
+                ClientGameMessage::Discover(technology) => {
+                    let game = self.game();
+                    let tribe_id = *game.client_tribe_id(&client_id).unwrap();
+                    if game.knowledge(&tribe_id).can_discover(technology) {
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Discover(
+                                tribe_id,
+                                *technology,
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
+                ClientGameMessage::Migrate(point) => {
+                    let game = self.game();
+                    let tribe_id = *game.client_tribe_id(&client_id).unwrap();
+                    if let (Some(campfire), None) =
+                        (game.tribe_campfire(&tribe_id), game.migration(&tribe_id))
+                    {
+                        match TryBuild::new(&self.world.read().unwrap())
+                            .try_(&Buildable::Campfire, point)
+                        {
+                            Ok(_) => {
+                                // All current structures will be left
+                                let left = game
+                                    .tribe_structures(&tribe_id, None)
+                                    .iter()
+                                    .map(|own| *own.point())
+                                    .collect();
+                                self.server_sender
+                                    .send(StateChange::Game(GameChange::Migration(
+                                        tribe_id,
+                                        MigrationChange::Start(Migration::new(
+                                            campfire, *point, left,
+                                        )),
+                                    )))
+                                    .unwrap();
+                                self.server_sender
+                                    .send(StateChange::Game(GameChange::Construction(
+                                        ConstructionChange::New(Construction::new(
+                                            Buildable::Campfire,
+                                            tribe_id,
+                                            *point,
+                                        )),
+                                    )))
+                                    .unwrap();
+                                self.server_sender
+                                    .send(StateChange::World(WorldChange::Structure(
+                                        *point,
+                                        StructureChange::Set(Some(Structure::Construction(
+                                            Buildable::Campfire,
+                                        ))),
+                                    )))
+                                    .unwrap();
+                                self.server_sender
+                                    .send(StateChange::Game(
+                                        GameChange::ImmediateClientGameStateRefresh(client_id),
+                                    ))
+                                    .unwrap();
+                            }
+                            Err(error) => self
+                                .gateways
+                                .read()
+                                .unwrap()
+                                .send(ServerMessageEnveloppe::To(
+                                    client_id,
+                                    ServerMessage::Game(ServerGameMessage::TryBuildError(
+                                        message, error,
+                                    )),
+                                ))
+                                .unwrap(),
+                        }
+                    }
+                }
+                ClientGameMessage::CancelMigration => {
+                    let game = self.game();
+                    let tribe_id = *game.client_tribe_id(&client_id).unwrap();
+                    // Once new campfire is built, migration can't be canceled anymore
+                    if let Some(point) = game
+                        .migration(&tribe_id)
+                        .map(|migration| *migration.to())
+                        .filter(|point| game.construction(point).is_some())
+                    {
+                        // Brought materials stay on the ground
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Construction(
+                                ConstructionChange::Remove(point),
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::World(WorldChange::Structure(
+                                point,
+                                StructureChange::Set(None),
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Migration(
+                                tribe_id,
+                                MigrationChange::End,
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
+                ClientGameMessage::NewField(point) => {
+                    let game = self.game();
+                    let tribe_id = *game.client_tribe_id(&client_id).unwrap();
+                    let field = Field::new(*point, FIELD_RADIUS);
+                    // Fields can't overlap each other
+                    if game.knowledge(&tribe_id).can_work(&Job::Farm)
+                        && game.overlapping_field(&field).is_none()
+                    {
+                        self.server_sender
+                            .send(StateChange::Game(GameChange::Field(
+                                tribe_id,
+                                FieldChange::New(field),
+                            )))
+                            .unwrap();
+                        self.server_sender
+                            .send(StateChange::Game(
+                                GameChange::ImmediateClientGameStateRefresh(client_id),
+                            ))
+                            .unwrap();
+                    }
+                }
+                ClientGameMessage::RemoveField(point) => {
+                    let tribe_id = *self.game().client_tribe_id(&client_id).unwrap();
+                    // Sown and grown bushes stay in place
+                    self.server_sender
+                        .send(StateChange::Game(GameChange::Field(
+                            tribe_id,
+                            FieldChange::Remove(*point),
+                        )))
+                        .unwrap();
+                    self.server_sender
+                        .send(StateChange::Game(
+                            GameChange::ImmediateClientGameStateRefresh(client_id),
+                        ))
+                        .unwrap();
+                }
             },
         }
     }
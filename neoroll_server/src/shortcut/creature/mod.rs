@@ -1,6 +1,9 @@
 use neoroll_world::{
     entity::creature::{Creature, CreatureChange, CreatureId},
-    gameplay::tribe::TribeId,
+    gameplay::{
+        lifecycle::{random_name, Age},
+        tribe::TribeId,
+    },
     space::{world::WorldChange, AbsoluteWorldPoint},
 };
 
@@ -9,9 +12,10 @@ use crate::{
     state::StateChange,
 };
 
-pub fn new_creature(tribe_id: TribeId, point: AbsoluteWorldPoint) -> Vec<StateChange> {
+pub fn new_creature(tribe_id: TribeId, point: AbsoluteWorldPoint, age: Age) -> Vec<StateChange> {
     let creature_id = CreatureId::new();
-    let creature = Creature::new(creature_id, tribe_id, point);
+    let name = random_name(&mut rand::thread_rng());
+    let creature = Creature::new(creature_id, tribe_id, name, age, point);
 
     vec![
         StateChange::World(WorldChange::Creature(
@@ -1,40 +1,84 @@
 use neoroll_world::{
-    entity::structure::Structure, gameplay::tribe::TribeId, space::AbsoluteWorldPoint,
+    entity::structure::Structure,
+    gameplay::{
+        build::Buildable,
+        job::Job,
+        tribe::{field::Field, migration::Migration, TribeId},
+    },
+    space::AbsoluteWorldPoint,
 };
+use strum::IntoEnumIterator;
 
 use crate::state::State;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BuildGameState {
-    can_build_campfire: bool,
-    can_build_storage: bool,
+    /// Buildables which can be placed now (discovered and allowed by tribe situation)
+    buildables: Vec<Buildable>,
     campfires: Vec<AbsoluteWorldPoint>,
+    owned: Vec<(AbsoluteWorldPoint, Structure)>,
+    migration: Option<Migration>,
+    fields: Vec<Field>,
+    /// Tribe knows how to farm its fields
+    can_farm: bool,
 }
 
 impl BuildGameState {
     pub fn new(
-        can_build_campfire: bool,
-        can_build_storage: bool,
+        buildables: Vec<Buildable>,
         campfires: Vec<AbsoluteWorldPoint>,
+        owned: Vec<(AbsoluteWorldPoint, Structure)>,
+        migration: Option<Migration>,
+        fields: Vec<Field>,
+        can_farm: bool,
     ) -> Self {
         Self {
-            can_build_campfire,
-            can_build_storage,
+            buildables,
             campfires,
+            owned,
+            migration,
+            fields,
+            can_farm,
         }
     }
 
-    pub fn can_build_campfire(&self) -> bool {
-        self.can_build_campfire
+    pub fn buildables(&self) -> &[Buildable] {
+        &self.buildables
     }
 
-    pub fn can_build_storage(&self) -> bool {
-        self.can_build_storage
+    pub fn can_build(&self, buildable: &Buildable) -> bool {
+        self.buildables.contains(buildable)
     }
 
     pub fn campfires(&self) -> &[AbsoluteWorldPoint] {
         &self.campfires
     }
+
+    /// Tribe structures (which can be demolished)
+    pub fn owned(&self) -> &[(AbsoluteWorldPoint, Structure)] {
+        &self.owned
+    }
+
+    pub fn is_owned(&self, point: &AbsoluteWorldPoint) -> bool {
+        self.owned.iter().any(|(p, _)| p == point)
+    }
+
+    pub fn migration(&self) -> Option<&Migration> {
+        self.migration.as_ref()
+    }
+
+    /// Tribe can move its campfire elsewhere
+    pub fn can_migrate(&self) -> bool {
+        !self.campfires.is_empty() && self.migration.is_none()
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    pub fn can_farm(&self) -> bool {
+        self.can_farm
+    }
 }
 
 pub struct BuildGameStateBuilder<'a> {
@@ -46,24 +90,37 @@ impl<'a> BuildGameStateBuilder<'a> {
         Self { state }
     }
 
-    pub fn build(self, tribe_id: &TribeId) -> BuildGameState {
-        // In the future, we will manage migration, but for now, only one fire allowed
-        let can_build_campfire = self
-            .state
+    fn constructing(&self, tribe_id: &TribeId, buildable: Buildable) -> bool {
+        self.state
             .game()
-            .tribe_structures(tribe_id, Some(Structure::Campfire))
-            .is_empty();
+            .tribe_constructions(tribe_id)
+            .iter()
+            .any(|c| c.buildable() == &buildable)
+    }
 
-        let can_build_storage = !self
+    /// Tribe situation rules, whatever its knowledge
+    fn allowed(&self, tribe_id: &TribeId, buildable: Buildable) -> bool {
+        let has_campfire = !self
             .state
             .game()
             .tribe_structures(tribe_id, Some(Structure::Campfire))
-            .is_empty()
-            && self
-                .state
-                .game()
-                .tribe_structures(tribe_id, Some(Structure::Storage))
-                .is_empty();
+            .is_empty();
+
+        match buildable {
+            // Only one fire allowed, tribe moves it by migrating
+            Buildable::Campfire => !has_campfire && !self.constructing(tribe_id, buildable),
+            // Other structures are built around the campfire
+            Buildable::Storage => has_campfire,
+        }
+    }
+
+    pub fn build(self, tribe_id: &TribeId) -> BuildGameState {
+        let knowledge = self.state.game().knowledge(tribe_id);
+        let buildables = Buildable::iter()
+            .filter(|buildable| {
+                knowledge.can_build(buildable) && self.allowed(tribe_id, *buildable)
+            })
+            .collect::<Vec<Buildable>>();
         let campfires = self
             .state
             .game()
@@ -72,6 +129,19 @@ impl<'a> BuildGameStateBuilder<'a> {
             .map(|s| *s.point())
             .collect::<Vec<AbsoluteWorldPoint>>();
 
-        BuildGameState::new(can_build_campfire, can_build_storage, campfires)
+        let owned = self
+            .state
+            .game()
+            .tribe_structures(tribe_id, None)
+            .iter()
+            .map(|s| (*s.point(), s.type_().clone()))
+            .collect::<Vec<(AbsoluteWorldPoint, Structure)>>();
+
+        let migration = self.state.game().migration(tribe_id).cloned();
+
+        let fields = self.state.game().tribe_fields(tribe_id).to_vec();
+        let can_farm = knowledge.can_work(&Job::Farm);
+
+        BuildGameState::new(buildables, campfires, owned, migration, fields, can_farm)
     }
 }
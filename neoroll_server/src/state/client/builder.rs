@@ -1,8 +1,9 @@
 use crate::{gateway::ClientId, state::State};
 
 use super::{
-    build::BuildGameStateBuilder, human::HumanGameStateBuilder, material::MaterialsStateBuilder,
-    target::TargetGameStateBuilder, ClientGameState,
+    build::BuildGameStateBuilder, construction::ConstructionsStateBuilder,
+    human::HumanGameStateBuilder, material::MaterialsStateBuilder, target::TargetGameStateBuilder,
+    ClientGameState,
 };
 
 pub struct ClientGameStateBuilder<'a> {
@@ -19,9 +20,24 @@ impl<'a> ClientGameStateBuilder<'a> {
         let tribe_id = game.client_tribe_id(client_id).unwrap();
         let human = HumanGameStateBuilder::new(self.state).build(tribe_id);
         let build = BuildGameStateBuilder::new(self.state).build(tribe_id);
+        let constructions = ConstructionsStateBuilder::new(self.state).build(tribe_id);
         let target = TargetGameStateBuilder::new(self.state).build(tribe_id);
         let materials = MaterialsStateBuilder::new(self.state).build(tribe_id);
+        let knowledge = game.knowledge(tribe_id);
+        let script_errors = game.script_errors().to_vec();
+        let script_targets = game.scripts().target_names();
 
-        ClientGameState::new(*tribe_id, human, build, target, materials)
+        ClientGameState::new(
+            *tribe_id,
+            self.state.calendar(),
+            human,
+            build,
+            constructions,
+            target,
+            materials,
+            knowledge,
+            script_errors,
+            script_targets,
+        )
     }
 }
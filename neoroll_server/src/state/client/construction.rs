@@ -0,0 +1,91 @@
+use neoroll_world::{
+    gameplay::{
+        build::Buildable, material::Material, progress::Progress, tribe::TribeId, Quantity,
+    },
+    space::AbsoluteWorldPoint,
+};
+
+use crate::state::State;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstructionState {
+    point: AbsoluteWorldPoint,
+    buildable: Buildable,
+    progress: Progress,
+    /// Required and already brought quantities
+    materials: Vec<(Material, Quantity, Quantity)>,
+}
+
+impl ConstructionState {
+    pub fn point(&self) -> &AbsoluteWorldPoint {
+        &self.point
+    }
+
+    pub fn buildable(&self) -> &Buildable {
+        &self.buildable
+    }
+
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    pub fn materials(&self) -> &[(Material, Quantity, Quantity)] {
+        &self.materials
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstructionsState {
+    constructions: Vec<ConstructionState>,
+}
+
+impl ConstructionsState {
+    fn new(constructions: Vec<ConstructionState>) -> Self {
+        Self { constructions }
+    }
+
+    pub fn constructions(&self) -> &[ConstructionState] {
+        &self.constructions
+    }
+
+    pub fn construction(&self, point: &AbsoluteWorldPoint) -> Option<&ConstructionState> {
+        self.constructions.iter().find(|c| c.point() == point)
+    }
+}
+
+pub struct ConstructionsStateBuilder<'a> {
+    state: &'a State,
+}
+
+impl<'a> ConstructionsStateBuilder<'a> {
+    pub fn new(state: &'a State) -> Self {
+        Self { state }
+    }
+
+    pub fn build(self, tribe_id: &TribeId) -> ConstructionsState {
+        let game = self.state.game();
+        let world = self.state.world();
+        let mut constructions = vec![];
+
+        for construction in game.tribe_constructions(tribe_id) {
+            let materials = construction
+                .buildable()
+                .materials()
+                .into_iter()
+                .map(|(material, required)| {
+                    let brought = world.material_quantity(construction.point(), material);
+                    (material, required, brought)
+                })
+                .collect();
+
+            constructions.push(ConstructionState {
+                point: *construction.point(),
+                buildable: *construction.buildable(),
+                progress: construction.progress(),
+                materials,
+            })
+        }
+
+        ConstructionsState::new(constructions)
+    }
+}
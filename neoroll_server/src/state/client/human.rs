@@ -1,17 +1,28 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-use neoroll_world::gameplay::tribe::TribeId;
+use neoroll_world::{
+    entity::creature::CreatureId,
+    gameplay::{order::Order, tribe::TribeId},
+};
 
 use crate::state::State;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct HumanGameState {
     human_count: HumanCount,
+    orders: HashMap<CreatureId, Order>,
 }
 
 impl HumanGameState {
-    pub fn new(human_count: HumanCount) -> Self {
-        Self { human_count }
+    pub fn new(human_count: HumanCount, orders: HashMap<CreatureId, Order>) -> Self {
+        Self {
+            human_count,
+            orders,
+        }
+    }
+
+    pub fn order(&self, creature_id: &CreatureId) -> Option<&Order> {
+        self.orders.get(creature_id)
     }
 }
 
@@ -45,9 +56,24 @@ impl<'a> HumanGameStateBuilder<'a> {
         Self { state }
     }
 
-    pub fn build(self, _tribe_id: &TribeId) -> HumanGameState {
+    pub fn build(self, tribe_id: &TribeId) -> HumanGameState {
+        let world = self.state.world();
         // FIXME BS NOW: count only tribe human creatures !
-        let human_count = HumanCount(self.state.world().creatures().len() as u16);
-        HumanGameState::new(human_count)
+        let human_count = HumanCount(world.creatures().len() as u16);
+        let orders = self
+            .state
+            .game()
+            .orders()
+            .iter()
+            .filter(|(creature_id, _)| {
+                world
+                    .creatures()
+                    .get(creature_id)
+                    .map(|creature| creature.tribe_id() == tribe_id)
+                    .unwrap_or(false)
+            })
+            .map(|(creature_id, order)| (*creature_id, order.clone()))
+            .collect();
+        HumanGameState::new(human_count, orders)
     }
 }
@@ -2,24 +2,71 @@ use std::collections::HashMap;
 
 use neoroll_world::{
     entity::structure::Structure,
-    gameplay::{material::Material, tribe::TribeId, Quantity},
+    gameplay::{material::Material, tribe::TribeId, Quantity, Volume, Weight},
     space::AbsoluteWorldPoint,
 };
 
-use crate::state::State;
+use crate::state::{game::settings::StorageSetting, State};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct MaterialsState {
     points: Vec<(AbsoluteWorldPoint, Vec<(Material, Quantity)>)>,
+    settings: HashMap<AbsoluteWorldPoint, StorageSetting>,
+    capacities: HashMap<AbsoluteWorldPoint, StorageCapacity>,
 }
 impl MaterialsState {
-    fn new(points: Vec<(AbsoluteWorldPoint, Vec<(Material, Quantity)>)>) -> Self {
-        Self { points }
+    fn new(
+        points: Vec<(AbsoluteWorldPoint, Vec<(Material, Quantity)>)>,
+        settings: HashMap<AbsoluteWorldPoint, StorageSetting>,
+        capacities: HashMap<AbsoluteWorldPoint, StorageCapacity>,
+    ) -> Self {
+        Self {
+            points,
+            settings,
+            capacities,
+        }
+    }
+
+    pub fn capacity(&self, point: &AbsoluteWorldPoint) -> Option<&StorageCapacity> {
+        self.capacities.get(point)
     }
 
     pub fn storages(&self) -> &[(AbsoluteWorldPoint, Vec<(Material, Quantity)>)] {
         &self.points
     }
+
+    pub fn accept(&self, point: &AbsoluteWorldPoint, material: &Material) -> bool {
+        self.settings
+            .get(point)
+            .map(|setting| setting.accept(material))
+            .unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageCapacity {
+    weight: Weight,
+    max_weight: Weight,
+    volume: Volume,
+    max_volume: Volume,
+}
+
+impl StorageCapacity {
+    pub fn weight(&self) -> &Weight {
+        &self.weight
+    }
+
+    pub fn max_weight(&self) -> &Weight {
+        &self.max_weight
+    }
+
+    pub fn volume(&self) -> &Volume {
+        &self.volume
+    }
+
+    pub fn max_volume(&self) -> &Volume {
+        &self.max_volume
+    }
 }
 
 pub struct MaterialsStateBuilder<'a> {
@@ -32,22 +79,38 @@ impl<'a> MaterialsStateBuilder<'a> {
     }
 
     pub fn build(self, tribe_id: &TribeId) -> MaterialsState {
+        let game = self.state.game();
+        let tribe_settings = game.tribe_settings().get(tribe_id);
         let mut points = vec![];
-        for storage in self
-            .state
-            .game()
-            .tribe_structures(tribe_id, Some(Structure::Storage))
-        {
-            let content = self
-                .state
-                .world()
+        let mut settings = HashMap::new();
+        let mut capacities = HashMap::new();
+        let world = self.state.world();
+        for storage in game.tribe_structures(tribe_id, Some(Structure::Storage)) {
+            let content = world
                 .materials_on(storage.point(), None)
                 .iter()
                 .map(|(m, q)| (*m, q.clone()))
                 .collect();
             points.push((*storage.point(), content));
+
+            if let Some((max_weight, max_volume)) = storage.type_().storage_capacity() {
+                let (weight, volume) = world.filling(storage.point());
+                capacities.insert(
+                    *storage.point(),
+                    StorageCapacity {
+                        weight,
+                        max_weight,
+                        volume,
+                        max_volume,
+                    },
+                );
+            }
+
+            if let Some(tribe_settings) = tribe_settings {
+                settings.insert(*storage.point(), tribe_settings.storage(storage.point()));
+            }
         }
 
-        MaterialsState::new(points)
+        MaterialsState::new(points, settings, capacities)
     }
 }
@@ -1,38 +1,57 @@
 pub mod build;
 pub mod builder;
+pub mod construction;
 pub mod human;
 pub mod material;
 pub mod target;
 
 use build::BuildGameState;
+use construction::ConstructionsState;
 use human::HumanGameState;
 use material::MaterialsState;
-use neoroll_world::gameplay::tribe::TribeId;
+use neoroll_world::gameplay::{calendar::Calendar, tech::Knowledge, tribe::TribeId};
 use target::TargetsGameState;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ClientGameState {
     tribe_id: TribeId,
+    calendar: Calendar,
     human: HumanGameState,
     build: BuildGameState,
+    constructions: ConstructionsState,
     target: TargetsGameState,
     materials: MaterialsState,
+    knowledge: Knowledge,
+    script_errors: Vec<String>,
+    /// Names of targets defined by scenario scripts
+    script_targets: Vec<String>,
 }
 
 impl ClientGameState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tribe_id: TribeId,
+        calendar: Calendar,
         human: HumanGameState,
         build: BuildGameState,
+        constructions: ConstructionsState,
         target: TargetsGameState,
         materials: MaterialsState,
+        knowledge: Knowledge,
+        script_errors: Vec<String>,
+        script_targets: Vec<String>,
     ) -> Self {
         Self {
             tribe_id,
+            calendar,
             human,
             build,
+            constructions,
             target,
             materials,
+            knowledge,
+            script_errors,
+            script_targets,
         }
     }
 
@@ -40,6 +59,10 @@ impl ClientGameState {
         &self.tribe_id
     }
 
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
     pub fn human(&self) -> &HumanGameState {
         &self.human
     }
@@ -48,6 +71,10 @@ impl ClientGameState {
         &self.build
     }
 
+    pub fn constructions(&self) -> &ConstructionsState {
+        &self.constructions
+    }
+
     pub fn target(&self) -> &TargetsGameState {
         &self.target
     }
@@ -60,4 +87,16 @@ impl ClientGameState {
     pub fn materials(&self) -> &MaterialsState {
         &self.materials
     }
+
+    pub fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
+
+    pub fn script_errors(&self) -> &[String] {
+        &self.script_errors
+    }
+
+    pub fn script_targets(&self) -> &[String] {
+        &self.script_targets
+    }
 }
@@ -1,17 +1,25 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use neoroll_world::{
-    entity::structure::Structure,
+    entity::{creature::CreatureId, structure::Structure},
     gameplay::{
-        build::{Buildable, TryBuildError},
+        build::{construction::Construction, Buildable, TryBuildError},
+        material::{Crafted, Material},
+        order::Order,
         target::{ComputedTarget, Target, TargetId, WaitingReason},
-        tribe::{structure::StructureOwn, Tribe, TribeId},
+        tech::{Knowledge, Technology},
+        tribe::{field::Field, migration::Migration, structure::StructureOwn, Tribe, TribeId},
+        Quantity,
     },
-    space::AbsoluteWorldPoint,
+    map::area::MapArea,
+    space::{area::WorldArea, AbsoluteWorldPoint},
 };
 use settings::{TargetSetting, TribeSettings};
 
-use crate::gateway::ClientId;
+use crate::{action::demolish::DemolishError, gateway::ClientId, script::Scripts};
 
 use super::client::ClientGameState;
 
@@ -26,6 +34,18 @@ pub struct GameState {
     tribe_settings: HashMap<TribeId, TribeSettings>,
     tribe_targets: HashMap<TribeId, Vec<ComputedTarget>>,
     tribe_waitings: HashMap<TribeId, HashMap<TargetId, Vec<WaitingReason>>>,
+    tribe_constructions: HashMap<TribeId, Vec<Construction>>,
+    tribe_explored: HashMap<TribeId, HashSet<AbsoluteWorldPoint>>,
+    tribe_produced: HashMap<TribeId, HashMap<Crafted, Quantity>>,
+    orders: HashMap<CreatureId, Order>,
+    /// Target each creature works for, as affected by jobs affectation
+    worker_targets: HashMap<CreatureId, TargetId>,
+    tribe_knowledge: HashMap<TribeId, Knowledge>,
+    tribe_migrations: HashMap<TribeId, Migration>,
+    tribe_fields: HashMap<TribeId, Vec<Field>>,
+    /// Scenario scripts errors, reported to all clients
+    script_errors: Vec<String>,
+    scripts: Arc<Scripts>,
 }
 
 // FIXME BS NOW: need default value of speed for each clients (connected or not)
@@ -38,6 +58,7 @@ impl GameState {
         // TODO: Is that the good place for tribe init ?
         self.tribe_settings
             .insert(tribe_id, TribeSettings::default());
+        self.tribe_knowledge.insert(tribe_id, Knowledge::default());
     }
 
     pub fn set_client_tribe_id(&mut self, client_id: ClientId, tribe_id: TribeId) {
@@ -83,6 +104,37 @@ impl GameState {
             .push(own);
     }
 
+    pub fn remove_structure_own(&mut self, point: &AbsoluteWorldPoint) {
+        for owns in self.structures_own.values_mut() {
+            owns.retain(|own| own.point() != point);
+        }
+        for settings in self.tribe_settings.values_mut() {
+            settings.storages_mut().remove(point);
+        }
+    }
+
+    /// Tribe center, where tribe creatures gather
+    pub fn tribe_campfire(&self, tribe_id: &TribeId) -> Option<AbsoluteWorldPoint> {
+        self.tribe_structures(tribe_id, Some(Structure::Campfire))
+            .first()
+            .map(|own| *own.point())
+    }
+
+    /// Where tribe lives: its campfire, or the new campfire place while migrating
+    pub fn tribe_home(&self, tribe_id: &TribeId) -> Option<AbsoluteWorldPoint> {
+        match self.tribe_migrations.get(tribe_id) {
+            Some(migration) => Some(*migration.to()),
+            None => self.tribe_campfire(tribe_id),
+        }
+    }
+
+    pub fn structure_own(&self, point: &AbsoluteWorldPoint) -> Option<&StructureOwn> {
+        self.structures_own
+            .values()
+            .flatten()
+            .find(|own| own.point() == point)
+    }
+
     pub fn client_speed_requests(&self) -> &HashMap<ClientId, u8> {
         &self.client_speed_requests
     }
@@ -136,6 +188,202 @@ impl GameState {
     pub fn set_tribe_targets(&mut self, tribe_id: TribeId, value: Vec<ComputedTarget>) {
         self.tribe_targets.insert(tribe_id, value);
     }
+
+    /// Remove target from tribe settings, computed targets and waitings
+    pub fn remove_target(&mut self, tribe_id: &TribeId, target_id: &TargetId) {
+        if let Some(settings) = self.tribe_settings.get_mut(tribe_id) {
+            settings.targets_mut().retain(|(i, _)| i != target_id);
+        }
+        if let Some(targets) = self.tribe_targets.get_mut(tribe_id) {
+            targets.retain(|t| t.id() != target_id);
+        }
+        self.clear_waitings(tribe_id, target_id);
+    }
+
+    pub fn clear_waitings(&mut self, tribe_id: &TribeId, target_id: &TargetId) {
+        if let Some(waitings) = self.tribe_waitings.get_mut(tribe_id) {
+            waitings.remove(target_id);
+        }
+    }
+
+    pub fn tribe_constructions(&self, tribe_id: &TribeId) -> &[Construction] {
+        self.tribe_constructions
+            .get(tribe_id)
+            .map(|c| c.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn construction(&self, point: &AbsoluteWorldPoint) -> Option<&Construction> {
+        self.tribe_constructions
+            .values()
+            .flatten()
+            .find(|c| c.point() == point)
+    }
+
+    pub fn new_construction(&mut self, construction: Construction) {
+        self.tribe_constructions
+            .entry(*construction.tribe_id())
+            .or_default()
+            .push(construction);
+    }
+
+    pub fn work_construction(&mut self, point: &AbsoluteWorldPoint, value: u64) {
+        if let Some(construction) = self
+            .tribe_constructions
+            .values_mut()
+            .flatten()
+            .find(|c| c.point() == point)
+        {
+            construction.work(value);
+        }
+    }
+
+    pub fn remove_construction(&mut self, point: &AbsoluteWorldPoint) {
+        for constructions in self.tribe_constructions.values_mut() {
+            constructions.retain(|c| c.point() != point);
+        }
+    }
+
+    pub fn is_explored(&self, tribe_id: &TribeId, point: &AbsoluteWorldPoint) -> bool {
+        self.tribe_explored
+            .get(tribe_id)
+            .map(|explored| explored.contains(point))
+            .unwrap_or(false)
+    }
+
+    pub fn explore(&mut self, tribe_id: TribeId, points: Vec<AbsoluteWorldPoint>) {
+        self.tribe_explored
+            .entry(tribe_id)
+            .or_default()
+            .extend(points);
+    }
+
+    pub fn produced(&self, tribe_id: &TribeId, crafted: &Crafted) -> Quantity {
+        self.tribe_produced
+            .get(tribe_id)
+            .and_then(|produced| produced.get(crafted))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn produce(&mut self, tribe_id: TribeId, crafted: Crafted, quantity: Quantity) {
+        let produced = self
+            .tribe_produced
+            .entry(tribe_id)
+            .or_default()
+            .entry(crafted)
+            .or_default();
+        *produced = Material::Crafted(crafted).quantity(produced.0 + quantity.0);
+    }
+
+    pub fn order(&self, creature_id: &CreatureId) -> Option<&Order> {
+        self.orders.get(creature_id)
+    }
+
+    pub fn orders(&self) -> &HashMap<CreatureId, Order> {
+        &self.orders
+    }
+
+    pub fn set_order(&mut self, creature_id: CreatureId, order: Order) {
+        self.orders.insert(creature_id, order);
+    }
+
+    pub fn remove_order(&mut self, creature_id: &CreatureId) {
+        self.orders.remove(creature_id);
+    }
+
+    pub fn worker_target(&self, creature_id: &CreatureId) -> Option<&TargetId> {
+        self.worker_targets.get(creature_id)
+    }
+
+    pub fn set_worker_target(&mut self, creature_id: CreatureId, target_id: Option<TargetId>) {
+        match target_id {
+            Some(target_id) => self.worker_targets.insert(creature_id, target_id),
+            None => self.worker_targets.remove(&creature_id),
+        };
+    }
+
+    pub fn knowledge(&self, tribe_id: &TribeId) -> Knowledge {
+        self.tribe_knowledge
+            .get(tribe_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn earn_knowledge(&mut self, tribe_id: &TribeId, points: u64) {
+        self.tribe_knowledge
+            .entry(*tribe_id)
+            .or_default()
+            .earn(points);
+    }
+
+    pub fn discover(&mut self, tribe_id: &TribeId, technology: Technology) {
+        self.tribe_knowledge
+            .entry(*tribe_id)
+            .or_default()
+            .discover(technology);
+    }
+
+    pub fn migration(&self, tribe_id: &TribeId) -> Option<&Migration> {
+        self.tribe_migrations.get(tribe_id)
+    }
+
+    pub fn set_migration(&mut self, tribe_id: TribeId, migration: Migration) {
+        self.tribe_migrations.insert(tribe_id, migration);
+    }
+
+    pub fn remove_migration(&mut self, tribe_id: &TribeId) {
+        self.tribe_migrations.remove(tribe_id);
+    }
+
+    /// Structure is left by its tribe, which is migrating
+    pub fn is_left(&self, point: &AbsoluteWorldPoint) -> bool {
+        self.tribe_migrations.values().any(|m| m.is_left(point))
+    }
+
+    pub fn script_errors(&self) -> &[String] {
+        &self.script_errors
+    }
+
+    /// Same error (like raised at each script call) is reported once
+    pub fn add_script_error(&mut self, error: String) {
+        if !self.script_errors.contains(&error) {
+            self.script_errors.push(error);
+        }
+    }
+
+    pub fn scripts(&self) -> Arc<Scripts> {
+        Arc::clone(&self.scripts)
+    }
+
+    pub fn set_scripts(&mut self, scripts: Scripts) {
+        self.scripts = Arc::new(scripts);
+    }
+
+    pub fn tribe_fields(&self, tribe_id: &TribeId) -> &[Field] {
+        self.tribe_fields
+            .get(tribe_id)
+            .map(|fields| fields.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Field (of any tribe) which would overlap given field
+    pub fn overlapping_field(&self, field: &Field) -> Option<&Field> {
+        self.tribe_fields
+            .values()
+            .flatten()
+            .find(|field_| field_.overlaps(field))
+    }
+
+    pub fn new_field(&mut self, tribe_id: TribeId, field: Field) {
+        self.tribe_fields.entry(tribe_id).or_default().push(field);
+    }
+
+    pub fn remove_field(&mut self, tribe_id: &TribeId, center: &AbsoluteWorldPoint) {
+        if let Some(fields) = self.tribe_fields.get_mut(tribe_id) {
+            fields.retain(|field| field.center() != center);
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -144,25 +392,75 @@ pub enum ClientGameMessage {
     TryBuild(Buildable, AbsoluteWorldPoint),
     RequestServerSpeed(u8),
     Target(TargetId, TargetMessage),
+    CancelConstruction(AbsoluteWorldPoint),
+    Demolish(AbsoluteWorldPoint),
+    StorageAccept(AbsoluteWorldPoint, Material, bool),
+    Order(CreatureId, Order),
+    CancelOrder(CreatureId),
+    Discover(Technology),
+    Migrate(AbsoluteWorldPoint),
+    CancelMigration,
+    NewField(AbsoluteWorldPoint),
+    RemoveField(AbsoluteWorldPoint),
 }
 
 #[derive(Debug)]
 pub enum GameChange {
     ImmediateClientGameStateRefresh(ClientId),
-    SendClientGameState(ClientId, ClientGameState),
+    SendClientGameState(ClientId, Box<ClientGameState>),
+    /// Generate missing streamed chunks of area, then send its layers (except ignored area)
+    SendWorldLayers(ClientId, WorldArea, WorldArea),
+    /// Generate missing streamed chunks of area, then send its sectors (except ignored area)
+    SendMapSectors(ClientId, MapArea, MapArea),
     ComputedTarget(TribeId, ComputedTargetChange),
     Waiting(TribeId, WaitingChange),
+    Construction(ConstructionChange),
+    Explored(TribeId, Vec<AbsoluteWorldPoint>),
+    Produced(TribeId, Crafted, Quantity),
+    Order(CreatureId, OrderChange),
+    /// Target which creature works for (None when it works for no target)
+    WorkerTarget(CreatureId, Option<TargetId>),
+    Discover(TribeId, Technology),
+    EarnKnowledge(TribeId, u64),
+    Migration(TribeId, MigrationChange),
+    Field(TribeId, FieldChange),
+    /// Save world into its directory and unload chunks far from clients and living beings
+    Persist,
+    /// Error raised by a scenario script call
+    ScriptError(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ServerGameMessage {
     TryBuildError(ClientGameMessage, TryBuildError),
+    DemolishError(ClientGameMessage, DemolishError),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TargetMessage {
     Set(TargetSetting),
     New(TargetSetting),
+    Remove,
+    Pause,
+    Resume,
+}
+
+#[derive(Debug)]
+pub enum MigrationChange {
+    Start(Migration),
+    End,
+}
+
+#[derive(Debug)]
+pub enum FieldChange {
+    New(Field),
+    Remove(AbsoluteWorldPoint),
+}
+
+#[derive(Debug)]
+pub enum OrderChange {
+    Set(Order),
+    Remove,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -174,3 +472,10 @@ pub enum ComputedTargetChange {
 pub enum WaitingChange {
     Set(TargetId, Vec<WaitingReason>),
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstructionChange {
+    New(Construction),
+    Work(AbsoluteWorldPoint, u64),
+    Remove(AbsoluteWorldPoint),
+}
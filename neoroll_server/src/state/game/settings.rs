@@ -1,10 +1,16 @@
-use neoroll_world::gameplay::{
-    material::{Material, Resource},
-    target::{Target, TargetId},
+use std::collections::HashMap;
+
+use neoroll_world::{
+    gameplay::{
+        material::{Material, Resource},
+        target::{ComputedTarget, Target, TargetId},
+    },
+    space::AbsoluteWorldPoint,
 };
 
 pub struct TribeSettings {
     targets: Vec<(TargetId, TargetSetting)>,
+    storages: HashMap<AbsoluteWorldPoint, StorageSetting>,
 }
 
 impl TribeSettings {
@@ -15,6 +21,21 @@ impl TribeSettings {
     pub fn targets_mut(&mut self) -> &mut Vec<(TargetId, TargetSetting)> {
         &mut self.targets
     }
+
+    pub fn target_mut(&mut self, id: &TargetId) -> Option<&mut TargetSetting> {
+        self.targets
+            .iter_mut()
+            .find(|(id_, _)| id_ == id)
+            .map(|(_, target)| target)
+    }
+
+    pub fn storage(&self, point: &AbsoluteWorldPoint) -> StorageSetting {
+        self.storages.get(point).cloned().unwrap_or_default()
+    }
+
+    pub fn storages_mut(&mut self) -> &mut HashMap<AbsoluteWorldPoint, StorageSetting> {
+        &mut self.storages
+    }
 }
 
 impl Default for TribeSettings {
@@ -28,6 +49,7 @@ impl Default for TribeSettings {
                     1,
                 ),
             )],
+            storages: HashMap::new(),
         }
     }
 }
@@ -36,11 +58,32 @@ impl Default for TribeSettings {
 pub struct TargetSetting {
     target: Target,
     priority: usize,
+    min_workers: usize,
+    /// No limit if None
+    max_workers: Option<usize>,
+    enabled: bool,
 }
 
 impl TargetSetting {
     pub fn new(target: Target, priority: usize) -> Self {
-        Self { target, priority }
+        Self {
+            target,
+            priority,
+            min_workers: 0,
+            max_workers: None,
+            enabled: true,
+        }
+    }
+
+    pub fn with_target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_workers(mut self, min: usize, max: Option<usize>) -> Self {
+        self.min_workers = min;
+        self.max_workers = max;
+        self
     }
 
     pub fn target(&self) -> &Target {
@@ -50,4 +93,51 @@ impl TargetSetting {
     pub fn priority(&self) -> usize {
         self.priority
     }
+
+    pub fn min_workers(&self) -> usize {
+        self.min_workers
+    }
+
+    pub fn max_workers(&self) -> Option<usize> {
+        self.max_workers
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, value: bool) {
+        self.enabled = value;
+    }
+}
+
+impl From<&ComputedTarget> for TargetSetting {
+    fn from(value: &ComputedTarget) -> Self {
+        Self {
+            target: value.target().clone(),
+            priority: value.priority(),
+            min_workers: value.min_workers(),
+            max_workers: value.max_workers(),
+            enabled: value.enabled(),
+        }
+    }
+}
+
+/// Storages accept all materials by default
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StorageSetting {
+    refused: Vec<Material>,
+}
+
+impl StorageSetting {
+    pub fn accept(&self, material: &Material) -> bool {
+        !self.refused.contains(material)
+    }
+
+    pub fn set_accept(&mut self, material: Material, value: bool) {
+        self.refused.retain(|m| m != &material);
+        if !value {
+            self.refused.push(material);
+        }
+    }
 }
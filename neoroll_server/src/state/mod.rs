@@ -2,16 +2,28 @@ pub mod client;
 pub mod game;
 pub mod world;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::{Add, AddAssign},
     sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use client::builder::ClientGameStateBuilder;
-use game::{ComputedTargetChange, GameChange, GameState, WaitingChange};
+use game::{
+    ComputedTargetChange, ConstructionChange, FieldChange, GameChange, GameState, MigrationChange,
+    OrderChange, WaitingChange,
+};
 use neoroll_world::{
-    map::Map,
-    space::world::{World, WorldChange},
+    entity::creature::{CreatureChange, CreatureId},
+    gameplay::calendar::Calendar,
+    generator,
+    map::{patch::NewSectors, Map},
+    space::{
+        area::WorldArea,
+        chunk::ChunkPoint,
+        patch::NewLayers,
+        world::{World, WorldChange},
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
 };
 use world::WorldModifier;
 
@@ -24,6 +36,9 @@ use crate::{
     target::ComputedTargetBuilder,
 };
 
+/// Streamed world chunks are generated when a creature reach this distance (in tiles)
+const STREAM_DISTANCE: usize = 32;
+
 pub struct State {
     frame_i: FrameI,
     actions: HashMap<ActionId, WrappedAction>,
@@ -54,6 +69,10 @@ impl State {
         &self.frame_i
     }
 
+    pub fn calendar(&self) -> Calendar {
+        Calendar::from_frame(self.frame_i.0)
+    }
+
     pub fn world(&self) -> RwLockReadGuard<World> {
         self.world.read().unwrap()
     }
@@ -66,6 +85,10 @@ impl State {
         self.map.read().unwrap()
     }
 
+    fn map_mut(&self) -> RwLockWriteGuard<Map> {
+        self.map.write().unwrap()
+    }
+
     pub fn game(&self) -> RwLockReadGuard<GameState> {
         self.game.read().unwrap()
     }
@@ -86,6 +109,28 @@ impl State {
         &self.actions
     }
 
+    /// Return ids of actions of given creature
+    pub fn creature_actions(&self, creature_id: &CreatureId) -> Vec<ActionId> {
+        self.actions
+            .iter()
+            .filter(|(_, w)| w.1.creature_id() == Some(creature_id))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Return ids of actions bringing or taking materials at given point
+    pub fn transport_actions(&self, point: &AbsoluteWorldPoint) -> Vec<ActionId> {
+        self.actions
+            .iter()
+            .filter(|(_, w)| match &w.1 {
+                Action::DropOff(drop_off) => drop_off.point() == point,
+                Action::PickUp(pick_up) => pick_up.point() == point,
+                _ => false,
+            })
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
     /// Return actions to tick for current state
     pub fn to_do(&self) -> impl Iterator<Item = (&ActionId, &Action)> {
         self.actions
@@ -99,6 +144,61 @@ impl State {
         self.meta_mut().clear();
     }
 
+    /// Area of `STREAM_DISTANCE` around given point
+    fn stream_area(point: &AbsoluteWorldPoint) -> WorldArea {
+        WorldArea::new(
+            AbsoluteWorldPoint(
+                AbsoluteWorldRowI(point.row_i().0 - STREAM_DISTANCE as isize),
+                AbsoluteWorldColI(point.col_i().0 - STREAM_DISTANCE as isize),
+            ),
+            STREAM_DISTANCE * 2 + 1,
+            STREAM_DISTANCE * 2 + 1,
+        )
+    }
+
+    /// Generate streamed world chunks around given point (if not yet)
+    fn stream_around(&self, point: &AbsoluteWorldPoint) {
+        self.stream(&Self::stream_area(point));
+    }
+
+    /// Generate streamed world chunks of given area (if not yet)
+    fn stream(&self, area: &WorldArea) {
+        if !self.world().missing_chunks(area).is_empty() {
+            generator::stream(&mut self.world_mut(), &mut self.map_mut(), area);
+        }
+    }
+
+    /// Chunks which must stay in memory: around creatures and animals, and
+    /// watched by clients
+    fn used_chunks(&self, subscriptions: &Subscriptions) -> HashSet<ChunkPoint> {
+        let world = self.world();
+        let creatures = world.creatures().values().map(|creature| *creature.point());
+        let animals = world.animals().values().map(|animal| *animal.point());
+
+        creatures
+            .chain(animals)
+            .map(|point| Self::stream_area(&point))
+            .chain(subscriptions.areas().cloned())
+            .flat_map(|area| ChunkPoint::covering(&area))
+            .collect()
+    }
+
+    /// Save changed chunks into world directory (if world has one), then
+    /// unload saved chunks which are not used
+    fn persist(&self, subscriptions: &Subscriptions) {
+        let used = self.used_chunks(subscriptions);
+        let mut world = self.world_mut();
+
+        if let Some(directory) = world.directory().map(|directory| directory.to_path_buf()) {
+            if let Err(error) = world.save(&directory) {
+                eprintln!("Can't save world: {}", error);
+                return;
+            }
+        }
+
+        world.unload_chunks(&used);
+    }
+
     pub fn apply(
         &mut self,
         gateways: &Arc<RwLock<Gateways>>,
@@ -108,6 +208,13 @@ impl State {
         for change in changes {
             match change {
                 StateChange::Action(id, ActionChange::New(action)) => {
+                    // Creature can have been removed by a change of the same batch
+                    if let Some(creature_id) = action.creature_id() {
+                        if !self.world().creatures().contains_key(creature_id) {
+                            continue;
+                        }
+                    }
+
                     let next = NextTick::new(self.frame_i + 1);
                     for change in action.stamp() {
                         WorldModifier::new(
@@ -121,11 +228,16 @@ impl State {
 
                     self.actions.insert(id, WrappedAction(next, action));
                 }
+                // Action can have been removed by a change of the same batch
                 StateChange::Action(id, ActionChange::SetNextTick(next)) => {
-                    self.actions.get_mut(&id).unwrap().0 = next;
+                    if let Some(action) = self.actions.get_mut(&id) {
+                        action.0 = next;
+                    }
                 }
                 StateChange::Action(id, ActionChange::Update(change)) => {
-                    self.actions.get_mut(&id).unwrap().1.apply(change);
+                    if let Some(action) = self.actions.get_mut(&id) {
+                        action.1.apply(change);
+                    }
                 }
                 StateChange::Action(id, ActionChange::Remove) => {
                     if let Some(action) = self.actions.get(&id) {
@@ -143,6 +255,16 @@ impl State {
                     self.actions.remove(&id);
                 }
                 StateChange::World(change) => {
+                    // Actions of a removed creature are dropped without take off (creature is gone)
+                    if let WorldChange::Creature(creature_id, CreatureChange::Remove) = &change {
+                        self.actions
+                            .retain(|_, action| action.1.creature_id() != Some(creature_id));
+                    }
+                    let reached = match &change {
+                        WorldChange::Creature(_, CreatureChange::SetPoint(point)) => Some(*point),
+                        _ => None,
+                    };
+
                     WorldModifier::new(
                         gateways,
                         subscriptions,
@@ -150,6 +272,10 @@ impl State {
                         &mut self.game_mut(),
                     )
                     .apply(change);
+
+                    if let Some(point) = reached {
+                        self.stream_around(&point);
+                    }
                 }
                 StateChange::Game(change) => match change {
                     GameChange::SendClientGameState(client_id, state) => {
@@ -158,7 +284,33 @@ impl State {
                             .unwrap()
                             .send(ServerMessageEnveloppe::To(
                                 client_id,
-                                ServerMessage::NewClientGameState(state),
+                                ServerMessage::NewClientGameState(*state),
+                            ))
+                            .unwrap();
+                    }
+                    GameChange::SendWorldLayers(client_id, area, ignore_area) => {
+                        self.stream(&area);
+                        let new_layers =
+                            NewLayers::from_world_area(&self.world(), &area, &ignore_area);
+                        gateways
+                            .read()
+                            .unwrap()
+                            .send(ServerMessageEnveloppe::To(
+                                client_id,
+                                ServerMessage::NewWorldLayers(area, new_layers),
+                            ))
+                            .unwrap();
+                    }
+                    GameChange::SendMapSectors(client_id, area, ignore_area) => {
+                        self.stream(&WorldArea::from(&area));
+                        let new_sectors =
+                            NewSectors::from_map_area(&self.map(), &area, &ignore_area);
+                        gateways
+                            .read()
+                            .unwrap()
+                            .send(ServerMessageEnveloppe::To(
+                                client_id,
+                                ServerMessage::NewMapSectors(area, new_sectors),
                             ))
                             .unwrap();
                     }
@@ -196,6 +348,70 @@ impl State {
                             }
                         };
                     }
+                    GameChange::Construction(change) => {
+                        match change {
+                            ConstructionChange::New(construction) => {
+                                self.game_mut().new_construction(construction);
+                            }
+                            ConstructionChange::Work(point, value) => {
+                                self.game_mut().work_construction(&point, value);
+                            }
+                            ConstructionChange::Remove(point) => {
+                                self.game_mut().remove_construction(&point);
+                            }
+                        };
+                    }
+                    GameChange::Explored(tribe_id, points) => {
+                        self.game_mut().explore(tribe_id, points);
+                    }
+                    GameChange::Produced(tribe_id, crafted, quantity) => {
+                        self.game_mut().produce(tribe_id, crafted, quantity);
+                    }
+                    GameChange::Discover(tribe_id, technology) => {
+                        self.game_mut().discover(&tribe_id, technology);
+                    }
+                    GameChange::EarnKnowledge(tribe_id, points) => {
+                        self.game_mut().earn_knowledge(&tribe_id, points);
+                    }
+                    GameChange::Migration(tribe_id, change) => {
+                        match change {
+                            MigrationChange::Start(migration) => {
+                                self.game_mut().set_migration(tribe_id, migration);
+                            }
+                            MigrationChange::End => {
+                                self.game_mut().remove_migration(&tribe_id);
+                            }
+                        };
+                    }
+                    GameChange::Field(tribe_id, change) => {
+                        match change {
+                            FieldChange::New(field) => {
+                                self.game_mut().new_field(tribe_id, field);
+                            }
+                            FieldChange::Remove(center) => {
+                                self.game_mut().remove_field(&tribe_id, &center);
+                            }
+                        };
+                    }
+                    GameChange::Persist => {
+                        self.persist(&subscriptions.read().unwrap());
+                    }
+                    GameChange::ScriptError(error) => {
+                        self.game_mut().add_script_error(error);
+                    }
+                    GameChange::WorkerTarget(creature_id, target_id) => {
+                        self.game_mut().set_worker_target(creature_id, target_id);
+                    }
+                    GameChange::Order(creature_id, change) => {
+                        match change {
+                            OrderChange::Set(order) => {
+                                self.game_mut().set_order(creature_id, order);
+                            }
+                            OrderChange::Remove => {
+                                self.game_mut().remove_order(&creature_id);
+                            }
+                        };
+                    }
                 },
             };
         }
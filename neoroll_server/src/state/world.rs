@@ -1,11 +1,15 @@
 use std::sync::{Arc, RwLock};
 
 use neoroll_world::{
-    entity::creature::{CreatureChange, CreatureId, PartialCreatureChange},
+    entity::{
+        animal::{Animal, AnimalChange, AnimalId},
+        creature::{CreatureChange, CreatureId, PartialCreatureChange},
+    },
     space::{
         part::{
-            WorldPartCreatureMessage, WorldPartFloorMessage, WorldPartGroundMessage,
-            WorldPartMaterialMessage, WorldPartMessage, WorldPartStructureMessage,
+            WorldPartAnimalMessage, WorldPartCreatureMessage, WorldPartFloorMessage,
+            WorldPartGroundMessage, WorldPartMaterialMessage, WorldPartMessage,
+            WorldPartStructureMessage,
         },
         world::{FloorChange, GroundChange, MaterialChange, StructureChange, World, WorldChange},
         AbsoluteWorldPoint,
@@ -52,8 +56,21 @@ impl<'a> WorldModifier<'a> {
         }
     }
 
-    fn send_to_creature_clients(&self, id: &CreatureId, message: ServerMessage) {
-        for client_id in self.subscriptions.read().unwrap().to_creature(id) {
+    /// Send animal to clients seeing its previous or its new point
+    fn send_animal(&self, previous: &AbsoluteWorldPoint, animal: &Animal) {
+        let subscriptions = self.subscriptions.read().unwrap();
+        let mut client_ids = subscriptions.to_point(previous);
+        for client_id in subscriptions.to_point(animal.point()) {
+            if !client_ids.contains(&client_id) {
+                client_ids.push(client_id);
+            }
+        }
+
+        let message = ServerMessage::WorldPart(WorldPartMessage::Animal(
+            *animal.id(),
+            WorldPartAnimalMessage::Set(animal.clone()),
+        ));
+        for client_id in client_ids {
             self.gateways
                 .read()
                 .unwrap()
@@ -62,71 +79,157 @@ impl<'a> WorldModifier<'a> {
         }
     }
 
-    pub fn apply(&mut self, change: WorldChange) {
+    fn apply_animal(&mut self, id: AnimalId, change: AnimalChange) {
         match change {
-            WorldChange::Creature(id, change) => match change {
-                CreatureChange::New(creature) => {
-                    let point = *creature.point();
-                    self.world.add_creature(creature.clone());
-                    self.send_to_point_clients(
-                        &point,
-                        ServerMessage::WorldPart(WorldPartMessage::Creature(
-                            *creature.id(),
-                            WorldPartCreatureMessage::New(creature.clone().into()),
-                        )),
-                    );
-                }
-                CreatureChange::SetPoint(point) => {
-                    self.world
-                        .creatures_mut()
-                        .get_mut(&id)
-                        .unwrap()
-                        .set_point(point);
-                    self.send_to_creature_clients(
-                        &id,
-                        ServerMessage::Creature(id, PartialCreatureChange::SetPoint(point)),
-                    );
+            AnimalChange::New(animal) => {
+                self.world.add_animal(animal.clone());
+                self.send_animal(animal.point(), &animal);
+            }
+            AnimalChange::SetPoint(point) => {
+                if let Some(animal) = self.world.animals_mut().get_mut(&id) {
+                    let previous = *animal.point();
+                    animal.set_point(point);
+                    let animal = animal.clone();
+                    self.send_animal(&previous, &animal);
                 }
-                CreatureChange::SetJob(job) => {
-                    self.world
-                        .creatures_mut()
-                        .get_mut(&id)
-                        .unwrap()
-                        .set_job(job.clone());
-                    self.send_to_creature_clients(
-                        &id,
-                        ServerMessage::Creature(id, PartialCreatureChange::SetJob(job)),
-                    );
+            }
+            AnimalChange::SetBehavior(behavior) => {
+                if let Some(animal) = self.world.animals_mut().get_mut(&id) {
+                    animal.set_behavior(behavior);
                 }
-                CreatureChange::SetBehavior(behavior) => {
-                    self.world
-                        .creatures_mut()
-                        .get_mut(&id)
-                        .unwrap()
-                        .set_behavior(behavior.clone());
-                    self.send_to_creature_clients(
-                        &id,
-                        ServerMessage::Creature(id, PartialCreatureChange::SetBehavior(behavior)),
+            }
+            AnimalChange::Remove => {
+                if let Some(animal) = self.world.remove_animal(&id) {
+                    self.send_to_point_clients(
+                        animal.point(),
+                        ServerMessage::WorldPart(WorldPartMessage::Animal(
+                            id,
+                            WorldPartAnimalMessage::Remove,
+                        )),
                     );
                 }
-                CreatureChange::AddToCarrying(material, quantity) => {
-                    self.world
-                        .creatures_mut()
-                        .get_mut(&id)
-                        .unwrap()
-                        .add_to_carrying(material, quantity);
-                }
-                CreatureChange::RemoveFromCarrying(material, quantity) => {
-                    self.world
-                        .creatures_mut()
-                        .get_mut(&id)
-                        .unwrap()
-                        .remove_from_carrying(material, quantity);
+            }
+        }
+    }
+
+    fn send_to_creature_clients(&self, id: &CreatureId, message: ServerMessage) {
+        for client_id in self.subscriptions.read().unwrap().to_creature(id) {
+            self.gateways
+                .read()
+                .unwrap()
+                .send(ServerMessageEnveloppe::To(client_id, message.clone()))
+                .unwrap();
+        }
+    }
+
+    /// Send creature removal to clients seeing it or subscribed to it
+    fn send_creature_removed(&self, id: &CreatureId, point: &AbsoluteWorldPoint) {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let mut client_ids = subscriptions.to_point(point);
+        for client_id in subscriptions.to_creature(id) {
+            if !client_ids.contains(&client_id) {
+                client_ids.push(client_id);
+            }
+        }
+        subscriptions.remove_creature(id);
+
+        let message = ServerMessage::WorldPart(WorldPartMessage::Creature(
+            *id,
+            WorldPartCreatureMessage::Remove,
+        ));
+        for client_id in client_ids {
+            self.gateways
+                .read()
+                .unwrap()
+                .send(ServerMessageEnveloppe::To(client_id, message.clone()))
+                .unwrap();
+        }
+    }
+
+    fn apply_creature(&mut self, id: CreatureId, change: CreatureChange) {
+        if let CreatureChange::New(creature) = change {
+            let point = *creature.point();
+            self.world.add_creature(creature.clone());
+            self.send_to_point_clients(
+                &point,
+                ServerMessage::WorldPart(WorldPartMessage::Creature(
+                    *creature.id(),
+                    WorldPartCreatureMessage::New(creature.clone().into()),
+                )),
+            );
+            return;
+        }
+
+        if let CreatureChange::Remove = change {
+            if let Some(creature) = self.world.remove_creature(&id) {
+                self.game.remove_order(&id);
+                self.game.set_worker_target(id, None);
+                self.send_creature_removed(&id, creature.point());
+            }
+            return;
+        }
+
+        // Creature can have been removed by a change applied in the same tick
+        let creature = match self.world.creatures_mut().get_mut(&id) {
+            Some(creature) => creature,
+            None => return,
+        };
+
+        let tribe_id = *creature.tribe_id();
+        let message = match change {
+            CreatureChange::New(_) | CreatureChange::Remove => None,
+            CreatureChange::SetPoint(point) => {
+                creature.set_point(point);
+                Some(PartialCreatureChange::SetPoint(point))
+            }
+            CreatureChange::SetJob(job) => {
+                creature.set_job(job.clone());
+                Some(PartialCreatureChange::SetJob(job))
+            }
+            CreatureChange::SetBehavior(behavior) => {
+                creature.set_behavior(behavior.clone());
+                Some(PartialCreatureChange::SetBehavior(behavior))
+            }
+            CreatureChange::AddToCarrying(material, quantity) => {
+                // Gameplay bug which must not stop the world
+                if let Err(error) = creature.add_to_carrying(material, quantity) {
+                    eprintln!("Carrying of {} not changed: {}", material, error);
                 }
-            },
+                None
+            }
+            CreatureChange::RemoveFromCarrying(material, quantity) => {
+                creature.remove_from_carrying(material, quantity);
+                None
+            }
+            CreatureChange::Practice(skill, experience) => {
+                creature.practice(skill, experience);
+                // Tribe learns from its creatures activity: one knowledge point by experience
+                self.game.earn_knowledge(&tribe_id, experience as u64);
+                Some(PartialCreatureChange::Practice(skill, experience))
+            }
+            CreatureChange::SetAge(age) => {
+                creature.set_age(age);
+                Some(PartialCreatureChange::SetAge(age))
+            }
+            CreatureChange::SetHunger(hunger) => {
+                creature.set_hunger(hunger);
+                Some(PartialCreatureChange::SetHunger(hunger))
+            }
+        };
+
+        if let Some(message) = message {
+            self.send_to_creature_clients(&id, ServerMessage::Creature(id, message));
+        }
+    }
+
+    pub fn apply(&mut self, change: WorldChange) {
+        match change {
+            WorldChange::Creature(id, change) => self.apply_creature(id, change),
+            WorldChange::Animal(id, change) => self.apply_animal(id, change),
             WorldChange::Structure(point, change) => match change {
                 StructureChange::Set(structure) => {
-                    // FIXME: if None, destroy game.structures_own related objects
+                    // Replaced structure is no longer owned (owned ones are set with `SetOwned`)
+                    self.game.remove_structure_own(&point);
                     self.world.set_structure(point, structure.clone());
 
                     self.send_to_point_clients(
@@ -138,6 +241,7 @@ impl<'a> WorldModifier<'a> {
                     );
                 }
                 StructureChange::SetOwned(own) => {
+                    self.game.remove_structure_own(own.point());
                     self.world
                         .set_structure(*own.point(), Some(own.type_().clone()));
                     self.game.set_structure_own(own.clone());
@@ -179,7 +283,23 @@ impl<'a> WorldModifier<'a> {
             },
             WorldChange::Material(point, change) => match change {
                 MaterialChange::Add(material, quantity) => {
-                    self.world.add_material(point, material, quantity.clone());
+                    // Gameplay bug which must not stop the world
+                    if let Err(error) = self.world.add_material(point, material, quantity.clone()) {
+                        eprintln!("Material {} not added on {:?}: {}", material, point, error);
+                    }
+                    self.send_to_point_clients(
+                        &point,
+                        ServerMessage::WorldPart(WorldPartMessage::Material(
+                            point,
+                            WorldPartMaterialMessage::Set(
+                                self.world.material(&point).cloned().unwrap_or(vec![]),
+                            ),
+                        )),
+                    );
+                }
+                MaterialChange::Remove(material, quantity) => {
+                    self.world
+                        .remove_material(point, material, quantity.clone());
                     self.send_to_point_clients(
                         &point,
                         ServerMessage::WorldPart(WorldPartMessage::Material(
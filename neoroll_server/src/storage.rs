@@ -0,0 +1,229 @@
+use neoroll_world::{
+    entity::structure::Structure,
+    gameplay::{material::Material, tribe::TribeId, Quantity},
+    space::{world::World, AbsoluteWorldPoint},
+};
+
+use crate::state::{game::GameState, State};
+
+/// Count of reachable storages, nearest as the crow flies, to rank by walking cost
+const PATHFINDING_CANDIDATES: usize = 3;
+/// Maximum count of path searches (reachable or not) for one storage selection
+const PATHFINDING_SEARCHES: usize = 6;
+
+/// Select tribe storages for a creature, nearest (by walking cost) first
+pub struct StorageFinder<'a> {
+    state: &'a State,
+    tribe_id: TribeId,
+}
+
+impl<'a> StorageFinder<'a> {
+    pub fn new(state: &'a State, tribe_id: TribeId) -> Self {
+        Self { state, tribe_id }
+    }
+
+    /// Storages matching given filter sorted by walking cost from given point
+    /// (unreachable ones are excluded). Storages are ranked as the crow flies
+    /// first, so paths are only searched for the nearest ones.
+    fn nearest(
+        &self,
+        world: &World,
+        game: &GameState,
+        from: &AbsoluteWorldPoint,
+        filter: impl Fn(&AbsoluteWorldPoint) -> bool,
+    ) -> Vec<AbsoluteWorldPoint> {
+        let mut candidates = game
+            .tribe_structures(&self.tribe_id, Some(Structure::Storage))
+            .iter()
+            .map(|storage| *storage.point())
+            .filter(|point| filter(point))
+            .collect::<Vec<AbsoluteWorldPoint>>();
+        candidates.sort_by_key(|point| point.squared_distance(from));
+
+        let mut storages = vec![];
+        let mut searches = 0;
+        for point in candidates {
+            if storages.len() >= PATHFINDING_CANDIDATES || searches >= PATHFINDING_SEARCHES {
+                break;
+            }
+
+            let cost = if &point == from {
+                Some(0)
+            } else {
+                searches += 1;
+                world.find_path(from, &point).map(|(_, cost)| cost)
+            };
+            if let Some(cost) = cost {
+                storages.push((cost, point));
+            }
+        }
+        storages.sort_by_key(|(cost, _)| *cost);
+
+        storages.into_iter().map(|(_, point)| point).collect()
+    }
+
+    pub fn stored(&self, point: &AbsoluteWorldPoint, material: &Material) -> Quantity {
+        stored(&self.state.world(), point, material)
+    }
+
+    /// Storages which accept given material and have remaining room for it (storages
+    /// left by a migrating tribe are excluded)
+    pub fn drop_off(
+        &self,
+        from: &AbsoluteWorldPoint,
+        material: &Material,
+    ) -> Vec<AbsoluteWorldPoint> {
+        let game = self.state.game();
+        let world = self.state.world();
+        let settings = game.tribe_settings().get(&self.tribe_id);
+
+        self.nearest(&world, &game, from, |point| {
+            let accept = settings
+                .map(|s| s.storage(point).accept(material))
+                .unwrap_or(true);
+            let room = world.storage_room(point, material).unwrap_or_default();
+            accept && room.0 > 0 && !game.is_left(point)
+        })
+    }
+
+    /// Storages containing at least given quantity of material
+    pub fn pick_up(
+        &self,
+        from: &AbsoluteWorldPoint,
+        material: &Material,
+        quantity: &Quantity,
+    ) -> Vec<AbsoluteWorldPoint> {
+        let game = self.state.game();
+        let world = self.state.world();
+
+        self.nearest(&world, &game, from, |point| {
+            stored(&world, point, material).0 >= quantity.0
+        })
+    }
+}
+
+fn stored(world: &World, point: &AbsoluteWorldPoint, material: &Material) -> Quantity {
+    world.material_quantity(point, *material)
+}
+
+#[cfg(test)]
+mod test {
+    use neoroll_world::{
+        entity::{elevation::Elevation, ground::Ground, Filled},
+        gameplay::material::Resource,
+    };
+    use rstest::*;
+
+    use crate::testing::{self, own, point};
+
+    use super::*;
+
+    /// Tribe storages on a world where (0, 2) is surrounded by water
+    fn state(tribe_id: TribeId, storages: Vec<AbsoluteWorldPoint>) -> State {
+        let mut world = testing::world(3, 5, vec![]);
+        for (row, col) in [(0, 1), (0, 3), (1, 1), (1, 2), (1, 3)] {
+            world.set_ground(point(row, col), Ground::FreshWater(Filled::full()));
+        }
+        let mut game = GameState::default();
+        for storage in storages {
+            own(&mut world, &mut game, tribe_id, Structure::Storage, storage);
+        }
+
+        testing::state(world, game)
+    }
+
+    #[rstest]
+    #[case(vec![point(1, 4), point(2, 0)], vec![point(2, 0), point(1, 4)])]
+    #[case(vec![point(0, 0), point(2, 2)], vec![point(0, 0), point(2, 2)])]
+    // Nearest storage as the crow flies is not reachable
+    #[case(vec![point(0, 2), point(1, 4)], vec![point(1, 4)])]
+    #[case(vec![point(0, 2)], vec![])]
+    fn test_drop_off_nearest(
+        #[case] storages: Vec<AbsoluteWorldPoint>,
+        #[case] expected: Vec<AbsoluteWorldPoint>,
+    ) {
+        // Given
+        let tribe_id = TribeId::new();
+        let state = state(tribe_id, storages);
+
+        // When
+        let found = StorageFinder::new(&state, tribe_id)
+            .drop_off(&point(0, 0), &Material::Resource(Resource::Food));
+
+        // Then
+        assert_eq!(found, expected);
+    }
+
+    #[rstest]
+    fn test_drop_off_nearest_by_walking_cost() {
+        // Given
+        let tribe_id = TribeId::new();
+        let elevations = [0, 0, 0, 0, 12, 24, 0, 0].map(Elevation).to_vec();
+        let mut world = testing::world_with_elevations(1, 8, elevations, vec![]);
+        let mut game = GameState::default();
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(0, 0),
+        );
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(0, 5),
+        );
+        let state = testing::state(world, game);
+
+        // When
+        let found = StorageFinder::new(&state, tribe_id)
+            .drop_off(&point(0, 3), &Material::Resource(Resource::Food));
+
+        // Then
+        // Nearest storage by steps is uphill, so more costly to reach
+        assert_eq!(found, vec![point(0, 0), point(0, 5)]);
+    }
+
+    #[rstest]
+    #[case(PATHFINDING_SEARCHES - 1, vec![point(9, 0)])]
+    #[case(PATHFINDING_SEARCHES, vec![])]
+    fn test_drop_off_nearest_searches_limit(
+        #[case] unreachable: usize,
+        #[case] expected: Vec<AbsoluteWorldPoint>,
+    ) {
+        // Given
+        let tribe_id = TribeId::new();
+        let mut world = testing::world(10, 4, vec![]);
+        let mut game = GameState::default();
+        for row in 0..10 {
+            world.set_ground(point(row, 2), Ground::FreshWater(Filled::full()));
+        }
+        // Behind water, but nearer as the crow flies than the reachable one
+        for row in 0..unreachable {
+            own(
+                &mut world,
+                &mut game,
+                tribe_id,
+                Structure::Storage,
+                point(row as isize, 3),
+            );
+        }
+        own(
+            &mut world,
+            &mut game,
+            tribe_id,
+            Structure::Storage,
+            point(9, 0),
+        );
+        let state = testing::state(world, game);
+
+        // When
+        let found = StorageFinder::new(&state, tribe_id)
+            .drop_off(&point(0, 0), &Material::Resource(Resource::Food));
+
+        // Then
+        assert_eq!(found, expected);
+    }
+}
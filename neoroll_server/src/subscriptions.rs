@@ -28,6 +28,11 @@ impl Subscriptions {
         }
     }
 
+    /// World areas watched by clients
+    pub fn areas(&self) -> impl Iterator<Item = &WorldArea> {
+        self.areas.values()
+    }
+
     pub fn set_creatures(&mut self, client_id: ClientId, creature_ids: Vec<CreatureId>) {
         self.creatures.insert(client_id, creature_ids);
     }
@@ -39,6 +44,13 @@ impl Subscriptions {
             .push(creature_id);
     }
 
+    /// Forget a creature which no longer exists
+    pub fn remove_creature(&mut self, creature_id: &CreatureId) {
+        for creature_ids in self.creatures.values_mut() {
+            creature_ids.retain(|id| id != creature_id);
+        }
+    }
+
     pub fn to_point(&self, point: &AbsoluteWorldPoint) -> Vec<ClientId> {
         self.areas
             .iter()
@@ -10,7 +10,10 @@ use neoroll_world::{
     },
 };
 
-use crate::state::State;
+use crate::{
+    action::job::realize::{explore::exploration_area, farm::field_works, plant::trees_around},
+    state::State,
+};
 
 pub struct ComputedTargetBuilder<'a> {
     state: &'a State,
@@ -36,28 +39,53 @@ impl<'a> ComputedTargetBuilder<'a> {
             let satisfied = needs
                 .iter()
                 .all(|n| n.satisfied(&self.tribe_id, self.state));
+            let urgency = needs
+                .iter()
+                .map(|n| 1. - n.progress(&self.tribe_id, self.state))
+                .fold(0., f32::max);
 
             let affected = world
                 .tribe_creature_ids(&self.tribe_id)
                 .unwrap_or(&vec![])
                 .iter()
                 .map(|i| world.creatures().get(i).expect("Id just retrieved"))
-                .filter(|c| c.job() == &Job::from(target.target()))
+                .filter(|c| self.works_for(c, target.target()))
                 .collect::<Vec<&Creature>>()
                 .len();
 
-            computed_targets.push(ComputedTarget::new(
-                target_id,
-                target.target().clone(),
-                satisfied,
-                affected,
-                needs.clone(),
-                target.priority(),
-            ));
+            computed_targets.push(
+                ComputedTarget::new(
+                    target_id,
+                    target.target().clone(),
+                    satisfied,
+                    affected,
+                    needs.clone(),
+                    target.priority(),
+                    (urgency.clamp(0., 1.) * 100.) as u8,
+                )
+                .with_workers(target.min_workers(), target.max_workers())
+                .with_enabled(target.enabled()),
+            );
         }
 
         computed_targets
     }
+
+    fn works_for(&self, creature: &Creature, target: &Target) -> bool {
+        match target {
+            // Builders work on construction sites of the target buildable
+            Target::KeepBuilt(buildable, _) => match creature.job() {
+                Job::Build(point) => self
+                    .state
+                    .game()
+                    .construction(point)
+                    .map(|c| c.buildable() == buildable)
+                    .unwrap_or(false),
+                _ => false,
+            },
+            _ => Some(creature.job()) == target.job().as_ref(),
+        }
+    }
 }
 
 pub trait IntoQuantity {
@@ -75,7 +103,7 @@ impl IntoQuantity for TargetQuantity {
                     .tribe_creature_ids(tribe_id)
                     .unwrap_or(&vec![])
                     .len();
-                Quantity(quantity.0 * tribe_humans as u64)
+                quantity.clone() * tribe_humans as u64
             }
         }
     }
@@ -95,12 +123,25 @@ impl IntoSatisfactionNeeds for Target {
                     quantity.resolve_quantity(state, tribe_id),
                 )]
             }
+            Target::KeepBuilt(buildable, count) => vec![Need::StructuresBuilt(*buildable, *count)],
+            Target::Explore(radius) => vec![Need::Explored(*radius)],
+            Target::Produce(crafted, quantity) => {
+                vec![Need::Produced(
+                    Material::Crafted(*crafted),
+                    quantity.clone(),
+                )]
+            }
+            Target::Plant(count) => vec![Need::TreesAround(*count)],
+            Target::Farm => vec![Need::FieldsTended],
+            Target::Scripted(name) => vec![Need::Scripted(name.clone())],
         }
     }
 }
 
 trait Satisfied {
     fn satisfied(&self, tribe_id: &TribeId, state: &State) -> bool;
+    /// Accomplished part of the need, from 0.0 to 1.0
+    fn progress(&self, tribe_id: &TribeId, state: &State) -> f32;
 }
 
 impl Satisfied for Need {
@@ -111,16 +152,92 @@ impl Satisfied for Need {
         match self {
             Need::MaterialInStorages(material, quantity) => {
                 game.tribe_structures(tribe_id, Some(Structure::Storage))
+                    .iter()
+                    .map(|s| world.material_quantity(s.point(), *material).0)
+                    .sum::<u64>()
+                    >= quantity.0
+            }
+            Need::StructuresBuilt(buildable, count) => {
+                game.tribe_structures(tribe_id, Some((*buildable).into()))
+                    .len()
+                    >= *count
+            }
+            Need::Explored(radius) => match game.tribe_campfire(tribe_id) {
+                Some(campfire) => exploration_area(&world, &campfire, *radius)
+                    .iter()
+                    .all(|point| game.is_explored(tribe_id, point)),
+                None => false,
+            },
+            Need::Produced(material, quantity) => match material {
+                Material::Crafted(crafted) => game.produced(tribe_id, crafted).0 >= quantity.0,
+                Material::Resource(_) | Material::Hunted(_) => false,
+            },
+            Need::TreesAround(count) => match game.tribe_campfire(tribe_id) {
+                Some(campfire) => trees_around(&world, &campfire) >= *count,
+                None => false,
+            },
+            Need::FieldsTended => field_works(state, tribe_id).is_empty(),
+            Need::Scripted(_) => self.progress(tribe_id, state) >= 1.,
+        }
+    }
+    fn progress(&self, tribe_id: &TribeId, state: &State) -> f32 {
+        let game = state.game();
+        let world = state.world();
+        let ratio = |value: u64, expected: u64| {
+            if expected == 0 {
+                return 1.;
+            }
+            (value as f32 / expected as f32).min(1.)
+        };
+
+        match self {
+            Need::MaterialInStorages(material, quantity) => {
+                let stored = game
+                    .tribe_structures(tribe_id, Some(Structure::Storage))
                     .iter()
                     .map(|s| world.materials_on(s.point(), Some(*material)))
                     .collect::<Vec<Vec<&(Material, Quantity)>>>()
                     .concat()
                     .iter()
-                    .map(|(_, q)| q.clone())
-                    .sum::<Quantity>()
-                    .0
-                    >= quantity.0
+                    .map(|(_, q)| q.0)
+                    .sum::<u64>();
+                ratio(stored, quantity.0)
+            }
+            Need::StructuresBuilt(buildable, count) => {
+                let built = game
+                    .tribe_structures(tribe_id, Some((*buildable).into()))
+                    .len();
+                ratio(built as u64, *count as u64)
+            }
+            Need::Explored(radius) => match game.tribe_campfire(tribe_id) {
+                Some(campfire) => {
+                    let area = exploration_area(&world, &campfire, *radius);
+                    let explored = area
+                        .iter()
+                        .filter(|point| game.is_explored(tribe_id, point))
+                        .count();
+                    ratio(explored as u64, area.len() as u64)
+                }
+                None => 0.,
+            },
+            Need::Produced(material, quantity) => match material {
+                Material::Crafted(crafted) => ratio(game.produced(tribe_id, crafted).0, quantity.0),
+                Material::Resource(_) | Material::Hunted(_) => 0.,
+            },
+            Need::TreesAround(count) => match game.tribe_campfire(tribe_id) {
+                Some(campfire) => ratio(trees_around(&world, &campfire) as u64, *count as u64),
+                None => 0.,
+            },
+            Need::FieldsTended => {
+                let total = game
+                    .tribe_fields(tribe_id)
+                    .iter()
+                    .map(|field| field.points().len())
+                    .sum::<usize>();
+                let pending = field_works(state, tribe_id).len();
+                ratio(total.saturating_sub(pending) as u64, total as u64)
             }
+            Need::Scripted(name) => game.scripts().progress(state, tribe_id, name),
         }
     }
 }
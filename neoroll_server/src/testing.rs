@@ -0,0 +1,109 @@
+use std::sync::{Arc, RwLock};
+
+use neoroll_world::{
+    entity::{
+        creature::Creature, elevation::Elevation, floor::Floor, ground::Ground,
+        structure::Structure,
+    },
+    gameplay::{
+        lifecycle::Age,
+        tribe::{structure::StructureOwn, TribeId},
+    },
+    space::{
+        layer::{CompositeLayer, FilledLayer, Layers},
+        world::World,
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
+};
+
+use crate::{
+    action::ActionChange,
+    gateway::Gateways,
+    state::{game::GameState, State, StateChange},
+    subscriptions::Subscriptions,
+};
+
+pub fn point(row: isize, col: isize) -> AbsoluteWorldPoint {
+    AbsoluteWorldPoint(AbsoluteWorldRowI(row), AbsoluteWorldColI(col))
+}
+
+/// Flat soil world, without floor nor structure
+pub fn world(lines: usize, columns: usize, creatures: Vec<Creature>) -> World {
+    let elevations = vec![Elevation::default(); lines * columns];
+    world_with_elevations(lines, columns, elevations, creatures)
+}
+
+/// Soil world, without floor nor structure, with given (row major) elevations
+pub fn world_with_elevations(
+    lines: usize,
+    columns: usize,
+    elevations: Vec<Elevation>,
+    creatures: Vec<Creature>,
+) -> World {
+    let count = lines * columns;
+    World::new(
+        Layers::new(
+            FilledLayer::new(vec![Ground::Soil; count]),
+            FilledLayer::new(vec![Floor::Nothing; count]),
+            CompositeLayer::new(vec![None; count]),
+            FilledLayer::new(vec![vec![]; count]),
+            FilledLayer::new(elevations),
+        ),
+        lines,
+        columns,
+        creatures,
+    )
+}
+
+/// Adult creature of given tribe
+pub fn creature(tribe_id: TribeId, point: AbsoluteWorldPoint) -> Creature {
+    Creature::new(
+        Default::default(),
+        tribe_id,
+        "Bob".to_string(),
+        Age::from_years(20),
+        point,
+    )
+}
+
+/// Place given structure in world and give it to given tribe
+pub fn own(
+    world: &mut World,
+    game: &mut GameState,
+    tribe_id: TribeId,
+    structure: Structure,
+    point: AbsoluteWorldPoint,
+) {
+    world.set_structure(point, Some(structure.clone()));
+    game.set_structure_own(StructureOwn::new(structure, tribe_id, point));
+}
+
+pub fn state(world: World, game: GameState) -> State {
+    State::new(
+        Arc::new(RwLock::new(world)),
+        Default::default(),
+        Arc::new(RwLock::new(game)),
+        Default::default(),
+    )
+}
+
+/// Apply given changes like the runner does (without any client)
+pub fn apply(state: &mut State, changes: Vec<StateChange>) {
+    let gateways = Arc::new(RwLock::new(Gateways::new()));
+    let subscriptions = Arc::new(RwLock::new(Subscriptions::default()));
+    state.apply(&gateways, &subscriptions, changes);
+}
+
+/// Tick actions during given frames count, like the runner does (sequentially)
+pub fn run(state: &mut State, frames: u64) {
+    for _ in 0..frames {
+        let mut changes = vec![];
+        for (id, action) in state.to_do() {
+            let (next, action_changes) = action.tick(*id, state);
+            changes.push(StateChange::Action(*id, ActionChange::SetNextTick(next)));
+            changes.extend(action_changes);
+        }
+        apply(state, changes);
+        state.increment();
+    }
+}
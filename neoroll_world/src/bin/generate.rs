@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::PathBuf;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use neoroll_world::generator::WorldGenerator;
 use neoroll_world::{
     generator::perlin_noise_simple::PerlinNoiseSimpleGenerator, map::builder::MapBuilder,
@@ -19,6 +19,10 @@ pub struct Opt {
     #[structopt(short, long)]
     lakes: bool,
 
+    /// Generate world chunks beyond given size when they are reached
+    #[structopt(long)]
+    streamed: bool,
+
     #[structopt()]
     lines: i64,
 
@@ -57,7 +61,7 @@ fn main() -> Result<()> {
             .collect(),
     );
 
-    let world = PerlinNoiseSimpleGenerator::new(
+    let mut world = PerlinNoiseSimpleGenerator::new(
         seed,
         opt.lines,
         opt.columns,
@@ -67,13 +71,19 @@ fn main() -> Result<()> {
         opt.nm2_to,
         opt.nm2_factor,
     )
+    .streamed(opt.streamed)
     .generate();
     let map = MapBuilder::new(&world).build_lakes(opt.lakes).build();
 
     let world_output_display = opt.world_output.display().to_string();
     let map_output_display = opt.map_output.display().to_string();
-    fs::write(opt.world_output, bincode::serialize(&world)?)
-        .context(format!("Write world into file '{}'", &world_output_display))?;
+    world
+        .save(&opt.world_output)
+        .map_err(|error| anyhow!("{}", error))
+        .context(format!(
+            "Write world into directory '{}'",
+            &world_output_display
+        ))?;
     fs::write(opt.map_output, bincode::serialize(&map)?)
         .context(format!("Write map into file '{}'", &map_output_display))?;
 
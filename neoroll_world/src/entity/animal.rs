@@ -0,0 +1,301 @@
+use std::fmt::Display;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use uuid::Uuid;
+
+use crate::{
+    gameplay::{
+        material::{Hunted, Material, Resource},
+        Quantity,
+    },
+    space::{
+        area::WorldArea,
+        chunk::{ChunkPoint, CHUNK_SIZE},
+        world::World,
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
+};
+
+/// Random point tries before giving up when searching a place for a new herd
+const SPAWN_TRIES: usize = 100;
+
+/// Wild creatures, not owned by any tribe
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Animal {
+    id: AnimalId,
+    species: Species,
+    herd: HerdId,
+    point: AbsoluteWorldPoint,
+    behavior: AnimalBehavior,
+}
+
+impl Animal {
+    pub fn new(id: AnimalId, species: Species, herd: HerdId, point: AbsoluteWorldPoint) -> Self {
+        Self {
+            id,
+            species,
+            herd,
+            point,
+            behavior: Default::default(),
+        }
+    }
+
+    pub fn id(&self) -> &AnimalId {
+        &self.id
+    }
+
+    pub fn species(&self) -> &Species {
+        &self.species
+    }
+
+    pub fn herd(&self) -> &HerdId {
+        &self.herd
+    }
+
+    pub fn point(&self) -> &AbsoluteWorldPoint {
+        &self.point
+    }
+
+    pub fn set_point(&mut self, point: AbsoluteWorldPoint) {
+        self.point = point;
+    }
+
+    pub fn behavior(&self) -> &AnimalBehavior {
+        &self.behavior
+    }
+
+    pub fn set_behavior(&mut self, behavior: AnimalBehavior) {
+        self.behavior = behavior;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, EnumIter)]
+pub enum Species {
+    Deer,
+    Boar,
+}
+
+impl Species {
+    /// Count of animals in a new herd
+    pub fn herd_size(&self) -> usize {
+        match self {
+            Species::Deer => 4,
+            Species::Boar => 2,
+        }
+    }
+
+    /// Count of world tiles required for one animal of this species
+    pub fn density(&self) -> usize {
+        match self {
+            Species::Deer => 500,
+            Species::Boar => 1000,
+        }
+    }
+
+    /// Maximum population of this species in given world
+    pub fn capacity(&self, world: &World) -> usize {
+        world.lines() * world.columns() / self.density()
+    }
+
+    /// Population of this species in a new streamed chunk
+    pub fn chunk_capacity(&self) -> usize {
+        CHUNK_SIZE * CHUNK_SIZE / self.density()
+    }
+
+    /// Animals flee humans closer than this distance
+    pub fn flee_radius(&self) -> u32 {
+        match self {
+            Species::Deer => 4,
+            Species::Boar => 2,
+        }
+    }
+
+    /// Materials obtained when hunted
+    pub fn yields(&self) -> Vec<(Material, Quantity)> {
+        match self {
+            Species::Deer => vec![
+                (Material::Resource(Resource::Food), Quantity::grams(20_000)),
+                (Material::Hunted(Hunted::Hide), Quantity::units(1)),
+            ],
+            Species::Boar => vec![
+                (Material::Resource(Resource::Food), Quantity::grams(30_000)),
+                (Material::Hunted(Hunted::Hide), Quantity::units(1)),
+            ],
+        }
+    }
+}
+
+impl Display for Species {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Species::Deer => f.write_str("Deer"),
+            Species::Boar => f.write_str("Boar"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AnimalBehavior {
+    #[default]
+    Wander,
+    /// Move away from near humans
+    Flee,
+    /// Move back near other herd members
+    Herd,
+}
+
+impl Display for AnimalBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimalBehavior::Wander => f.write_str("Wander"),
+            AnimalBehavior::Flee => f.write_str("Flee"),
+            AnimalBehavior::Herd => f.write_str("Herd"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct AnimalId(Uuid);
+
+impl AnimalId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for AnimalId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for AnimalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct HerdId(Uuid);
+
+impl HerdId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for HerdId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// New herd of given species, all animals placed at given point
+pub fn new_herd(species: Species, point: AbsoluteWorldPoint) -> Vec<Animal> {
+    let herd = HerdId::new();
+    (0..species.herd_size())
+        .map(|_| Animal::new(AnimalId::new(), species, herd, point))
+        .collect()
+}
+
+/// Random walkable point of the world accepted by given filter
+pub fn spawn_point<F>(world: &World, filter: F) -> Option<AbsoluteWorldPoint>
+where
+    F: Fn(&AbsoluteWorldPoint) -> bool,
+{
+    let area = WorldArea::new(
+        AbsoluteWorldPoint(AbsoluteWorldRowI(0), AbsoluteWorldColI(0)),
+        world.lines(),
+        world.columns(),
+    );
+    spawn_point_in(world, &area, filter)
+}
+
+/// Random walkable point of given world area accepted by given filter
+pub fn spawn_point_in<F>(world: &World, area: &WorldArea, filter: F) -> Option<AbsoluteWorldPoint>
+where
+    F: Fn(&AbsoluteWorldPoint) -> bool,
+{
+    if area.lines() == 0 || area.columns() == 0 {
+        return None;
+    }
+
+    let mut rng = rand::thread_rng();
+    (0..SPAWN_TRIES)
+        .map(|_| {
+            AbsoluteWorldPoint(
+                AbsoluteWorldRowI(rng.gen_range(area.start_row().0..area.end_row().0)),
+                AbsoluteWorldColI(rng.gen_range(area.start_col().0..area.end_col().0)),
+            )
+        })
+        .find(|point| world.can_walk(point) && filter(point))
+}
+
+/// Herds of all species up to their capacity (used at world generation)
+pub fn populate(world: &World) -> Vec<Animal> {
+    let mut animals = vec![];
+
+    for species in Species::iter() {
+        let herds = species.capacity(world) / species.herd_size();
+        for _ in 0..herds {
+            if let Some(point) = spawn_point(world, |_| true) {
+                animals.extend(new_herd(species, point));
+            }
+        }
+    }
+
+    animals
+}
+
+/// Herds of all species up to their capacity in given (new streamed) chunk
+pub fn populate_chunk(world: &World, chunk: &ChunkPoint) -> Vec<Animal> {
+    let area = WorldArea::new(chunk.start(), CHUNK_SIZE, CHUNK_SIZE);
+    let mut animals = vec![];
+
+    for species in Species::iter() {
+        let herds = species.chunk_capacity() / species.herd_size();
+        for _ in 0..herds {
+            if let Some(point) = spawn_point_in(world, &area, |_| true) {
+                animals.extend(new_herd(species, point));
+            }
+        }
+    }
+
+    animals
+}
+
+#[derive(Debug)]
+pub enum AnimalChange {
+    New(Animal),
+    SetPoint(AbsoluteWorldPoint),
+    SetBehavior(AnimalBehavior),
+    Remove,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Species::Deer)]
+    #[case(Species::Boar)]
+    fn test_new_herd(#[case] species: Species) {
+        // Given
+        let point = AbsoluteWorldPoint(AbsoluteWorldRowI(3), AbsoluteWorldColI(4));
+
+        // When
+        let herd = new_herd(species, point);
+
+        // Then
+        assert_eq!(herd.len(), species.herd_size());
+        assert!(herd.iter().all(|animal| animal.herd() == herd[0].herd()));
+        assert!(herd.iter().all(|animal| animal.point() == &point));
+        assert!(herd
+            .iter()
+            .all(|animal| animal.behavior() == &AnimalBehavior::Wander));
+    }
+}
@@ -1,34 +1,60 @@
 use std::fmt::Display;
 
 use crate::{
-    gameplay::{behavior::Behavior, job::Job, material::Material, tribe::TribeId, Quantity},
+    gameplay::{
+        behavior::Behavior,
+        job::{requirement::JobRequirement, Job},
+        lifecycle::{Age, Hunger},
+        material::Material,
+        skill::{Skill, Skills},
+        tribe::TribeId,
+        unit::UnitMismatch,
+        Quantity, Volume, Weight,
+    },
     space::AbsoluteWorldPoint,
 };
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-const MAX_CARRYING_QUANTITY: Quantity = Quantity(50000);
+const MAX_CARRYING_WEIGHT: Weight = Weight(40_000);
+const MAX_CARRYING_VOLUME: Volume = Volume(1_000_000);
+/// Work speed multiplier when carrying the right tool
+const TOOL_SPEED: f32 = 1.5;
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Creature {
     id: CreatureId,
     tribe_id: TribeId,
+    name: String,
+    age: Age,
+    hunger: Hunger,
     point: AbsoluteWorldPoint,
     job: Job,
     behavior: Behavior,
     carrying: Vec<(Material, Quantity)>,
+    skills: Skills,
 }
 
 impl Creature {
-    pub fn new(id: CreatureId, tribe_id: TribeId, position: AbsoluteWorldPoint) -> Self {
+    pub fn new(
+        id: CreatureId,
+        tribe_id: TribeId,
+        name: String,
+        age: Age,
+        position: AbsoluteWorldPoint,
+    ) -> Self {
         Self {
             id,
             tribe_id,
+            name,
+            age,
+            hunger: Default::default(),
             point: position,
             job: Default::default(),
             behavior: Default::default(),
             carrying: vec![],
+            skills: Default::default(),
         }
     }
 
@@ -48,6 +74,26 @@ impl Creature {
         &self.tribe_id
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn age(&self) -> &Age {
+        &self.age
+    }
+
+    pub fn set_age(&mut self, age: Age) {
+        self.age = age;
+    }
+
+    pub fn hunger(&self) -> &Hunger {
+        &self.hunger
+    }
+
+    pub fn set_hunger(&mut self, hunger: Hunger) {
+        self.hunger = hunger;
+    }
+
     pub fn job(&self) -> &Job {
         &self.job
     }
@@ -64,7 +110,13 @@ impl Creature {
         self.behavior = behavior;
     }
 
-    pub fn add_to_carrying(&mut self, material: Material, quantity: Quantity) {
+    /// Given quantity must be counted in material unit
+    pub fn add_to_carrying(
+        &mut self,
+        material: Material,
+        quantity: Quantity,
+    ) -> Result<(), UnitMismatch> {
+        let quantity = material.checked_quantity(quantity)?;
         if let Some(quantity_) = self
             .carrying
             .iter_mut()
@@ -72,10 +124,12 @@ impl Creature {
             .map(|(_, q)| q)
             .next()
         {
-            *quantity_ += quantity;
+            *quantity_ = quantity_.clone().checked_add(quantity)?;
         } else {
             self.carrying.push((material, quantity))
         }
+
+        Ok(())
     }
 
     pub fn remove_from_carrying(&mut self, material: Material, quantity: Quantity) {
@@ -90,29 +144,99 @@ impl Creature {
         }
     }
 
-    pub fn cant_carry_more(&self) -> bool {
+    /// Materials used as tools by current job (required ones and collect tool)
+    fn tools(&self) -> Vec<(Material, Quantity)> {
+        let mut tools = self
+            .job
+            .requirements()
+            .into_iter()
+            .map(|requirement| match requirement {
+                JobRequirement::Material(material, quantity) => (material, quantity),
+            })
+            .collect::<Vec<(Material, Quantity)>>();
+        if let Job::SearchResource(resource) = &self.job {
+            if let Some(tool) = resource.tool() {
+                tools.push((tool, tool.quantity(1)));
+            }
+        }
+        tools
+    }
+
+    /// Carried materials, except tools of current job
+    fn load(&self) -> Vec<(Material, Quantity)> {
+        let tools = self.tools();
         self.carrying
             .iter()
-            .map(|(_, q)| q.clone())
-            .sum::<Quantity>()
-            .0
-            >= MAX_CARRYING_QUANTITY.0
+            .map(|(material, quantity)| {
+                let tool: u64 = tools
+                    .iter()
+                    .filter(|(m, _)| m == material)
+                    .map(|(_, q)| q.0)
+                    .sum();
+                (*material, quantity.with(quantity.0.saturating_sub(tool)))
+            })
+            .collect()
+    }
+
+    /// Weight of carried materials (tools are not counted)
+    pub fn carrying_weight(&self) -> Weight {
+        self.load().iter().map(|(m, q)| m.weight(q)).sum()
+    }
+
+    /// Volume of carried materials (tools are not counted)
+    pub fn carrying_volume(&self) -> Volume {
+        self.load().iter().map(|(m, q)| m.volume(q)).sum()
+    }
+
+    /// Quantity of given material which can still be carried
+    pub fn can_carry(&self, material: &Material) -> Quantity {
+        let weight = Weight(
+            MAX_CARRYING_WEIGHT
+                .0
+                .saturating_sub(self.carrying_weight().0),
+        );
+        let volume = Volume(
+            MAX_CARRYING_VOLUME
+                .0
+                .saturating_sub(self.carrying_volume().0),
+        );
+        material.fitting(&weight, &volume)
+    }
+
+    pub fn cant_carry_more(&self, material: &Material) -> bool {
+        self.can_carry(material).0 == 0
     }
 
     pub fn carrying(&self) -> &[(Material, Quantity)] {
         &self.carrying
     }
 
-    pub fn carrying_quantity(&self, filter: Option<Material>) -> Quantity {
-        match &filter {
-            Some(material) => self
-                .carrying()
+    pub fn skills(&self) -> &Skills {
+        &self.skills
+    }
+
+    pub fn practice(&mut self, skill: Skill, experience: u32) {
+        self.skills.practice(skill, experience)
+    }
+
+    /// Work speed multiplier according to skill and carried tool
+    pub fn work_speed(&self, skill: &Skill, tool: Option<Material>) -> f32 {
+        let tool_speed = match tool {
+            Some(tool) if self.carrying_quantity(tool).0 > 0 => TOOL_SPEED,
+            _ => 1.,
+        };
+        self.skills.speed(skill) * tool_speed
+    }
+
+    pub fn carrying_quantity(&self, material: Material) -> Quantity {
+        // Carried quantities are checked to be counted in their material unit
+        material.quantity(
+            self.carrying()
                 .iter()
-                .filter(|(m, _)| m == material)
-                .map(|(_, q)| q.clone())
+                .filter(|(m, _)| m == &material)
+                .map(|(_, q)| q.0)
                 .sum(),
-            None => self.carrying().iter().map(|(_, q)| q.clone()).sum(),
-        }
+        )
     }
 }
 
@@ -145,15 +269,24 @@ pub enum CreatureChange {
     SetBehavior(Behavior),
     AddToCarrying(Material, Quantity),
     RemoveFromCarrying(Material, Quantity),
+    Practice(Skill, u32),
+    SetAge(Age),
+    SetHunger(Hunger),
+    /// Creature died (or left the world)
+    Remove,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct PartialCreature {
     id: CreatureId,
     tribe_id: TribeId,
+    name: String,
+    age: Age,
+    hunger: Hunger,
     point: AbsoluteWorldPoint,
     job: Job,
     behavior: Behavior,
+    skills: Skills,
 }
 
 impl PartialCreature {
@@ -169,6 +302,26 @@ impl PartialCreature {
         self.point = point;
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn age(&self) -> &Age {
+        &self.age
+    }
+
+    pub fn set_age(&mut self, age: Age) {
+        self.age = age;
+    }
+
+    pub fn hunger(&self) -> &Hunger {
+        &self.hunger
+    }
+
+    pub fn set_hunger(&mut self, hunger: Hunger) {
+        self.hunger = hunger;
+    }
+
     pub fn job(&self) -> &Job {
         &self.job
     }
@@ -184,6 +337,14 @@ impl PartialCreature {
     pub fn set_behavior(&mut self, behavior: Behavior) {
         self.behavior = behavior;
     }
+
+    pub fn skills(&self) -> &Skills {
+        &self.skills
+    }
+
+    pub fn practice(&mut self, skill: Skill, experience: u32) {
+        self.skills.practice(skill, experience)
+    }
 }
 
 impl From<Creature> for PartialCreature {
@@ -191,9 +352,13 @@ impl From<Creature> for PartialCreature {
         Self {
             id: *value.id(),
             tribe_id: *value.tribe_id(),
+            name: value.name().to_string(),
+            age: *value.age(),
+            hunger: *value.hunger(),
             point: *value.point(),
             job: value.job().clone(),
             behavior: value.behavior().clone(),
+            skills: value.skills().clone(),
         }
     }
 }
@@ -203,4 +368,7 @@ pub enum PartialCreatureChange {
     SetPoint(AbsoluteWorldPoint),
     SetJob(Job),
     SetBehavior(Behavior),
+    Practice(Skill, u32),
+    SetAge(Age),
+    SetHunger(Hunger),
 }
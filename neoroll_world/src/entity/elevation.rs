@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// Moving between adjacent tiles with a greater elevation difference is impossible
+pub const MAX_WALKABLE_SLOPE: u8 = 12;
+/// Each this count of elevation difference adds one to the move cost
+const SLOPE_COST_STEP: u8 = 3;
+/// Generator noise values corresponding to lowest and highest elevation
+const NOISE_MIN: f64 = -2.0;
+const NOISE_MAX: f64 = 2.0;
+
+/// Tile height, from lowest (0) to highest (255)
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+pub struct Elevation(pub u8);
+
+impl Elevation {
+    /// Elevation from generator noise value
+    pub fn from_noise(value: f64) -> Self {
+        let ratio = ((value - NOISE_MIN) / (NOISE_MAX - NOISE_MIN)).clamp(0., 1.);
+        Self((ratio * u8::MAX as f64).round() as u8)
+    }
+
+    pub fn slope(&self, other: &Elevation) -> u8 {
+        self.0.abs_diff(other.0)
+    }
+
+    pub fn walkable_to(&self, other: &Elevation) -> bool {
+        self.slope(other) <= MAX_WALKABLE_SLOPE
+    }
+
+    /// Cost to move from this elevation to given one (1 on flat ground)
+    pub fn move_cost(&self, to: &Elevation) -> i32 {
+        1 + (self.slope(to) / SLOPE_COST_STEP) as i32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(-10.0, 0)]
+    #[case(-2.0, 0)]
+    #[case(0.0, 128)]
+    #[case(2.0, 255)]
+    #[case(10.0, 255)]
+    fn test_from_noise(#[case] value: f64, #[case] expected: u8) {
+        assert_eq!(Elevation::from_noise(value), Elevation(expected));
+    }
+
+    #[rstest]
+    #[case(100, 100, true, 1)]
+    #[case(100, 102, true, 1)]
+    #[case(100, 91, true, 4)]
+    #[case(100, 112, true, 5)]
+    #[case(100, 113, false, 5)]
+    fn test_move(#[case] from: u8, #[case] to: u8, #[case] walkable: bool, #[case] cost: i32) {
+        // Given
+        let (from, to) = (Elevation(from), Elevation(to));
+
+        // When/Then
+        assert_eq!(from.walkable_to(&to), walkable);
+        assert_eq!(from.move_cost(&to), cost);
+    }
+}
@@ -1,11 +1,16 @@
 use super::Filled;
 use serde::{Deserialize, Serialize};
 
+/// Growth won by a sown bush at each growth step
+const SOWN_GROWTH: u8 = 3;
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub enum Floor {
     Nothing,
     ShortGrass,
     FruitBush(Filled),
+    /// Fruit bush sown by a tribe, not grown yet
+    Sown(Filled), // Growth
 }
 
 // TODO: trait for collect/reduced/etc
@@ -15,6 +20,33 @@ impl Floor {
             Floor::Nothing => false,
             Floor::ShortGrass => true,
             Floor::FruitBush(_) => true,
+            Floor::Sown(_) => false,
+        }
+    }
+
+    pub fn growing(&self) -> bool {
+        matches!(self, Floor::Sown(_))
+    }
+
+    /// Return self after one growth step (or None if nothing grows). Growth is
+    /// multiplied by given factor (see `Season::regrowth_factor`)
+    pub fn grown(&self, factor: u8) -> Option<Self> {
+        let increment = SOWN_GROWTH.saturating_mul(factor);
+        if increment == 0 {
+            return None;
+        }
+
+        match self {
+            Floor::Sown(growth) => {
+                let growth = Filled::new(growth.0.saturating_add(increment));
+                if growth.is_full() {
+                    // Fruits will come with regrowth
+                    Some(Floor::FruitBush(Filled::empty()))
+                } else {
+                    Some(Floor::Sown(growth))
+                }
+            }
+            Floor::Nothing | Floor::ShortGrass | Floor::FruitBush(_) => None,
         }
     }
 
@@ -118,6 +150,7 @@ impl Floor {
             Floor::Nothing => "Nothing".to_string(),
             Floor::ShortGrass => "Short grass".to_string(),
             Floor::FruitBush(_) => "Fruit bush".to_string(),
+            Floor::Sown(_) => "Sown bush".to_string(),
         }
     }
 }
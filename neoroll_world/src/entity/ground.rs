@@ -4,7 +4,8 @@ use super::Filled;
 
 #[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
 pub enum Ground {
-    FreshWater,
+    /// Filled with fishes
+    FreshWater(Filled),
     Soil,
     SoilFlint(Filled),
 }
@@ -12,12 +13,16 @@ pub enum Ground {
 impl Ground {
     pub fn filled(&self) -> Option<&Filled> {
         match self {
-            Ground::FreshWater | Ground::Soil => None,
+            Ground::Soil => None,
 
-            Ground::SoilFlint(filled) => Some(filled),
+            Ground::FreshWater(filled) | Ground::SoilFlint(filled) => Some(filled),
         }
     }
 
+    pub fn is_water(&self) -> bool {
+        matches!(self, Ground::FreshWater(_))
+    }
+
     // pub fn collect_quantity(&self, type_: CollectType) -> Option<Quantity> {
     //     match type_ {
     //         CollectType::Food => match self {
@@ -104,7 +109,7 @@ impl Ground {
 
     pub fn detail_string(&self) -> String {
         match self {
-            Ground::FreshWater => "Fresh Water".to_string(),
+            Ground::FreshWater(_) => "Fresh Water".to_string(),
             Ground::Soil => "Soil".to_string(),
             Ground::SoilFlint(_) => "Soil with flint".to_string(),
         }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+pub mod animal;
 pub mod creature;
+pub mod elevation;
 pub mod floor;
 pub mod ground;
 pub mod structure;
@@ -24,6 +26,10 @@ impl Filled {
     pub fn is_empty(&self) -> bool {
         self.0 == 0
     }
+
+    pub fn is_full(&self) -> bool {
+        self.0 == 255
+    }
 }
 
 impl From<f32> for Filled {
@@ -1,18 +1,26 @@
 use std::fmt::Display;
 
-use crate::gameplay::build::Buildable;
+use crate::gameplay::{build::Buildable, Volume, Weight};
 
 use super::Filled;
 use serde::{Deserialize, Serialize};
 
+/// Quantity of `Filled` gained by a sapling at each growth step
+const SAPLING_GROWTH: u8 = 2;
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub enum Structure {
     // FIXME: Nothing ? Why Option in World ?
     Nothing,
     BigLeafTree(Filled),       // Branches
     FruitTree(Filled, Filled), // Branches, Fruits
+    /// Young tree, growing until it becomes a big leaf tree
+    Sapling(Filled), // Growth
+    /// Remains of a felled tree
+    Stump,
     Campfire,
     Storage,
+    Construction(Buildable),
 }
 
 impl Structure {
@@ -21,8 +29,11 @@ impl Structure {
             Structure::Nothing
             | Structure::BigLeafTree(_)
             | Structure::FruitTree(_, _)
+            | Structure::Sapling(_)
+            | Structure::Stump
             | Structure::Campfire
-            | Structure::Storage => false,
+            | Structure::Storage
+            | Structure::Construction(_) => false,
         }
     }
 
@@ -177,13 +188,88 @@ impl Structure {
     //     // }
     // }
 
+    /// Maximum weight and volume of materials which can be stored in this structure
+    pub fn storage_capacity(&self) -> Option<(Weight, Volume)> {
+        match self {
+            Structure::Storage => Some((Weight(500_000), Volume(10_000_000))),
+            Structure::Nothing
+            | Structure::BigLeafTree(_)
+            | Structure::FruitTree(_, _)
+            | Structure::Sapling(_)
+            | Structure::Stump
+            | Structure::Campfire
+            | Structure::Construction(_) => None,
+        }
+    }
+
+    /// Buildable which produce this structure (if any)
+    pub fn buildable(&self) -> Option<Buildable> {
+        match self {
+            Structure::Nothing
+            | Structure::BigLeafTree(_)
+            | Structure::FruitTree(_, _)
+            | Structure::Sapling(_)
+            | Structure::Stump
+            | Structure::Construction(_) => None,
+            Structure::Campfire => Some(Buildable::Campfire),
+            Structure::Storage => Some(Buildable::Storage),
+        }
+    }
+
+    /// Trees (saplings included) which make a forest
+    pub fn is_tree(&self) -> bool {
+        match self {
+            Structure::BigLeafTree(_) | Structure::FruitTree(_, _) | Structure::Sapling(_) => true,
+            Structure::Nothing
+            | Structure::Stump
+            | Structure::Campfire
+            | Structure::Storage
+            | Structure::Construction(_) => false,
+        }
+    }
+
+    /// True if this structure grows into another one
+    pub fn growing(&self) -> bool {
+        matches!(self, Structure::Sapling(_))
+    }
+
+    /// Return self after one growth step (or None if nothing grows). Growth is
+    /// multiplied by given factor (see `Season::regrowth_factor`)
+    pub fn grown(&self, factor: u8) -> Option<Self> {
+        let increment = SAPLING_GROWTH.saturating_mul(factor);
+        if increment == 0 {
+            return None;
+        }
+
+        match self {
+            Structure::Sapling(growth) => {
+                let growth = Filled::new(growth.0.saturating_add(increment));
+                if growth.is_full() {
+                    Some(Structure::BigLeafTree(Filled::full()))
+                } else {
+                    Some(Structure::Sapling(growth))
+                }
+            }
+            Structure::Nothing
+            | Structure::BigLeafTree(_)
+            | Structure::FruitTree(_, _)
+            | Structure::Stump
+            | Structure::Campfire
+            | Structure::Storage
+            | Structure::Construction(_) => None,
+        }
+    }
+
     pub fn detail_string(&self) -> String {
         match self {
             Structure::Nothing => "Nothing".to_string(),
             Structure::BigLeafTree(_) => "Big leaf tree".to_string(),
             Structure::FruitTree(_, _) => "Fruit tree".to_string(),
+            Structure::Sapling(_) => "Sapling".to_string(),
+            Structure::Stump => "Stump".to_string(),
             Structure::Campfire => "Campfire".to_string(),
             Structure::Storage => "Storage".to_string(),
+            Structure::Construction(buildable) => format!("{} (construction)", buildable),
         }
     }
 }
@@ -203,8 +289,11 @@ impl Display for Structure {
             Structure::Nothing => f.write_str("Nothing"),
             Structure::BigLeafTree(_) => f.write_str("BigLeafTree"),
             Structure::FruitTree(_, _) => f.write_str("FruitTree"),
+            Structure::Sapling(_) => f.write_str("Sapling"),
+            Structure::Stump => f.write_str("Stump"),
             Structure::Campfire => f.write_str("Campfire"),
             Structure::Storage => f.write_str("Storage"),
+            Structure::Construction(_) => f.write_str("Construction"),
         }
     }
 }
@@ -9,7 +9,11 @@ pub enum Behavior {
     Idle,
     Collect(Progress),
     DropOff,
+    PickUp,
     MoveTo,
+    Build(Progress),
+    Craft(Progress),
+    Hunt(Progress),
 }
 impl Behavior {
     pub fn progress(&self) -> Option<&Progress> {
@@ -17,7 +21,11 @@ impl Behavior {
             Behavior::Idle => None,
             Behavior::Collect(progress) => Some(progress),
             Behavior::DropOff => None,
+            Behavior::PickUp => None,
             Behavior::MoveTo => None,
+            Behavior::Build(progress) => Some(progress),
+            Behavior::Craft(progress) => Some(progress),
+            Behavior::Hunt(progress) => Some(progress),
         }
     }
 }
@@ -36,7 +44,11 @@ impl Display for Behavior {
                 f.write_str(&format!("Collect ({}%)", progress.percent()))
             }
             Behavior::DropOff => f.write_str("Drop off"),
+            Behavior::PickUp => f.write_str("Pick up"),
             Behavior::MoveTo => f.write_str("Move"),
+            Behavior::Build(progress) => f.write_str(&format!("Build ({}%)", progress.percent())),
+            Behavior::Craft(progress) => f.write_str(&format!("Craft ({}%)", progress.percent())),
+            Behavior::Hunt(progress) => f.write_str(&format!("Hunt ({}%)", progress.percent())),
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::{
+    gameplay::{progress::Progress, tribe::TribeId},
+    space::AbsoluteWorldPoint,
+};
+
+use super::Buildable;
+
+/// A structure being built by tribe. Brought materials are stored as world
+/// materials on the construction point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Construction {
+    buildable: Buildable,
+    tribe_id: TribeId,
+    point: AbsoluteWorldPoint,
+    work_done: u64,
+}
+
+impl Construction {
+    pub fn new(buildable: Buildable, tribe_id: TribeId, point: AbsoluteWorldPoint) -> Self {
+        Self {
+            buildable,
+            tribe_id,
+            point,
+            work_done: 0,
+        }
+    }
+
+    pub fn buildable(&self) -> &Buildable {
+        &self.buildable
+    }
+
+    pub fn tribe_id(&self) -> &TribeId {
+        &self.tribe_id
+    }
+
+    pub fn point(&self) -> &AbsoluteWorldPoint {
+        &self.point
+    }
+
+    pub fn work_done(&self) -> u64 {
+        self.work_done
+    }
+
+    pub fn work(&mut self, value: u64) {
+        self.work_done = (self.work_done + value).min(self.buildable.work());
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.work_done >= self.buildable.work()
+    }
+
+    pub fn progress(&self) -> Progress {
+        Progress::from(self.work_done as f32 / self.buildable.work() as f32)
+    }
+}
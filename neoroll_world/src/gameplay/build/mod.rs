@@ -1,14 +1,70 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
 use crate::{
     entity::{floor::Floor, ground::Ground, structure::Structure},
     space::{world::World, AbsoluteWorldPoint},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use super::{
+    material::{Material, Resource},
+    Quantity,
+};
+
+pub mod construction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, EnumIter)]
 pub enum Buildable {
     Campfire,
     Storage,
 }
 
+impl Buildable {
+    /// Materials which must be brought on construction site
+    pub fn materials(&self) -> Vec<(Material, Quantity)> {
+        match self {
+            Buildable::Campfire => vec![
+                (
+                    Material::Resource(Resource::Branches),
+                    Quantity::cubic_meters(2),
+                ),
+                (Material::Resource(Resource::RawFlint), Quantity::units(5)),
+            ],
+            Buildable::Storage => {
+                vec![(Material::Resource(Resource::RawFlint), Quantity::units(10))]
+            }
+        }
+    }
+
+    /// Materials given back when structure is demolished (half of required ones)
+    pub fn refund(&self) -> Vec<(Material, Quantity)> {
+        self.materials()
+            .into_iter()
+            .map(|(material, quantity)| (material, quantity.with(quantity.0 / 2)))
+            .filter(|(_, quantity)| quantity.0 > 0)
+            .collect()
+    }
+
+    /// Work steps required to finish construction once materials are brought
+    pub fn work(&self) -> u64 {
+        match self {
+            Buildable::Campfire => 3,
+            Buildable::Storage => 5,
+        }
+    }
+}
+
+impl Display for Buildable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Buildable::Campfire => f.write_str("Campfire"),
+            Buildable::Storage => f.write_str("Storage"),
+        }
+    }
+}
+
 pub struct TryBuild<'a> {
     world: &'a World,
 }
@@ -29,7 +85,7 @@ impl<'a> TryBuild<'a> {
 
         if let Some(ground) = self.world.ground(point) {
             match ground {
-                Ground::FreshWater => {
+                Ground::FreshWater(_) => {
                     return Err(TryBuildError::IncompatibleGround(ground.clone()))
                 }
                 Ground::Soil | Ground::SoilFlint(_) => {}
@@ -38,7 +94,7 @@ impl<'a> TryBuild<'a> {
 
         if let Some(floor) = self.world.floor(point) {
             match floor {
-                Floor::Nothing | Floor::ShortGrass | Floor::FruitBush(_) => {}
+                Floor::Nothing | Floor::ShortGrass | Floor::FruitBush(_) | Floor::Sown(_) => {}
             }
         }
 
@@ -46,12 +102,15 @@ impl<'a> TryBuild<'a> {
             match structure {
                 Structure::BigLeafTree(_)
                 | Structure::FruitTree(_, _)
+                | Structure::Sapling(_)
                 | Structure::Campfire
-                | Structure::Storage => {
+                | Structure::Storage
+                | Structure::Construction(_) => {
                     return Err(TryBuildError::StructureAlreadyExist(structure.clone()))
                 }
 
-                Structure::Nothing => {}
+                // Stump is cleared by construction
+                Structure::Nothing | Structure::Stump => {}
             }
         };
 
@@ -64,4 +123,6 @@ pub enum TryBuildError {
     OutsideWorld,
     IncompatibleGround(Ground),
     StructureAlreadyExist(Structure),
+    /// Tribe didn't discover the technology giving this buildable
+    NotDiscovered,
 }
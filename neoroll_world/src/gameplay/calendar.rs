@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// Frames count for one in game hour (10 seconds at base server speed)
+pub const FRAMES_PER_HOUR: u64 = 500;
+pub const MINUTES_PER_HOUR: u64 = 60;
+pub const HOURS_PER_DAY: u64 = 24;
+pub const DAYS_PER_SEASON: u64 = 10;
+pub const SEASONS_PER_YEAR: u64 = 4;
+/// Game start in the morning
+const START_HOUR: u64 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, EnumIter)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    fn from_index(index: u64) -> Self {
+        match index % SEASONS_PER_YEAR {
+            0 => Self::Spring,
+            1 => Self::Summer,
+            2 => Self::Autumn,
+            _ => Self::Winter,
+        }
+    }
+
+    pub fn sunrise(&self) -> u64 {
+        match self {
+            Season::Spring => 6,
+            Season::Summer => 5,
+            Season::Autumn => 7,
+            Season::Winter => 8,
+        }
+    }
+
+    pub fn sunset(&self) -> u64 {
+        match self {
+            Season::Spring => 20,
+            Season::Summer => 21,
+            Season::Autumn => 19,
+            Season::Winter => 17,
+        }
+    }
+
+    /// True if bushes and trees bear fruits during this season
+    pub fn fruits(&self) -> bool {
+        !matches!(self, Season::Winter)
+    }
+
+    /// Multiplier applied to collectables regrowth rate
+    pub fn regrowth_factor(&self) -> u8 {
+        match self {
+            Season::Spring => 2,
+            Season::Summer => 1,
+            Season::Autumn => 1,
+            Season::Winter => 0,
+        }
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Season::Spring => f.write_str("Spring"),
+            Season::Summer => f.write_str("Summer"),
+            Season::Autumn => f.write_str("Autumn"),
+            Season::Winter => f.write_str("Winter"),
+        }
+    }
+}
+
+/// In game date, derived from server frame index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Calendar {
+    year: u64,
+    season: Season,
+    day: u64,
+    hour: u64,
+    minute: u64,
+}
+
+impl Calendar {
+    pub fn from_frame(frame_i: u64) -> Self {
+        let minutes = frame_i * MINUTES_PER_HOUR / FRAMES_PER_HOUR + START_HOUR * MINUTES_PER_HOUR;
+        let hours = minutes / MINUTES_PER_HOUR;
+        let days = hours / HOURS_PER_DAY;
+        let seasons = days / DAYS_PER_SEASON;
+
+        Self {
+            year: seasons / SEASONS_PER_YEAR + 1,
+            season: Season::from_index(seasons),
+            day: days % DAYS_PER_SEASON + 1,
+            hour: hours % HOURS_PER_DAY,
+            minute: minutes % MINUTES_PER_HOUR,
+        }
+    }
+
+    pub fn year(&self) -> u64 {
+        self.year
+    }
+
+    pub fn season(&self) -> Season {
+        self.season
+    }
+
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u64 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u64 {
+        self.minute
+    }
+
+    /// Creatures only work between sunrise and sunset
+    pub fn is_work_hour(&self) -> bool {
+        self.hour >= self.season.sunrise() && self.hour < self.season.sunset()
+    }
+
+    pub fn is_night(&self) -> bool {
+        !self.is_work_hour()
+    }
+
+    /// Light level, from 0.0 (night) to 1.0 (day), with one hour of
+    /// transition at sunrise and sunset
+    pub fn daylight(&self) -> f32 {
+        let now = self.hour as f32 + self.minute as f32 / MINUTES_PER_HOUR as f32;
+        let sunrise = self.season.sunrise() as f32;
+        let sunset = self.season.sunset() as f32;
+
+        if now < sunrise - 1. || now >= sunset + 1. {
+            0.
+        } else if now < sunrise {
+            now - (sunrise - 1.)
+        } else if now >= sunset {
+            1. - (now - sunset)
+        } else {
+            1.
+        }
+    }
+}
+
+impl Display for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "Year {}, {} day {}, {:0>2}:{:0>2}",
+            self.year, self.season, self.day, self.hour, self.minute
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    const DAY: u64 = FRAMES_PER_HOUR * HOURS_PER_DAY;
+    const SEASON: u64 = DAY * DAYS_PER_SEASON;
+
+    #[rstest]
+    #[case(0, 1, Season::Spring, 1, 8, 0)]
+    #[case(FRAMES_PER_HOUR / 2, 1, Season::Spring, 1, 8, 30)]
+    #[case(FRAMES_PER_HOUR * 16, 1, Season::Spring, 2, 0, 0)]
+    #[case(SEASON, 1, Season::Summer, 1, 8, 0)]
+    #[case(SEASON * 3, 1, Season::Winter, 1, 8, 0)]
+    #[case(SEASON * 4, 2, Season::Spring, 1, 8, 0)]
+    fn test_calendar_from_frame(
+        #[case] frame_i: u64,
+        #[case] year: u64,
+        #[case] season: Season,
+        #[case] day: u64,
+        #[case] hour: u64,
+        #[case] minute: u64,
+    ) {
+        let calendar = Calendar::from_frame(frame_i);
+
+        assert_eq!(calendar.year(), year);
+        assert_eq!(calendar.season(), season);
+        assert_eq!(calendar.day(), day);
+        assert_eq!(calendar.hour(), hour);
+        assert_eq!(calendar.minute(), minute);
+    }
+
+    #[rstest]
+    // Spring 08:00
+    #[case(0, 1., true)]
+    // Spring 20:30
+    #[case(FRAMES_PER_HOUR * 12 + FRAMES_PER_HOUR / 2, 0.5, false)]
+    // Spring 23:00
+    #[case(FRAMES_PER_HOUR * 15, 0., false)]
+    // Winter 08:00
+    #[case(SEASON * 3, 1., true)]
+    // Winter 17:00
+    #[case(SEASON * 3 + FRAMES_PER_HOUR * 9, 1., false)]
+    fn test_calendar_daylight(
+        #[case] frame_i: u64,
+        #[case] daylight: f32,
+        #[case] work_hour: bool,
+    ) {
+        let calendar = Calendar::from_frame(frame_i);
+
+        assert_eq!(calendar.daylight(), daylight);
+        assert_eq!(calendar.is_work_hour(), work_hour);
+    }
+}
@@ -1,15 +1,379 @@
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
 use crate::entity::{floor::Floor, ground::Ground, structure::Structure, Filled};
 
 use super::{
-    material::{Material, Resource},
+    material::{Crafted, Hunted, Material, Resource},
+    target::TargetQuantity,
     CollectType, Quantity,
 };
 
+/// File read (from working directory) at server start. Built-in defaults are
+/// used when it doesn't exist
+pub const GAMEPLAY_CONFIG_FILE: &str = "gameplay.ron";
+
+static GAMEPLAY: OnceLock<GameplayConfig> = OnceLock::new();
+
+/// Gameplay config in use (built-in defaults if none has been set)
+pub fn gameplay() -> &'static GameplayConfig {
+    GAMEPLAY.get_or_init(GameplayConfig::default)
+}
+
+/// Set gameplay config in use. Must be called before any gameplay config read
+pub fn set_gameplay(config: GameplayConfig) -> Result<(), GameplayAlreadySet> {
+    GAMEPLAY.set(config).map_err(|_| GameplayAlreadySet)
+}
+
+/// Gameplay config has already been set (or read)
+#[derive(Debug)]
+pub struct GameplayAlreadySet;
+
+impl Display for GameplayAlreadySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Gameplay config already set")
+    }
+}
+
+/// Gameplay values which can be rebalanced without recompiling. All quantities
+/// are counted in their material unit (see `Material::unit`) and all periods
+/// in frames
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GameplayConfig {
+    pub collects: CollectsConfig,
+    pub targets: TargetsConfig,
+    /// Quantity carried by a worker before bringing it back to storage
+    pub carrying: HashMap<Material, u64>,
+    pub ticks: TicksConfig,
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        let carrying = all_materials()
+            .into_iter()
+            .map(|material| {
+                let quantity = match material {
+                    Material::Resource(Resource::Food) => 4000,
+                    Material::Resource(Resource::RawFlint) => 30,
+                    Material::Resource(Resource::Branches) => 1,
+                    Material::Resource(Resource::Fish) => 4000,
+                    Material::Resource(Resource::Water) => 10_000,
+                    Material::Resource(Resource::Logs) => 2,
+                    Material::Crafted(Crafted::CarvedFlint) => 5,
+                    Material::Hunted(Hunted::Hide) => 2,
+                };
+                (material, quantity)
+            })
+            .collect();
+
+        Self {
+            collects: CollectsConfig::default(),
+            targets: TargetsConfig::default(),
+            carrying,
+            ticks: TicksConfig::default(),
+        }
+    }
+}
+
+impl GameplayConfig {
+    /// Read config from RON content. Omitted values are taken from built-in defaults
+    pub fn from_ron(content: &str) -> Result<Self, GameplayConfigError> {
+        let config = ron::from_str::<Self>(content)
+            .map_err(|error| GameplayConfigError::Parse(error.to_string()))?
+            .completed();
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Fill materials missing in maps with built-in defaults
+    fn completed(mut self) -> Self {
+        let default = Self::default();
+        for (material, quantity) in default.carrying {
+            self.carrying.entry(material).or_insert(quantity);
+        }
+        for (material, quantity) in default.targets.keep_stock {
+            self.targets.keep_stock.entry(material).or_insert(quantity);
+        }
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), GameplayConfigError> {
+        for (name, collect) in self.collects.all() {
+            if collect.maximum == 0 || collect.iteration == 0 {
+                return Err(GameplayConfigError::Zero(format!("collects.{}", name)));
+            }
+            if collect.iteration > collect.maximum {
+                return Err(GameplayConfigError::IterationExceedsMaximum(
+                    name.to_string(),
+                ));
+            }
+        }
+
+        for (material, quantity) in &self.carrying {
+            if *quantity == 0 {
+                return Err(GameplayConfigError::Zero(format!(
+                    "carrying.{:?}",
+                    material
+                )));
+            }
+        }
+
+        for (name, period) in self.ticks.all() {
+            if period == 0 {
+                return Err(GameplayConfigError::Zero(format!("ticks.{}", name)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Quantity carried by a worker before bringing it back to storage
+    pub fn carrying(&self, material: &Material) -> Quantity {
+        material.quantity(self.carrying.get(material).copied().unwrap_or(1))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameplayConfigError {
+    Parse(String),
+    /// Value (named by its path in config) can't be zero
+    Zero(String),
+    /// Collect (named by its field in config) iteration is greater than its maximum
+    IterationExceedsMaximum(String),
+}
+
+impl Display for GameplayConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameplayConfigError::Parse(error) => f.write_str(&format!("Parse error: {}", error)),
+            GameplayConfigError::Zero(name) => f.write_str(&format!("{} can't be zero", name)),
+            GameplayConfigError::IterationExceedsMaximum(name) => f.write_str(&format!(
+                "collects.{} iteration is greater than maximum",
+                name
+            )),
+        }
+    }
+}
+
+fn all_materials() -> Vec<Material> {
+    Resource::iter()
+        .map(Material::Resource)
+        .chain(Crafted::iter().map(Material::Crafted))
+        .chain(Hunted::iter().map(Material::Hunted))
+        .collect()
+}
+
+/// Collectable quantities of a world tile
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CollectConfig {
+    /// Quantity when tile is full
+    pub maximum: u64,
+    /// Quantity collected by one collect
+    pub iteration: u64,
+    /// Filled (over 255) recovered at each regrowth step, None if it never regrows
+    pub regrowth: Option<u8>,
+}
+
+impl CollectConfig {
+    fn new(maximum: u64, iteration: u64, regrowth: Option<u8>) -> Self {
+        Self {
+            maximum,
+            iteration,
+            regrowth,
+        }
+    }
+
+    fn collect<'a>(&self, material: Material, filled: &'a Filled) -> Collect<'a> {
+        Collect::new(
+            material,
+            material.quantity(self.maximum),
+            material.quantity(self.iteration),
+            filled,
+            self.regrowth.map(RegrowthRate),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CollectsConfig {
+    pub fresh_water_fish: CollectConfig,
+    pub fresh_water_water: CollectConfig,
+    pub soil_flint_raw_flint: CollectConfig,
+    pub fruit_bush_food: CollectConfig,
+    pub fruit_tree_food: CollectConfig,
+    pub fruit_tree_branches: CollectConfig,
+    pub big_leaf_tree_branches: CollectConfig,
+    pub big_leaf_tree_logs: CollectConfig,
+}
+
+impl Default for CollectsConfig {
+    fn default() -> Self {
+        Self {
+            fresh_water_fish: CollectConfig::new(20_000, 500, Some(1)),
+            // Fresh water is never exhausted
+            fresh_water_water: CollectConfig::new(10_000, 2_000, None),
+            // Flint deposits never regrow
+            soil_flint_raw_flint: CollectConfig::new(1000, 5, None),
+            fruit_bush_food: CollectConfig::new(2000, 500, Some(2)),
+            fruit_tree_food: CollectConfig::new(25000, 1000, Some(1)),
+            fruit_tree_branches: CollectConfig::new(1, 1, Some(1)),
+            big_leaf_tree_branches: CollectConfig::new(3, 1, Some(2)),
+            // A felled tree becomes a stump
+            big_leaf_tree_logs: CollectConfig::new(2, 2, None),
+        }
+    }
+}
+
+impl CollectsConfig {
+    fn all(&self) -> Vec<(&str, &CollectConfig)> {
+        vec![
+            ("fresh_water_fish", &self.fresh_water_fish),
+            ("fresh_water_water", &self.fresh_water_water),
+            ("soil_flint_raw_flint", &self.soil_flint_raw_flint),
+            ("fruit_bush_food", &self.fruit_bush_food),
+            ("fruit_tree_food", &self.fruit_tree_food),
+            ("fruit_tree_branches", &self.fruit_tree_branches),
+            ("big_leaf_tree_branches", &self.big_leaf_tree_branches),
+            ("big_leaf_tree_logs", &self.big_leaf_tree_logs),
+        ]
+    }
+}
+
+/// Target quantity, counted in material unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TargetQuantityConfig {
+    Fixed(u64),
+    PerHuman(u64),
+}
+
+impl TargetQuantityConfig {
+    pub fn target_quantity(&self, material: &Material) -> TargetQuantity {
+        match self {
+            TargetQuantityConfig::Fixed(value) => TargetQuantity::Fixed(material.quantity(*value)),
+            TargetQuantityConfig::PerHuman(value) => {
+                TargetQuantity::PerHuman(material.quantity(*value))
+            }
+        }
+    }
+}
+
+/// Values of new targets
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TargetsConfig {
+    pub keep_stock: HashMap<Material, TargetQuantityConfig>,
+    pub keep_built: usize,
+    pub explore: u32,
+    pub produce: u64,
+    pub plant: usize,
+}
+
+impl Default for TargetsConfig {
+    fn default() -> Self {
+        let keep_stock = all_materials()
+            .into_iter()
+            .map(|material| {
+                let quantity = match material {
+                    Material::Resource(Resource::Food) => TargetQuantityConfig::PerHuman(2000),
+                    Material::Resource(Resource::RawFlint) => TargetQuantityConfig::Fixed(100),
+                    Material::Resource(Resource::Branches) => TargetQuantityConfig::Fixed(5),
+                    Material::Resource(Resource::Fish) => TargetQuantityConfig::PerHuman(1000),
+                    Material::Resource(Resource::Water) => TargetQuantityConfig::PerHuman(3000),
+                    Material::Resource(Resource::Logs) => TargetQuantityConfig::Fixed(10),
+                    Material::Crafted(_) => TargetQuantityConfig::Fixed(10),
+                    Material::Hunted(_) => TargetQuantityConfig::Fixed(5),
+                };
+                (material, quantity)
+            })
+            .collect();
+
+        Self {
+            keep_stock,
+            keep_built: 1,
+            explore: 15,
+            produce: 10,
+            plant: 20,
+        }
+    }
+}
+
+/// Periods (in frames) of world simulation actions
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TicksConfig {
+    pub affect_job: u64,
+    pub compute_targets: u64,
+    pub regrowth: u64,
+    pub wildlife: u64,
+    pub wildlife_respawn: u64,
+    pub migrant: u64,
+    /// One step of walking creatures (moving, bringing to or taking from storage)
+    pub walk: u64,
+    pub collect: u64,
+    pub construct: u64,
+    pub craft: u64,
+    pub hunt: u64,
+    /// Creatures decide what to do next for their job
+    pub realize_job: u64,
+}
+
+impl Default for TicksConfig {
+    fn default() -> Self {
+        Self {
+            affect_job: 250,
+            compute_targets: 100,
+            regrowth: 1000,
+            wildlife: 100,
+            wildlife_respawn: 10_000,
+            migrant: 250,
+            walk: 50,
+            collect: 25,
+            construct: 25,
+            craft: 25,
+            hunt: 25,
+            realize_job: 50,
+        }
+    }
+}
+
+impl TicksConfig {
+    fn all(&self) -> Vec<(&str, u64)> {
+        vec![
+            ("affect_job", self.affect_job),
+            ("compute_targets", self.compute_targets),
+            ("regrowth", self.regrowth),
+            ("wildlife", self.wildlife),
+            ("wildlife_respawn", self.wildlife_respawn),
+            ("migrant", self.migrant),
+            ("walk", self.walk),
+            ("collect", self.collect),
+            ("construct", self.construct),
+            ("craft", self.craft),
+            ("hunt", self.hunt),
+            ("realize_job", self.realize_job),
+        ]
+    }
+}
+
+/// Fresh water is never exhausted
+static WATER_FILLED: Filled = Filled(255);
+/// A standing tree can always be felled (it becomes a stump)
+static LOGS_FILLED: Filled = Filled(255);
+
+/// Quantity of `Filled` recovered at each regrowth step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegrowthRate(pub u8);
+
 pub struct Collect<'a> {
     material: Material,
     maximum: Quantity,
     iteration: Quantity,
     filled: &'a Filled,
+    regrowth: Option<RegrowthRate>,
 }
 
 impl<'a> Collect<'a> {
@@ -18,12 +382,16 @@ impl<'a> Collect<'a> {
         maximum: Quantity,
         iteration: Quantity,
         filled: &'a Filled,
+        regrowth: Option<RegrowthRate>,
     ) -> Self {
+        debug_assert_eq!(maximum.unit(), material.unit());
+        debug_assert_eq!(iteration.unit(), material.unit());
         Self {
             material,
             maximum,
             iteration,
             filled,
+            regrowth,
         }
     }
 
@@ -42,6 +410,10 @@ impl<'a> Collect<'a> {
     pub fn filled(&self) -> &Filled {
         self.filled
     }
+
+    pub fn regrowth(&self) -> Option<RegrowthRate> {
+        self.regrowth
+    }
 }
 
 pub trait IntoCollect: Clone {
@@ -52,36 +424,80 @@ pub trait IntoCollect: Clone {
         self.collect(type_).map(|c| c.material())
     }
 
-    fn reduced(&self, type_: CollectType) -> (Self, Quantity) {
+    /// Collect one iteration multiplied by given efficiency (see
+    /// `Creature::work_speed`), without exceeding given limit
+    fn reduced(&self, type_: CollectType, limit: &Quantity, efficiency: f32) -> (Self, Quantity) {
         if let Some(collect) = self.collect(type_) {
             let maximum = collect.maximum();
             let iteration = collect.iteration();
             let filled = collect.filled();
             let current: u64 = (maximum.0 as f32 * (filled.0 as f32 / 255.)) as u64;
-            let collectable = iteration.0.min(current);
+            let iteration = (iteration.0 as f32 * efficiency) as u64;
+            let collectable = iteration.min(current).min(limit.0);
             let new_quantity_ = current - collectable;
             let new_filled_raw = ((new_quantity_ as f32 / maximum.0 as f32) * 255.) as u8;
             let new_filled = Filled::new(new_filled_raw);
 
-            (self.with_filled(type_, new_filled), Quantity(collectable))
+            (
+                self.with_filled(type_, new_filled),
+                collect.material().quantity(collectable),
+            )
         } else {
-            (self.clone(), Quantity(0))
+            (self.clone(), Quantity::default())
+        }
+    }
+
+    /// True if given collect type is not full and will regrow
+    fn regrowing(&self, type_: CollectType) -> bool {
+        self.collect(type_)
+            .map(|c| c.regrowth().is_some() && !c.filled().is_full())
+            .unwrap_or(false)
+    }
+
+    /// Return self after one regrowth step (or None if nothing regrow). Regrowth
+    /// rate is multiplied by given factor (see `Season::regrowth_factor`)
+    fn regrown(&self, type_: CollectType, factor: u8) -> Option<Self> {
+        if let Some(collect) = self.collect(type_) {
+            if let Some(rate) = collect.regrowth() {
+                let filled = collect.filled();
+                let increment = rate.0.saturating_mul(factor);
+                if !filled.is_full() && increment > 0 {
+                    let new_filled = Filled::new(filled.0.saturating_add(increment));
+                    return Some(self.with_filled(type_, new_filled));
+                }
+            }
         }
+
+        None
     }
 }
 
 impl IntoCollect for Ground {
     fn collect(&self, type_: CollectType) -> Option<Collect> {
         match self {
-            Ground::FreshWater => None,
+            Ground::FreshWater(fish_filled) => match type_ {
+                CollectType::Fish => Some(
+                    gameplay()
+                        .collects
+                        .fresh_water_fish
+                        .collect(Material::Resource(Resource::Fish), fish_filled),
+                ),
+                CollectType::Water => Some(
+                    gameplay()
+                        .collects
+                        .fresh_water_water
+                        .collect(Material::Resource(Resource::Water), &WATER_FILLED),
+                ),
+                _ => None,
+            },
             Ground::Soil => None,
             Ground::SoilFlint(raw_flint_filled) => match type_ {
-                CollectType::RawFlint => Some(Collect::new(
-                    Material::Resource(Resource::RawFlint),
-                    Quantity(1000),
-                    Quantity(5),
-                    raw_flint_filled,
-                )),
+                CollectType::RawFlint => Some(
+                    gameplay()
+                        .collects
+                        .soil_flint_raw_flint
+                        .collect(Material::Resource(Resource::RawFlint), raw_flint_filled),
+                ),
                 _ => None,
             },
         }
@@ -91,10 +507,16 @@ impl IntoCollect for Ground {
         match type_ {
             CollectType::Food => self.clone(),
             CollectType::RawFlint => match self {
-                Ground::FreshWater | Ground::Soil => self.clone(),
+                Ground::FreshWater(_) | Ground::Soil => self.clone(),
                 Ground::SoilFlint(_) => Ground::SoilFlint(new_filled),
             },
             CollectType::Branches => self.clone(),
+            CollectType::Fish => match self {
+                Ground::FreshWater(_) => Ground::FreshWater(new_filled),
+                Ground::Soil | Ground::SoilFlint(_) => self.clone(),
+            },
+            CollectType::Water => self.clone(),
+            CollectType::Logs => self.clone(),
         }
     }
 }
@@ -105,14 +527,15 @@ impl IntoCollect for Floor {
             Floor::Nothing => None,
             Floor::ShortGrass => None,
             Floor::FruitBush(food_filled) => match type_ {
-                CollectType::Food => Some(Collect::new(
-                    Material::Resource(Resource::Food),
-                    Quantity(2000),
-                    Quantity(500),
-                    food_filled,
-                )),
+                CollectType::Food => Some(
+                    gameplay()
+                        .collects
+                        .fruit_bush_food
+                        .collect(Material::Resource(Resource::Food), food_filled),
+                ),
                 _ => None,
             },
+            Floor::Sown(_) => None,
         }
     }
 
@@ -122,9 +545,11 @@ impl IntoCollect for Floor {
                 Floor::Nothing => self.clone(),
                 Floor::ShortGrass => self.clone(),
                 Floor::FruitBush(_) => Floor::FruitBush(new_filled),
+                Floor::Sown(_) => self.clone(),
             },
             CollectType::RawFlint => self.clone(),
             CollectType::Branches => self.clone(),
+            CollectType::Fish | CollectType::Water | CollectType::Logs => self.clone(),
         }
     }
 }
@@ -134,31 +559,40 @@ impl IntoCollect for Structure {
         match self {
             Structure::Nothing => None,
             Structure::FruitTree(branches_filled, food_filled) => match type_ {
-                CollectType::Food => Some(Collect::new(
-                    Material::Resource(Resource::Food),
-                    Quantity(25000),
-                    Quantity(1000),
-                    food_filled,
-                )),
-                CollectType::Branches => Some(Collect::new(
-                    Material::Resource(Resource::Branches),
-                    Quantity(1),
-                    Quantity(1),
-                    branches_filled,
-                )),
+                CollectType::Food => Some(
+                    gameplay()
+                        .collects
+                        .fruit_tree_food
+                        .collect(Material::Resource(Resource::Food), food_filled),
+                ),
+                CollectType::Branches => Some(
+                    gameplay()
+                        .collects
+                        .fruit_tree_branches
+                        .collect(Material::Resource(Resource::Branches), branches_filled),
+                ),
                 _ => None,
             },
             Structure::BigLeafTree(branches_filled) => match type_ {
-                CollectType::Branches => Some(Collect::new(
-                    Material::Resource(Resource::Branches),
-                    Quantity(1),
-                    Quantity(1),
-                    branches_filled,
-                )),
+                CollectType::Branches => Some(
+                    gameplay()
+                        .collects
+                        .big_leaf_tree_branches
+                        .collect(Material::Resource(Resource::Branches), branches_filled),
+                ),
+                CollectType::Logs => Some(
+                    gameplay()
+                        .collects
+                        .big_leaf_tree_logs
+                        .collect(Material::Resource(Resource::Logs), &LOGS_FILLED),
+                ),
                 _ => None,
             },
+            Structure::Sapling(_) => None,
+            Structure::Stump => None,
             Structure::Campfire => None,
             Structure::Storage => None,
+            Structure::Construction(_) => None,
         }
     }
 
@@ -170,19 +604,86 @@ impl IntoCollect for Structure {
                 Structure::FruitTree(branches_filled, _) => {
                     Structure::FruitTree(branches_filled.clone(), new_filled)
                 }
+                Structure::Sapling(_) | Structure::Stump => self.clone(),
                 Structure::Campfire => self.clone(),
                 Structure::Storage => self.clone(),
+                Structure::Construction(_) => self.clone(),
             },
             CollectType::RawFlint => self.clone(),
             CollectType::Branches => match self {
                 Structure::Nothing => todo!(),
-                Structure::BigLeafTree(_) => self.clone(),
+                Structure::BigLeafTree(_) => Structure::BigLeafTree(new_filled),
                 Structure::FruitTree(_, food_filled) => {
                     Structure::FruitTree(new_filled, food_filled.clone())
                 }
+                Structure::Sapling(_) | Structure::Stump => self.clone(),
                 Structure::Campfire => self.clone(),
                 Structure::Storage => self.clone(),
+                Structure::Construction(_) => self.clone(),
+            },
+            CollectType::Fish | CollectType::Water => self.clone(),
+            // Felled tree leaves a stump, whatever the collected quantity
+            CollectType::Logs => match self {
+                Structure::BigLeafTree(_) => Structure::Stump,
+                _ => self.clone(),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn test_example_file_is_default() {
+        // Given
+        let content = include_str!("../../../gameplay.example.ron");
+
+        // When
+        let config = GameplayConfig::from_ron(content);
+
+        // Then
+        assert_eq!(config, Ok(GameplayConfig::default()));
+    }
+
+    #[rstest]
+    fn test_omitted_values_are_default() {
+        // Given
+        let content = "(carrying: {Resource(Food): 1000}, ticks: (regrowth: 50))";
+
+        // When
+        let config = GameplayConfig::from_ron(content).unwrap();
+
+        // Then
+        assert_eq!(
+            config.carrying(&Material::Resource(Resource::Food)),
+            Quantity::grams(1000)
+        );
+        assert_eq!(
+            config.carrying(&Material::Resource(Resource::Fish)),
+            Quantity::grams(4000)
+        );
+        assert_eq!(config.ticks.regrowth, 50);
+        assert_eq!(config.ticks.wildlife, TicksConfig::default().wildlife);
+        assert_eq!(config.collects, CollectsConfig::default());
+    }
+
+    #[rstest]
+    #[case(
+        "(collects: (fruit_bush_food: (maximum: 100, iteration: 500, regrowth: None)))",
+        GameplayConfigError::IterationExceedsMaximum("fruit_bush_food".to_string())
+    )]
+    #[case(
+        "(ticks: (migrant: 0))",
+        GameplayConfigError::Zero("ticks.migrant".to_string())
+    )]
+    fn test_invalid(#[case] content: &str, #[case] expected: GameplayConfigError) {
+        // When
+        let config = GameplayConfig::from_ron(content);
+
+        // Then
+        assert_eq!(config, Err(expected));
+    }
+}
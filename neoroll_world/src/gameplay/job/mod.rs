@@ -3,7 +3,13 @@ use std::fmt::Display;
 use requirement::JobRequirement;
 use serde::{Deserialize, Serialize};
 
-use super::material::{Material, Resource};
+use crate::space::AbsoluteWorldPoint;
+
+use super::{
+    calendar::Season,
+    material::{Crafted, Material, Resource},
+    Quantity,
+};
 
 pub mod requirement;
 
@@ -11,6 +17,22 @@ pub mod requirement;
 pub enum Job {
     Idle,
     SearchResource(Resource),
+    /// Bring materials and work on construction site at given point
+    Build(AbsoluteWorldPoint),
+    /// Walk around tribe campfire, in given radius
+    Explore(u32),
+    /// Transform recipe materials at tribe campfire
+    Craft(Crafted),
+    /// Track and kill wild animals
+    Hunt,
+    /// Carry stocks of left storages to the new home of a migrating tribe
+    Migrate,
+    /// Plant saplings around tribe campfire
+    Plant,
+    /// Sow and harvest tribe fields
+    Farm,
+    /// Work defined by the scenario script target with this name
+    Scripted(String),
 }
 
 impl Default for Job {
@@ -25,6 +47,11 @@ impl From<&Material> for Job {
             Material::Resource(Resource::Food) => Job::SearchResource(Resource::Food),
             Material::Resource(Resource::RawFlint) => Job::SearchResource(Resource::RawFlint),
             Material::Resource(Resource::Branches) => Job::SearchResource(Resource::Branches),
+            Material::Resource(Resource::Fish) => Job::SearchResource(Resource::Fish),
+            Material::Resource(Resource::Water) => Job::SearchResource(Resource::Water),
+            Material::Resource(Resource::Logs) => Job::SearchResource(Resource::Logs),
+            Material::Crafted(crafted) => Job::Craft(*crafted),
+            Material::Hunted(_) => Job::Hunt,
         }
     }
 }
@@ -36,8 +63,39 @@ impl Job {
             Job::SearchResource(resource) => match resource {
                 Resource::Food => vec![],
                 Resource::RawFlint => vec![],
-                Resource::Branches => vec![],
+                // A sharp flint is needed to cut branches
+                Resource::Branches => vec![JobRequirement::Material(
+                    Material::Resource(Resource::RawFlint),
+                    Quantity::units(1),
+                )],
+                Resource::Fish => vec![],
+                Resource::Water => vec![],
+                // Trees are felled with a carved flint
+                Resource::Logs => vec![JobRequirement::Material(
+                    Material::Crafted(Crafted::CarvedFlint),
+                    Quantity::units(1),
+                )],
             },
+            Job::Build(_) => vec![],
+            Job::Explore(_) => vec![],
+            Job::Craft(crafted) => crafted
+                .recipe()
+                .into_iter()
+                .map(|(material, quantity)| JobRequirement::Material(material, quantity))
+                .collect(),
+            Job::Hunt => vec![],
+            Job::Migrate => vec![],
+            Job::Plant => vec![],
+            Job::Farm => vec![],
+            Job::Scripted(_) => vec![],
+        }
+    }
+
+    /// Job can't be done during some seasons (like fruits search)
+    pub fn in_season(&self, season: &Season) -> bool {
+        match self {
+            Job::SearchResource(Resource::Food) => season.fruits(),
+            _ => true,
         }
     }
 }
@@ -47,6 +105,14 @@ impl Display for Job {
         match self {
             Job::Idle => f.write_str("Idle"),
             Job::SearchResource(resource) => f.write_str(&format!("Search {}", resource)),
+            Job::Build(_) => f.write_str("Build"),
+            Job::Explore(_) => f.write_str("Explore"),
+            Job::Craft(crafted) => f.write_str(&format!("Craft {}", crafted)),
+            Job::Hunt => f.write_str("Hunt"),
+            Job::Migrate => f.write_str("Migrate"),
+            Job::Plant => f.write_str("Plant"),
+            Job::Farm => f.write_str("Farm"),
+            Job::Scripted(name) => f.write_str(name),
         }
     }
 }
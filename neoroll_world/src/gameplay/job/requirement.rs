@@ -1,5 +1,7 @@
 use crate::gameplay::{material::Material, Quantity};
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum JobRequirement {
+    /// Creature must carry this material (as tool or consumable) before working
     Material(Material, Quantity),
 }
@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    calendar::{DAYS_PER_SEASON, SEASONS_PER_YEAR},
+    material::{Material, Resource},
+    Quantity,
+};
+
+pub const DAYS_PER_YEAR: u64 = DAYS_PER_SEASON * SEASONS_PER_YEAR;
+/// Age from which a creature works and can give birth
+const ADULT_YEARS: u64 = 14;
+/// Age from which a creature can die of old age
+const OLD_YEARS: u64 = 50;
+/// Days without eating before dying
+pub const STARVATION_DAYS: u64 = 5;
+/// Food eaten by a creature each day
+pub const DAILY_FOOD_GRAMS: u64 = 1_000;
+
+const NAME_PREFIXES: [&str; 12] = [
+    "Ar", "Bo", "Ka", "Dru", "El", "Ga", "Ho", "Ma", "Na", "Ori", "Ta", "Vu",
+];
+const NAME_SUFFIXES: [&str; 12] = [
+    "k", "ra", "m", "nda", "lo", "g", "rin", "sa", "th", "u", "ven", "x",
+];
+
+/// Creature age, counted in in game days
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Age(pub u64);
+
+impl Age {
+    pub fn from_years(years: u64) -> Self {
+        Self(years * DAYS_PER_YEAR)
+    }
+
+    pub fn years(&self) -> u64 {
+        self.0 / DAYS_PER_YEAR
+    }
+
+    pub fn older(&self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    pub fn is_adult(&self) -> bool {
+        self.years() >= ADULT_YEARS
+    }
+
+    pub fn is_old(&self) -> bool {
+        self.years() >= OLD_YEARS
+    }
+}
+
+impl Display for Age {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{} years", self.years()))
+    }
+}
+
+/// Count of consecutive days without eating
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Hunger(pub u64);
+
+impl Hunger {
+    pub fn starving(&self) -> bool {
+        self.0 > 0
+    }
+
+    pub fn starved(&self) -> bool {
+        self.0 >= STARVATION_DAYS
+    }
+}
+
+impl Display for Hunger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.starving() {
+            f.write_str(&format!("Starving ({} days)", self.0))
+        } else {
+            f.write_str("Fed")
+        }
+    }
+}
+
+/// Materials a creature can eat
+pub fn foods() -> Vec<Material> {
+    vec![
+        Material::Resource(Resource::Food),
+        Material::Resource(Resource::Fish),
+    ]
+}
+
+pub fn daily_food() -> Quantity {
+    Quantity::grams(DAILY_FOOD_GRAMS)
+}
+
+pub fn random_name<R: Rng>(rng: &mut R) -> String {
+    format!(
+        "{}{}",
+        NAME_PREFIXES.choose(rng).unwrap_or(&"Ar"),
+        NAME_SUFFIXES.choose(rng).unwrap_or(&"k")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Age(0), 0, false, false)]
+    #[case(Age::from_years(13), 13, false, false)]
+    #[case(Age::from_years(14), 14, true, false)]
+    #[case(Age::from_years(50), 50, true, true)]
+    fn test_age(#[case] age: Age, #[case] years: u64, #[case] adult: bool, #[case] old: bool) {
+        // Given/When/Then
+        assert_eq!(age.years(), years);
+        assert_eq!(age.is_adult(), adult);
+        assert_eq!(age.is_old(), old);
+    }
+}
@@ -1,24 +1,72 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
 
-use super::{Quantity, Weight};
+use super::{
+    unit::{Unit, UnitMismatch},
+    Quantity, Volume, Weight,
+};
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub enum Material {
     Resource(Resource),
+    Crafted(Crafted),
+    Hunted(Hunted),
 }
 impl Material {
-    pub fn quantity_string(&self, quantity: &Quantity) -> String {
-        let (unit, divide) = match self {
-            Material::Resource(resource) => match resource {
-                Resource::Food => ("Kg", 1_000), // wrote in g
-                Resource::RawFlint => ("u", 1),  // wrote in u
-                Resource::Branches => ("m³", 1), // wrote in m³
-            },
-        };
+    pub fn unit(&self) -> Unit {
+        match self {
+            Material::Resource(resource) => resource.unit(),
+            Material::Crafted(crafted) => crafted.unit(),
+            Material::Hunted(hunted) => hunted.unit(),
+        }
+    }
 
-        format!("{}{}", quantity.0 / divide, unit)
+    /// Quantity of this material, counted in its unit
+    pub fn quantity(&self, value: u64) -> Quantity {
+        Quantity(value, self.unit())
+    }
+
+    /// Given quantity, if it is counted in this material unit
+    pub fn checked_quantity(&self, quantity: Quantity) -> Result<Quantity, UnitMismatch> {
+        if quantity.0 == 0 || quantity.unit() == self.unit() {
+            Ok(self.quantity(quantity.0))
+        } else {
+            Err(UnitMismatch(self.unit(), quantity.unit()))
+        }
+    }
+
+    pub fn weight(&self, quantity: &Quantity) -> Weight {
+        match self {
+            Material::Resource(resource) => resource.weight(quantity),
+            Material::Crafted(crafted) => crafted.weight(quantity),
+            Material::Hunted(hunted) => hunted.weight(quantity),
+        }
+    }
+
+    pub fn volume(&self, quantity: &Quantity) -> Volume {
+        match self {
+            Material::Resource(resource) => resource.volume(quantity),
+            Material::Crafted(crafted) => crafted.volume(quantity),
+            Material::Hunted(hunted) => hunted.volume(quantity),
+        }
+    }
+
+    /// Material which is carried to the new home when tribe migrates
+    pub fn transportable(&self) -> bool {
+        match self {
+            // Water is collected again near the new home
+            Material::Resource(Resource::Water) => false,
+            Material::Resource(_) | Material::Crafted(_) | Material::Hunted(_) => true,
+        }
+    }
+
+    /// Maximum quantity of this material fitting in given weight and volume
+    pub fn fitting(&self, weight: &Weight, volume: &Volume) -> Quantity {
+        let unit_weight = self.weight(&self.quantity(1)).0.max(1);
+        let unit_volume = self.volume(&self.quantity(1)).0.max(1);
+        self.quantity((weight.0 / unit_weight).min(volume.0 / unit_volume))
     }
 }
 
@@ -26,27 +74,83 @@ impl Display for Material {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Material::Resource(resource) => f.write_str(&resource.to_string()),
+            Material::Crafted(crafted) => f.write_str(&crafted.to_string()),
+            Material::Hunted(hunted) => f.write_str(&hunted.to_string()),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, EnumIter)]
 pub enum Resource {
     Food,
     RawFlint,
     Branches,
-    // CarvedFlint,
+    Fish,
+    Water,
+    Logs,
 }
 
 impl Resource {
+    pub fn unit(self) -> Unit {
+        match self {
+            Resource::Food => Unit::Gram,
+            Resource::RawFlint => Unit::Unit,
+            Resource::Branches => Unit::CubicMeter,
+            Resource::Fish => Unit::Gram,
+            Resource::Water => Unit::Gram,
+            Resource::Logs => Unit::Unit,
+        }
+    }
+
     pub fn weight(self, quantity: &Quantity) -> Weight {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
         let factor = match self {
             Resource::Food => 1,
             Resource::RawFlint => 1000,
-            Resource::Branches => 1,
+            Resource::Branches => 20_000,
+            Resource::Fish => 1,
+            Resource::Water => 1,
+            Resource::Logs => 20_000,
         };
         Weight(quantity.0 * factor)
     }
+
+    pub fn volume(self, quantity: &Quantity) -> Volume {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
+        let factor = match self {
+            Resource::Food => 2,
+            Resource::RawFlint => 400,
+            Resource::Branches => 1_000_000,
+            Resource::Fish => 2,
+            Resource::Water => 1,
+            Resource::Logs => 100_000,
+        };
+        Volume(quantity.0 * factor)
+    }
+
+    /// Collect duration factor (relative to the easiest resource to collect)
+    pub fn difficulty(self) -> u64 {
+        match self {
+            Resource::Food => 1,
+            Resource::RawFlint => 2,
+            Resource::Branches => 3,
+            Resource::Fish => 2,
+            Resource::Water => 1,
+            Resource::Logs => 4,
+        }
+    }
+
+    /// Carried material making the collect faster and more productive
+    pub fn tool(self) -> Option<Material> {
+        match self {
+            Resource::Food => None,
+            Resource::RawFlint => None,
+            Resource::Branches => Some(Material::Crafted(Crafted::CarvedFlint)),
+            Resource::Fish => Some(Material::Crafted(Crafted::CarvedFlint)),
+            Resource::Water => None,
+            Resource::Logs => Some(Material::Crafted(Crafted::CarvedFlint)),
+        }
+    }
 }
 
 impl Display for Resource {
@@ -55,7 +159,192 @@ impl Display for Resource {
             Resource::Food => f.write_str("Food"),
             Resource::RawFlint => f.write_str("Raw Flint"),
             Resource::Branches => f.write_str("Branches"),
-            // Resource::CarvedFlint => f.write_str("Carved Flint"),
+            Resource::Fish => f.write_str("Fish"),
+            Resource::Water => f.write_str("Water"),
+            Resource::Logs => f.write_str("Logs"),
+        }
+    }
+}
+
+/// Materials produced by creatures from other materials
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, EnumIter)]
+pub enum Crafted {
+    CarvedFlint,
+}
+
+impl Crafted {
+    pub fn unit(self) -> Unit {
+        match self {
+            Crafted::CarvedFlint => Unit::Unit,
+        }
+    }
+
+    pub fn weight(self, quantity: &Quantity) -> Weight {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
+        let factor = match self {
+            Crafted::CarvedFlint => 500,
+        };
+        Weight(quantity.0 * factor)
+    }
+
+    pub fn volume(self, quantity: &Quantity) -> Volume {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
+        let factor = match self {
+            Crafted::CarvedFlint => 200,
+        };
+        Volume(quantity.0 * factor)
+    }
+
+    /// Materials consumed to produce one unit
+    pub fn recipe(self) -> Vec<(Material, Quantity)> {
+        match self {
+            Crafted::CarvedFlint => {
+                vec![(Material::Resource(Resource::RawFlint), Quantity::units(2))]
+            }
+        }
+    }
+}
+
+impl Display for Crafted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Crafted::CarvedFlint => f.write_str("Carved Flint"),
+        }
+    }
+}
+
+/// Materials obtained by hunting wild animals
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, EnumIter)]
+pub enum Hunted {
+    Hide,
+}
+
+impl Hunted {
+    pub fn unit(self) -> Unit {
+        match self {
+            Hunted::Hide => Unit::Unit,
+        }
+    }
+
+    pub fn weight(self, quantity: &Quantity) -> Weight {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
+        let factor = match self {
+            Hunted::Hide => 3000,
+        };
+        Weight(quantity.0 * factor)
+    }
+
+    pub fn volume(self, quantity: &Quantity) -> Volume {
+        debug_assert!(quantity.0 == 0 || quantity.1 == self.unit());
+        let factor = match self {
+            Hunted::Hide => 20_000,
+        };
+        Volume(quantity.0 * factor)
+    }
+}
+
+impl Display for Hunted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hunted::Hide => f.write_str("Hide"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(
+        Resource::Food,
+        Weight(40_000),
+        Volume(1_000_000),
+        Quantity::grams(40_000)
+    )]
+    #[case(Resource::Food, Weight(40_000), Volume(10_000), Quantity::grams(5_000))]
+    #[case(
+        Resource::RawFlint,
+        Weight(40_000),
+        Volume(1_000_000),
+        Quantity::units(40)
+    )]
+    #[case(
+        Resource::Branches,
+        Weight(40_000),
+        Volume(1_000_000),
+        Quantity::cubic_meters(1)
+    )]
+    #[case(
+        Resource::Branches,
+        Weight(10_000),
+        Volume(1_000_000),
+        Quantity::cubic_meters(0)
+    )]
+    #[case(Resource::Logs, Weight(40_000), Volume(1_000_000), Quantity::units(2))]
+    fn test_material_fitting(
+        #[case] resource: Resource,
+        #[case] weight: Weight,
+        #[case] volume: Volume,
+        #[case] expected: Quantity,
+    ) {
+        assert_eq!(
+            Material::Resource(resource).fitting(&weight, &volume),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(
+        Quantity::grams(500),
+        Quantity::grams(1_500),
+        Ok(Quantity::kilograms(2))
+    )]
+    #[case(Quantity::grams(0), Quantity::units(3), Ok(Quantity::units(3)))]
+    #[case(Quantity::units(3), Quantity::cubic_meters(0), Ok(Quantity::units(3)))]
+    #[case(
+        Quantity::grams(500),
+        Quantity::units(3),
+        Err(UnitMismatch(Unit::Gram, Unit::Unit))
+    )]
+    fn test_quantity_checked_add(
+        #[case] a: Quantity,
+        #[case] b: Quantity,
+        #[case] expected: Result<Quantity, UnitMismatch>,
+    ) {
+        assert_eq!(a.checked_add(b), expected);
+    }
+
+    #[rstest]
+    #[case(Resource::Food, Quantity::grams(500), Ok(Quantity::grams(500)))]
+    #[case(Resource::Food, Quantity::units(0), Ok(Quantity::grams(0)))]
+    #[case(
+        Resource::Food,
+        Quantity::units(3),
+        Err(UnitMismatch(Unit::Gram, Unit::Unit))
+    )]
+    fn test_material_checked_quantity(
+        #[case] resource: Resource,
+        #[case] quantity: Quantity,
+        #[case] expected: Result<Quantity, UnitMismatch>,
+    ) {
+        let checked = Material::Resource(resource).checked_quantity(quantity);
+        assert_eq!(checked, expected);
+        if let Ok(checked) = checked {
+            assert_eq!(checked.unit(), resource.unit());
         }
     }
+
+    #[rstest]
+    #[case(Material::Resource(Resource::Food), 2_500, "2Kg")]
+    #[case(Material::Resource(Resource::RawFlint), 12, "12u")]
+    #[case(Material::Resource(Resource::Branches), 3, "3m³")]
+    fn test_quantity_display(
+        #[case] material: Material,
+        #[case] value: u64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(material.quantity(value).to_string(), expected);
+    }
 }
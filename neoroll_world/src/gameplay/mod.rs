@@ -2,26 +2,51 @@ pub mod config;
 pub mod material;
 pub mod target;
 use std::{
+    fmt::Display,
     iter::Sum,
     ops::{Add, AddAssign, Mul},
 };
 
 use material::Resource;
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use unit::{Unit, UnitMismatch};
 
 pub mod behavior;
 pub mod build;
+pub mod calendar;
 pub mod job;
+pub mod lifecycle;
 pub mod need;
+pub mod order;
 pub mod progress;
+pub mod skill;
+pub mod tech;
 pub mod tribe;
+pub mod unit;
 
 // TODO: Replace by Resource ?
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, EnumIter)]
 pub enum CollectType {
     Food,
     RawFlint,
     Branches,
+    Fish,
+    Water,
+    Logs,
+}
+
+impl CollectType {
+    /// Collected from an adjacent tile because it can't be walked on (water)
+    pub fn from_adjacent(&self) -> bool {
+        match self {
+            CollectType::Food
+            | CollectType::RawFlint
+            | CollectType::Branches
+            | CollectType::Logs => false,
+            CollectType::Fish | CollectType::Water => true,
+        }
+    }
 }
 
 impl From<Resource> for CollectType {
@@ -30,43 +55,79 @@ impl From<Resource> for CollectType {
             Resource::Food => Self::Food,
             Resource::RawFlint => Self::RawFlint,
             Resource::Branches => Self::Branches,
+            Resource::Fish => Self::Fish,
+            Resource::Water => Self::Water,
+            Resource::Logs => Self::Logs,
         }
     }
 }
 
-// FIXME BS NOW: into enum for grams, mc, unit, etc ?
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Default)]
-pub struct Quantity(pub u64);
+/// Quantity of a material, counted in the material unit (see [Material::unit]).
+/// Zero is compatible with all units, other values can't be mixed between units.
+///
+/// [Material::unit]: material::Material::unit
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct Quantity(pub u64, pub Unit);
 
-impl Add for Quantity {
-    type Output = Self;
+impl Quantity {
+    pub fn grams(value: u64) -> Self {
+        Self(value, Unit::Gram)
+    }
 
-    fn add(self, other: Self) -> Self {
-        Self(self.0 + other.0)
+    pub fn kilograms(value: u64) -> Self {
+        Self(value * 1_000, Unit::Gram)
+    }
+
+    pub fn units(value: u64) -> Self {
+        Self(value, Unit::Unit)
+    }
+
+    pub fn cubic_meters(value: u64) -> Self {
+        Self(value, Unit::CubicMeter)
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.1
+    }
+
+    /// Another quantity with same unit
+    pub fn with(&self, value: u64) -> Self {
+        Self(value, self.1)
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, UnitMismatch> {
+        match (self.0, other.0) {
+            (0, _) => Ok(other),
+            (_, 0) => Ok(self),
+            _ if self.1 == other.1 => Ok(Self(self.0 + other.0, self.1)),
+            _ => Err(UnitMismatch(self.1, other.1)),
+        }
     }
 }
 
-impl AddAssign for Quantity {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0
+impl PartialEq for Quantity {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && (self.0 == 0 || self.1 == other.1)
     }
 }
 
-impl Mul for Quantity {
+impl Eq for Quantity {}
+
+impl Mul<u64> for Quantity {
     type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.0 * rhs, self.1)
     }
 }
 
-impl Sum for Quantity {
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        Self(iter.map(|q| q.0).sum())
+impl Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.1.format(self.0))
     }
 }
 
-/// All in game things have same unit
+/// All in game things have same unit (grams)
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Default)]
 pub struct Weight(pub u64);
 
@@ -97,3 +158,39 @@ impl Sum for Weight {
         Self(iter.map(|q| q.0).sum())
     }
 }
+
+impl Display for Weight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{}Kg", self.0 / 1_000))
+    }
+}
+
+/// Space taken by in game things (cm³)
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Volume(pub u64);
+
+impl Add for Volume {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Volume {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0
+    }
+}
+
+impl Sum for Volume {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|q| q.0).sum())
+    }
+}
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{:.2}m³", self.0 as f32 / 1_000_000.))
+    }
+}
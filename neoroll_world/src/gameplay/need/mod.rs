@@ -1,6 +1,18 @@
-use super::{job::Job, material::Material, Quantity};
+use super::{build::Buildable, job::Job, material::Material, Quantity};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Need {
     MaterialInStorages(Material, Quantity),
+    /// Count of owned structures built from this buildable
+    StructuresBuilt(Buildable, usize),
+    /// All walkable tiles around campfire, in this radius, have been explored
+    Explored(u32),
+    /// Total quantity produced by the tribe
+    Produced(Material, Quantity),
+    /// Count of trees (saplings included) around campfire
+    TreesAround(usize),
+    /// All tribe fields tiles are sown and no ripe bush waits for harvest
+    FieldsTended,
+    /// Progress computed by the scenario script target with this name
+    Scripted(String),
 }
@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::space::AbsoluteWorldPoint;
+
+use super::material::Resource;
+
+/// Player direct order given to a creature. An order overrides the creature job
+/// until it is completed (or canceled).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Order {
+    /// Walk to given point
+    MoveTo(AbsoluteWorldPoint),
+    /// Collect resource at given point until creature can't carry more or tile is empty
+    Collect(AbsoluteWorldPoint, Resource),
+    /// Bring all carried materials to storage at given point
+    DropOff(AbsoluteWorldPoint),
+    /// Do nothing until order is canceled
+    Idle,
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Order::MoveTo(_) => f.write_str("Move"),
+            Order::Collect(_, resource) => f.write_str(&format!("Collect {}", resource)),
+            Order::DropOff(_) => f.write_str("Drop off"),
+            Order::Idle => f.write_str("Stay idle"),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::job::Job;
+
+/// Experience required to reach the maximum level
+const MAX_EXPERIENCE: u32 = 10_000;
+pub const MAX_LEVEL: u8 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, EnumIter)]
+pub enum Skill {
+    Gathering,
+    Crafting,
+    Building,
+    Hunting,
+}
+
+impl Skill {
+    pub fn from_job(job: &Job) -> Option<Self> {
+        match job {
+            Job::Idle => None,
+            Job::SearchResource(_) => Some(Skill::Gathering),
+            Job::Build(_) => Some(Skill::Building),
+            Job::Explore(_) => None,
+            Job::Craft(_) => Some(Skill::Crafting),
+            Job::Hunt => Some(Skill::Hunting),
+            Job::Migrate => None,
+            Job::Plant => Some(Skill::Gathering),
+            Job::Farm => Some(Skill::Gathering),
+            Job::Scripted(_) => None,
+        }
+    }
+}
+
+impl Display for Skill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Skill::Gathering => f.write_str("Gathering"),
+            Skill::Crafting => f.write_str("Crafting"),
+            Skill::Building => f.write_str("Building"),
+            Skill::Hunting => f.write_str("Hunting"),
+        }
+    }
+}
+
+/// Experience of a creature for each skill. Experience grows with practice
+/// and gives a level (0 to [MAX_LEVEL]) with decreasing gains.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Skills {
+    experience: HashMap<Skill, u32>,
+}
+
+impl Skills {
+    pub fn experience(&self, skill: &Skill) -> u32 {
+        self.experience.get(skill).copied().unwrap_or(0)
+    }
+
+    pub fn practice(&mut self, skill: Skill, experience: u32) {
+        let value = self.experience.entry(skill).or_default();
+        *value = value.saturating_add(experience).min(MAX_EXPERIENCE);
+    }
+
+    pub fn level(&self, skill: &Skill) -> u8 {
+        let ratio = self.experience(skill) as f32 / MAX_EXPERIENCE as f32;
+        (ratio.sqrt() * MAX_LEVEL as f32) as u8
+    }
+
+    /// Work speed multiplier: from 1.0 (no experience) to 2.0 (maximum level)
+    pub fn speed(&self, skill: &Skill) -> f32 {
+        1. + self.level(skill) as f32 / MAX_LEVEL as f32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(0, 0, 1.)]
+    #[case(100, 10, 1.1)]
+    #[case(2_500, 50, 1.5)]
+    #[case(10_000, 100, 2.)]
+    #[case(20_000, 100, 2.)]
+    fn test_skill_level(#[case] experience: u32, #[case] level: u8, #[case] speed: f32) {
+        // Given
+        let mut skills = Skills::default();
+
+        // When
+        skills.practice(Skill::Gathering, experience);
+
+        // Then
+        assert_eq!(skills.level(&Skill::Gathering), level);
+        assert_eq!(skills.speed(&Skill::Gathering), speed);
+        assert_eq!(skills.level(&Skill::Crafting), 0);
+    }
+}
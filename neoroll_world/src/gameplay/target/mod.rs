@@ -0,0 +1,280 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entity::structure::Structure;
+
+use super::{
+    build::Buildable,
+    config::gameplay,
+    job::Job,
+    material::{Crafted, Material},
+    need::Need,
+    Quantity,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    KeepStock(Material, TargetQuantity), // Quantity per habitant
+    /// Keep this count of structures built
+    KeepBuilt(Buildable, usize),
+    /// Explore around the campfire, in this radius
+    Explore(u32),
+    /// Produce this total quantity
+    Produce(Crafted, Quantity),
+    /// Keep this count of trees (saplings included) around the campfire
+    Plant(usize),
+    /// Keep tribe fields sown and harvested
+    Farm,
+    /// Target defined by a scenario script, with this name
+    Scripted(String),
+}
+
+/// Quantities are counted in the target material unit
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TargetQuantity {
+    Fixed(Quantity),
+    PerHuman(Quantity),
+}
+
+impl Default for TargetQuantity {
+    fn default() -> Self {
+        Self::Fixed(Quantity::default())
+    }
+}
+
+impl Target {
+    pub fn name(&self) -> String {
+        match self {
+            Target::KeepStock(material, _) => format!("Keep stock of {}", &material.to_string()),
+            Target::KeepBuilt(buildable, _) => format!("Keep built {}", &buildable.to_string()),
+            Target::Explore(_) => "Explore".to_string(),
+            Target::Produce(crafted, _) => format!("Produce {}", &crafted.to_string()),
+            Target::Plant(_) => "Plant trees".to_string(),
+            Target::Farm => "Farm".to_string(),
+            Target::Scripted(name) => name.clone(),
+        }
+    }
+
+    /// Target with values of new targets (see `TargetsConfig`)
+    pub fn default(&self) -> Target {
+        let config = &gameplay().targets;
+        match self {
+            Target::KeepStock(material, _) => Target::KeepStock(
+                *material,
+                config
+                    .keep_stock
+                    .get(material)
+                    .map(|quantity| quantity.target_quantity(material))
+                    .unwrap_or_default(),
+            ),
+            Target::KeepBuilt(buildable, _) => Target::KeepBuilt(*buildable, config.keep_built),
+            Target::Explore(_) => Target::Explore(config.explore),
+            Target::Produce(crafted, _) => Target::Produce(
+                *crafted,
+                Material::Crafted(*crafted).quantity(config.produce),
+            ),
+            Target::Plant(_) => Target::Plant(config.plant),
+            Target::Farm => Target::Farm,
+            Target::Scripted(name) => Target::Scripted(name.clone()),
+        }
+    }
+
+    pub fn is_same(&self, target: &Target) -> bool {
+        match (self, target) {
+            (Target::KeepStock(material, _), Target::KeepStock(material_, _)) => {
+                material.eq(material_)
+            }
+            (Target::KeepBuilt(buildable, _), Target::KeepBuilt(buildable_, _)) => {
+                buildable.eq(buildable_)
+            }
+            (Target::Explore(_), Target::Explore(_)) => true,
+            (Target::Produce(crafted, _), Target::Produce(crafted_, _)) => crafted.eq(crafted_),
+            (Target::Plant(_), Target::Plant(_)) => true,
+            (Target::Farm, Target::Farm) => true,
+            (Target::Scripted(name), Target::Scripted(name_)) => name.eq(name_),
+            _ => false,
+        }
+    }
+
+    /// Quantity carried by a worker before bringing it back to storage
+    pub fn carrying_enough_quantity(material: &Material) -> Quantity {
+        gameplay().carrying(material)
+    }
+
+    pub fn material(&self) -> Option<Material> {
+        match self {
+            Target::KeepStock(material, _) => Some(*material),
+            Target::KeepBuilt(_, _)
+            | Target::Explore(_)
+            | Target::Plant(_)
+            | Target::Farm
+            | Target::Scripted(_) => None,
+            Target::Produce(crafted, _) => Some(Material::Crafted(*crafted)),
+        }
+    }
+
+    /// Job of target workers. Targets without job are realized by other ways
+    /// (like construction sites for [Target::KeepBuilt]).
+    pub fn job(&self) -> Option<Job> {
+        match self {
+            Target::KeepStock(material, _) => Some(material.into()),
+            Target::KeepBuilt(_, _) => None,
+            Target::Explore(radius) => Some(Job::Explore(*radius)),
+            Target::Produce(crafted, _) => Some(Job::Craft(*crafted)),
+            Target::Plant(_) => Some(Job::Plant),
+            Target::Farm => Some(Job::Farm),
+            Target::Scripted(name) => Some(Job::Scripted(name.clone())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TargetId(Uuid);
+
+impl TargetId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for TargetId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedTarget {
+    id: TargetId,
+    target: Target,
+    covered: bool,
+    affected: usize,
+    needs: Vec<Need>,
+    priority: usize,
+    /// Percentage of the target which is not covered yet
+    urgency: u8,
+    min_workers: usize,
+    max_workers: Option<usize>,
+    enabled: bool,
+}
+
+impl ComputedTarget {
+    pub fn new(
+        id: TargetId,
+        target: Target,
+        covered: bool,
+        affected: usize,
+        needs: Vec<Need>,
+        priority: usize,
+        urgency: u8,
+    ) -> Self {
+        Self {
+            id,
+            target,
+            covered,
+            affected,
+            needs,
+            priority,
+            urgency,
+            min_workers: 0,
+            max_workers: None,
+            enabled: true,
+        }
+    }
+
+    pub fn with_workers(mut self, min: usize, max: Option<usize>) -> Self {
+        self.min_workers = min;
+        self.max_workers = max;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn id(&self) -> &TargetId {
+        &self.id
+    }
+
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    pub fn affected(&self) -> usize {
+        self.affected
+    }
+
+    pub fn needs(&self) -> &Vec<Need> {
+        &self.needs
+    }
+
+    pub fn covered(&self) -> bool {
+        self.covered
+    }
+
+    pub fn state_string(&self) -> &str {
+        if !self.enabled {
+            return "Paused";
+        }
+
+        if self.covered {
+            return "Covered";
+        }
+
+        if self.affected != 0 {
+            return "On Going";
+        }
+
+        "Waiting"
+    }
+
+    pub fn priority(&self) -> usize {
+        self.priority
+    }
+
+    pub fn urgency(&self) -> u8 {
+        self.urgency
+    }
+
+    pub fn min_workers(&self) -> usize {
+        self.min_workers
+    }
+
+    pub fn max_workers(&self) -> Option<usize> {
+        self.max_workers
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitingReason {
+    NotEnoughWorker,
+    NotEnoughMaterial(Material),
+    NeedOwnedStructure(Structure),
+    NoPlaceToBuild(Buildable),
+    /// Tribe didn't discover the technology required by target
+    NotDiscovered,
+    /// Target job can't be done during current season
+    OutOfSeason,
+}
+
+impl Display for WaitingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaitingReason::NotEnoughWorker => f.write_str("Worker"),
+            WaitingReason::NotEnoughMaterial(material) => f.write_str(&material.to_string()),
+            WaitingReason::NeedOwnedStructure(structure) => f.write_str(&structure.to_string()),
+            WaitingReason::NoPlaceToBuild(buildable) => {
+                f.write_str(&format!("Place for {}", buildable))
+            }
+            WaitingReason::NotDiscovered => f.write_str("Technology"),
+            WaitingReason::OutOfSeason => f.write_str("Season"),
+        }
+    }
+}
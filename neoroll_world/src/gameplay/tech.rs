@@ -0,0 +1,221 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use super::{
+    build::Buildable,
+    job::Job,
+    material::{Crafted, Resource},
+    target::Target,
+};
+
+/// Technologies known by every new tribe
+const INITIAL: [Technology; 2] = [Technology::Fire, Technology::Storage];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, EnumIter)]
+pub enum Technology {
+    Fire,
+    Storage,
+    FlintKnapping,
+    Fishing,
+    Hunting,
+    Farming,
+}
+
+/// What a technology gives access to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unlock {
+    Buildable(Buildable),
+    Recipe(Crafted),
+    Job(Job),
+}
+
+impl Technology {
+    /// Knowledge points required to discover this technology
+    pub fn cost(&self) -> u64 {
+        match self {
+            Technology::Fire => 0,
+            Technology::Storage => 0,
+            Technology::FlintKnapping => 300,
+            Technology::Fishing => 500,
+            Technology::Hunting => 800,
+            Technology::Farming => 1000,
+        }
+    }
+
+    /// Technologies which must be known before discovering this one
+    pub fn requires(&self) -> Vec<Technology> {
+        match self {
+            Technology::Fire => vec![],
+            Technology::Storage => vec![],
+            Technology::FlintKnapping => vec![Technology::Fire],
+            Technology::Fishing => vec![Technology::Storage],
+            // Animals are killed with carved flint weapons
+            Technology::Hunting => vec![Technology::FlintKnapping],
+            // Harvests are kept in storages
+            Technology::Farming => vec![Technology::Storage],
+        }
+    }
+
+    pub fn unlocks(&self) -> Vec<Unlock> {
+        match self {
+            Technology::Fire => vec![Unlock::Buildable(Buildable::Campfire)],
+            Technology::Storage => vec![Unlock::Buildable(Buildable::Storage)],
+            Technology::FlintKnapping => vec![Unlock::Recipe(Crafted::CarvedFlint)],
+            Technology::Fishing => vec![Unlock::Job(Job::SearchResource(Resource::Fish))],
+            Technology::Hunting => vec![Unlock::Job(Job::Hunt)],
+            Technology::Farming => vec![Unlock::Job(Job::Farm)],
+        }
+    }
+}
+
+impl Display for Technology {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Technology::Fire => f.write_str("Fire"),
+            Technology::Storage => f.write_str("Storage"),
+            Technology::FlintKnapping => f.write_str("Flint knapping"),
+            Technology::Fishing => f.write_str("Fishing"),
+            Technology::Hunting => f.write_str("Hunting"),
+            Technology::Farming => f.write_str("Farming"),
+        }
+    }
+}
+
+/// Tribe knowledge: points earned by creatures activity, spent to discover technologies
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Knowledge {
+    points: u64,
+    known: Vec<Technology>,
+}
+
+impl Default for Knowledge {
+    fn default() -> Self {
+        Self {
+            points: 0,
+            known: INITIAL.to_vec(),
+        }
+    }
+}
+
+impl Knowledge {
+    pub fn points(&self) -> u64 {
+        self.points
+    }
+
+    pub fn known(&self) -> &[Technology] {
+        &self.known
+    }
+
+    pub fn knows(&self, technology: &Technology) -> bool {
+        self.known.contains(technology)
+    }
+
+    pub fn earn(&mut self, points: u64) {
+        self.points = self.points.saturating_add(points);
+    }
+
+    pub fn can_discover(&self, technology: &Technology) -> bool {
+        !self.knows(technology)
+            && technology.requires().iter().all(|t| self.knows(t))
+            && self.points >= technology.cost()
+    }
+
+    /// Spend knowledge points to discover technology (nothing happens if it can't be discovered)
+    pub fn discover(&mut self, technology: Technology) {
+        if self.can_discover(&technology) {
+            self.points -= technology.cost();
+            self.known.push(technology);
+        }
+    }
+
+    /// Things which are not unlocked by any technology are always available
+    pub fn unlocked(&self, unlock: &Unlock) -> bool {
+        let technologies = Technology::iter()
+            .filter(|t| t.unlocks().contains(unlock))
+            .collect::<Vec<Technology>>();
+        technologies.is_empty() || technologies.iter().any(|t| self.knows(t))
+    }
+
+    pub fn can_build(&self, buildable: &Buildable) -> bool {
+        self.unlocked(&Unlock::Buildable(*buildable))
+    }
+
+    pub fn can_craft(&self, crafted: &Crafted) -> bool {
+        self.unlocked(&Unlock::Recipe(*crafted))
+    }
+
+    pub fn can_work(&self, job: &Job) -> bool {
+        match job {
+            Job::Craft(crafted) => self.can_craft(crafted),
+            _ => self.unlocked(&Unlock::Job(job.clone())),
+        }
+    }
+
+    pub fn can_target(&self, target: &Target) -> bool {
+        match target {
+            Target::KeepBuilt(buildable, _) => self.can_build(buildable),
+            _ => target.job().map(|job| self.can_work(&job)).unwrap_or(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gameplay::material::Material;
+    use rstest::*;
+
+    #[rstest]
+    #[case(vec![], 0, Technology::FlintKnapping, false)]
+    #[case(vec![], 300, Technology::FlintKnapping, true)]
+    #[case(vec![], 1000, Technology::Hunting, false)]
+    #[case(vec![Technology::FlintKnapping], 1000, Technology::Hunting, true)]
+    #[case(vec![Technology::FlintKnapping], 1000, Technology::FlintKnapping, false)]
+    fn test_can_discover(
+        #[case] discovered: Vec<Technology>,
+        #[case] points: u64,
+        #[case] technology: Technology,
+        #[case] expected: bool,
+    ) {
+        // Given
+        let mut knowledge = Knowledge::default();
+        for technology in discovered {
+            knowledge.earn(technology.cost());
+            knowledge.discover(technology);
+        }
+        knowledge.earn(points);
+
+        // When
+        let result = knowledge.can_discover(&technology);
+
+        // Then
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(
+        Target::KeepStock(Material::Resource(Resource::Food), Default::default()),
+        true
+    )]
+    #[case(
+        Target::KeepStock(Material::Resource(Resource::Fish), Default::default()),
+        false
+    )]
+    #[case(Target::KeepBuilt(Buildable::Storage, 1), true)]
+    #[case(Target::Produce(Crafted::CarvedFlint, Default::default()), false)]
+    #[case(Target::Explore(10), true)]
+    #[case(Target::Farm, false)]
+    fn test_can_target(#[case] target: Target, #[case] expected: bool) {
+        // Given
+        let knowledge = Knowledge::default();
+
+        // When
+        let result = knowledge.can_target(&target);
+
+        // Then
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::space::AbsoluteWorldPoint;
+
+/// Radius of fields designated by player
+pub const FIELD_RADIUS: u32 = 2;
+
+/// Area where tribe sows fruit bushes and harvests them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    center: AbsoluteWorldPoint,
+    radius: u32,
+}
+
+impl Field {
+    pub fn new(center: AbsoluteWorldPoint, radius: u32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn center(&self) -> &AbsoluteWorldPoint {
+        &self.center
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    pub fn points(&self) -> Vec<AbsoluteWorldPoint> {
+        self.center.around(self.radius)
+    }
+
+    pub fn contains(&self, point: &AbsoluteWorldPoint) -> bool {
+        point.squared_distance(&self.center) <= (self.radius * self.radius) as isize
+    }
+
+    pub fn overlaps(&self, other: &Field) -> bool {
+        let distance = self.radius + other.radius;
+        self.center.squared_distance(&other.center) <= (distance * distance) as isize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::space::{AbsoluteWorldColI, AbsoluteWorldRowI};
+    use rstest::*;
+
+    fn point(row: isize, col: isize) -> AbsoluteWorldPoint {
+        AbsoluteWorldPoint(AbsoluteWorldRowI(row), AbsoluteWorldColI(col))
+    }
+
+    #[rstest]
+    #[case(point(10, 10), true)]
+    #[case(point(12, 10), true)]
+    #[case(point(12, 12), false)]
+    #[case(point(13, 10), false)]
+    fn test_contains(#[case] point_: AbsoluteWorldPoint, #[case] expected: bool) {
+        // Given
+        let field = Field::new(point(10, 10), 2);
+
+        // When
+        let result = field.contains(&point_);
+
+        // Then
+        assert_eq!(result, expected);
+        assert_eq!(field.points().contains(&point_), expected);
+    }
+
+    #[rstest]
+    #[case(point(10, 14), true)]
+    #[case(point(10, 15), false)]
+    fn test_overlaps(#[case] center: AbsoluteWorldPoint, #[case] expected: bool) {
+        // Given
+        let field = Field::new(point(10, 10), 2);
+        let other = Field::new(center, 2);
+
+        // When
+        let result = field.overlaps(&other);
+
+        // Then
+        assert_eq!(result, expected);
+    }
+}
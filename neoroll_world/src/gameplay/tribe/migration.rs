@@ -0,0 +1,39 @@
+use crate::space::AbsoluteWorldPoint;
+
+/// Tribe move from its campfire to a new place. Transportable stocks of left
+/// structures are carried to the new home, then left structures are dismantled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    from: AbsoluteWorldPoint,
+    to: AbsoluteWorldPoint,
+    left: Vec<AbsoluteWorldPoint>,
+}
+
+impl Migration {
+    pub fn new(
+        from: AbsoluteWorldPoint,
+        to: AbsoluteWorldPoint,
+        left: Vec<AbsoluteWorldPoint>,
+    ) -> Self {
+        Self { from, to, left }
+    }
+
+    /// Previous campfire
+    pub fn from(&self) -> &AbsoluteWorldPoint {
+        &self.from
+    }
+
+    /// New campfire
+    pub fn to(&self) -> &AbsoluteWorldPoint {
+        &self.to
+    }
+
+    /// Structures which will be dismantled at the end of migration
+    pub fn left(&self) -> &[AbsoluteWorldPoint] {
+        &self.left
+    }
+
+    pub fn is_left(&self, point: &AbsoluteWorldPoint) -> bool {
+        self.left.contains(point)
+    }
+}
@@ -1,3 +1,5 @@
+pub mod field;
+pub mod migration;
 pub mod settings;
 pub mod structure;
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Base unit in which a material quantity is counted
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, Default)]
+pub enum Unit {
+    Gram,
+    #[default]
+    Unit,
+    CubicMeter,
+}
+
+impl Unit {
+    /// Divisor and symbol used to display a quantity of this unit
+    fn display(&self) -> (u64, &str) {
+        match self {
+            Unit::Gram => (1_000, "Kg"),
+            Unit::Unit => (1, "u"),
+            Unit::CubicMeter => (1, "m³"),
+        }
+    }
+
+    pub fn format(&self, value: u64) -> String {
+        let (divide, symbol) = self.display();
+        format!("{}{}", value / divide, symbol)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitMismatch(pub Unit, pub Unit);
+
+impl Display for UnitMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "Can't mix quantities of {:?} and {:?}",
+            self.0, self.1
+        ))
+    }
+}
@@ -1,7 +1,7 @@
 use weighted_rand::builder::{NewBuilder, WalkerTableBuilder};
 
 use crate::{
-    entity::{floor::Floor, ground::Ground, structure::Structure, Filled},
+    entity::{elevation::Elevation, floor::Floor, ground::Ground, structure::Structure, Filled},
     space::{
         layer::{CompositeLayer, FilledLayer, Layers},
         world::World,
@@ -56,6 +56,7 @@ impl WorldGenerator for DummyWorldGenerator {
                 FilledLayer::new(floors),
                 CompositeLayer::new(structures),
                 FilledLayer::new(materials),
+                FilledLayer::new(vec![Elevation::default(); self.lines * self.columns]),
             ),
             self.lines,
             self.columns,
@@ -1,4 +1,8 @@
-use crate::space::world::World;
+use crate::{
+    entity::animal,
+    map::{builder::MapBuilder, Map},
+    space::{area::WorldArea, chunk::ChunkPoint, world::World},
+};
 
 pub mod dummy;
 pub mod perlin_noise_simple;
@@ -6,3 +10,23 @@ pub mod perlin_noise_simple;
 pub trait WorldGenerator {
     fn generate(&self) -> World;
 }
+
+/// Generate missing chunks of given area of a streamed world, their animals and
+/// their map sectors. Return generated chunks.
+pub fn stream(world: &mut World, map: &mut Map, area: &WorldArea) -> Vec<ChunkPoint> {
+    let chunks = world.generate_chunks(area);
+    for chunk in &chunks {
+        for animal in animal::populate_chunk(world, chunk) {
+            world.add_animal(animal);
+        }
+    }
+
+    let builder = MapBuilder::new(world);
+    for chunk in &chunks {
+        for (point, sector) in builder.chunk_sectors(chunk) {
+            map.set_sector(point, sector);
+        }
+    }
+
+    chunks
+}
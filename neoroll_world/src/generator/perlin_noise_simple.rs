@@ -1,19 +1,37 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BinaryHeap;
+use std::hash::{Hash, Hasher};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use weighted_rand::builder::{NewBuilder, WalkerTableBuilder};
-use worldgen::constraint;
 use worldgen::noise::perlin::PerlinNoise;
-use worldgen::noisemap::{NoiseMap, NoiseMapGenerator, Seed, Size, Step};
-use worldgen::world::tile::{Constraint, ConstraintType};
-use worldgen::world::{Tile, World};
+use worldgen::noisemap::{NoiseMap, NoiseMapGenerator, NoiseMapGeneratorBase, Seed, Size, Step};
 
+use crate::entity::animal;
+use crate::entity::elevation::Elevation;
 use crate::entity::floor::Floor;
 use crate::entity::ground::Ground;
 use crate::entity::structure::Structure;
 use crate::entity::Filled;
+use crate::gameplay::{material::Material, Quantity};
+use crate::space::chunk::{ChunkPoint, CHUNK_SIZE};
 use crate::space::layer::{CompositeLayer, FilledLayer, Layers};
 use crate::space::world::World as GeneratedWorld;
 
 use super::WorldGenerator;
 
+/// Noise value under which tiles are water
+const WATER_LEVEL: f64 = -0.1;
+/// Noise value under which (dry) tiles are plain, forest above
+const PLAIN_LEVEL: f64 = 0.10;
+/// Tiles around generated area also computed to find depressions crossing its border
+const POOL_MARGIN: i64 = 32;
+/// Depressions filled with water are at least this deep (in noise value)
+const POOL_MIN_DEPTH: f64 = 0.005;
+
 #[derive(Clone)]
 pub enum TileLike {
     Water,
@@ -21,6 +39,7 @@ pub enum TileLike {
     Forest,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PerlinNoiseSimpleGenerator {
     seed: String,
     lines: i64,
@@ -30,6 +49,7 @@ pub struct PerlinNoiseSimpleGenerator {
     nm2_from: f64,
     nm2_to: f64,
     nm2_factor: i64,
+    streamed: bool,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -53,12 +73,19 @@ impl PerlinNoiseSimpleGenerator {
             nm2_from,
             nm2_to,
             nm2_factor,
+            streamed: false,
         }
     }
-}
 
-impl WorldGenerator for PerlinNoiseSimpleGenerator {
-    fn generate(&self) -> GeneratedWorld {
+    /// Streamed worlds keep this generator to generate chunks beyond generated
+    /// size when they are reached. Generated size is rounded to whole chunks.
+    pub fn streamed(mut self, value: bool) -> Self {
+        self.streamed = value;
+        self
+    }
+
+    /// Noise value (height) of each tile (row by row) of given area
+    fn heights(&self, start_row: i64, start_col: i64, lines: i64, columns: i64) -> Vec<f64> {
         let noise = PerlinNoise::new();
 
         let nm1 = NoiseMap::new(noise)
@@ -68,68 +95,345 @@ impl WorldGenerator for PerlinNoiseSimpleGenerator {
         let nm2 = NoiseMap::new(noise)
             .set(Seed::of(&self.seed))
             .set(Step::of(self.nm2_from, self.nm2_to));
-        let nm = Box::new(nm1 + nm2 * self.nm2_factor);
+        let nm = nm1 + nm2 * self.nm2_factor;
+
+        // Tile sized chunks, because area is not aligned on chunks of its size
+        let size = Size::of(1, 1);
+        (start_row..start_row + lines)
+            .flat_map(|row_i| (start_col..start_col + columns).map(move |col_i| (row_i, col_i)))
+            .map(|(row_i, col_i)| nm.generate_sized_chunk(size, col_i, row_i)[0][0])
+            .collect()
+    }
 
-        let world = World::new()
-            .set(Size::of(self.columns, self.lines))
-            .add(Tile::new(TileLike::Water).when(constraint!(nm.clone(), < -0.1)))
-            .add(Tile::new(TileLike::Plain).when(constraint!(nm.clone(), < 0.10)))
-            .add(Tile::new(TileLike::Forest));
+    /// Tiles (row by row) of given area, with their elevation. Noise is computed
+    /// from absolute tile position, so tiles generated at once or by chunks are the
+    /// same (except for depressions wider than `POOL_MARGIN`).
+    fn tiles(
+        &self,
+        start_row: i64,
+        start_col: i64,
+        lines: i64,
+        columns: i64,
+    ) -> Vec<(TileLike, Elevation)> {
+        let width = columns + POOL_MARGIN * 2;
+        let heights = self.heights(
+            start_row - POOL_MARGIN,
+            start_col - POOL_MARGIN,
+            lines + POOL_MARGIN * 2,
+            width,
+        );
+        let levels = water_levels(&heights, width as usize);
 
+        (POOL_MARGIN..POOL_MARGIN + lines)
+            .flat_map(|row_i| (POOL_MARGIN..POOL_MARGIN + columns).map(move |col_i| (row_i, col_i)))
+            .map(|(row_i, col_i)| {
+                let i = (row_i * width + col_i) as usize;
+                let (height, level) = (heights[i], levels[i]);
+
+                // Water collect in low areas, where its surface is flat
+                if height < WATER_LEVEL || level - height > POOL_MIN_DEPTH {
+                    (TileLike::Water, Elevation::from_noise(level))
+                } else if height < PLAIN_LEVEL {
+                    (TileLike::Plain, Elevation::from_noise(height))
+                } else {
+                    (TileLike::Forest, Elevation::from_noise(height))
+                }
+            })
+            .collect()
+    }
+
+    fn layers<R: Rng>(&self, tiles: Vec<(TileLike, Elevation)>, rng: &mut R) -> Layers {
         let mut grounds = vec![];
         let mut floors = vec![];
         let mut structures = vec![];
-        let mut materials = vec![];
-
-        for row in world.generate(0, 0).iter() {
-            for val in row.iter() {
-                for tile in val.iter() {
-                    let (ground, floor, structure) = match tile {
-                        TileLike::Water => (Ground::FreshWater, Floor::Nothing, None),
-                        TileLike::Plain => {
-                            let floor = [
-                                Floor::ShortGrass,
-                                Floor::FruitBush(Filled::full()),
-                                Floor::Nothing,
-                            ][WalkerTableBuilder::new(&[60, 20, 20]).build().next()]
-                            .clone();
-                            let structure = [Some(Structure::BigLeafTree(Filled::full())), None]
-                                [WalkerTableBuilder::new(&[10, 90]).build().next()]
-                            .clone();
-                            let ground = [Ground::Soil, Ground::SoilFlint(Filled::full())]
-                                [WalkerTableBuilder::new(&[95, 5]).build().next()]
-                            .clone();
-                            (ground, floor, structure)
-                        }
-                        TileLike::Forest => {
-                            let structure = [
-                                Some(Structure::BigLeafTree(Filled::full())),
-                                Some(Structure::FruitTree(Filled::full(), Filled::full())),
-                                None,
-                            ][WalkerTableBuilder::new(&[60, 20, 20]).build().next()]
-                            .clone();
-                            (Ground::Soil, Floor::Nothing, structure)
-                        }
-                    };
-
-                    grounds.push(ground);
-                    floors.push(floor);
-                    structures.push(structure);
-                    materials.push(vec![]);
+        let mut materials: Vec<Vec<(Material, Quantity)>> = vec![];
+        let mut elevations = vec![];
+
+        for (tile, elevation) in tiles {
+            let (ground, floor, structure) = match tile {
+                TileLike::Water => (Ground::FreshWater(Filled::full()), Floor::Nothing, None),
+                TileLike::Plain => {
+                    let floor = [
+                        Floor::ShortGrass,
+                        Floor::FruitBush(Filled::full()),
+                        Floor::Nothing,
+                    ][WalkerTableBuilder::new(&[60, 20, 20]).build().next_rng(rng)]
+                    .clone();
+                    let structure = [Some(Structure::BigLeafTree(Filled::full())), None]
+                        [WalkerTableBuilder::new(&[10, 90]).build().next_rng(rng)]
+                    .clone();
+                    let ground = [Ground::Soil, Ground::SoilFlint(Filled::full())]
+                        [WalkerTableBuilder::new(&[95, 5]).build().next_rng(rng)]
+                    .clone();
+                    (ground, floor, structure)
                 }
-            }
+                TileLike::Forest => {
+                    let structure = [
+                        Some(Structure::BigLeafTree(Filled::full())),
+                        Some(Structure::FruitTree(Filled::full(), Filled::full())),
+                        None,
+                    ][WalkerTableBuilder::new(&[60, 20, 20]).build().next_rng(rng)]
+                    .clone();
+                    (Ground::Soil, Floor::Nothing, structure)
+                }
+            };
+
+            grounds.push(ground);
+            floors.push(floor);
+            structures.push(structure);
+            materials.push(vec![]);
+            elevations.push(elevation);
         }
 
-        GeneratedWorld::new(
-            Layers::new(
-                FilledLayer::new(grounds),
-                FilledLayer::new(floors),
-                CompositeLayer::new(structures),
-                FilledLayer::new(materials),
-            ),
-            self.lines as usize,
-            self.columns as usize,
-            vec![],
+        Layers::new(
+            FilledLayer::new(grounds),
+            FilledLayer::new(floors),
+            CompositeLayer::new(structures),
+            FilledLayer::new(materials),
+            FilledLayer::new(elevations),
         )
     }
+
+    /// Layers of given chunk (`CHUNK_SIZE` x `CHUNK_SIZE` tiles). Same seed and
+    /// chunk always give same layers.
+    pub fn generate_chunk(&self, point: &ChunkPoint) -> Layers {
+        let mut hasher = DefaultHasher::new();
+        (&self.seed, point).hash(&mut hasher);
+        let mut rng = StdRng::seed_from_u64(hasher.finish());
+
+        let size = CHUNK_SIZE as i64;
+        let tiles = self.tiles(point.0 as i64 * size, point.1 as i64 * size, size, size);
+        self.layers(tiles, &mut rng)
+    }
+}
+
+impl WorldGenerator for PerlinNoiseSimpleGenerator {
+    fn generate(&self) -> GeneratedWorld {
+        let (lines, columns) = if self.streamed {
+            let chunk_size = CHUNK_SIZE as i64;
+            (
+                (self.lines + chunk_size - 1) / chunk_size * chunk_size,
+                (self.columns + chunk_size - 1) / chunk_size * chunk_size,
+            )
+        } else {
+            (self.lines, self.columns)
+        };
+
+        let tiles = self.tiles(0, 0, lines, columns);
+        let mut world = GeneratedWorld::new(
+            self.layers(tiles, &mut rand::thread_rng()),
+            lines as usize,
+            columns as usize,
+            vec![],
+        );
+        if self.streamed {
+            world.set_generator(self.clone());
+        }
+
+        for animal in animal::populate(&world) {
+            world.add_animal(animal);
+        }
+
+        world
+    }
+}
+
+/// Height ordered by `f64::total_cmp`, to be used in a heap
+#[derive(PartialEq)]
+struct Level(f64);
+
+impl Eq for Level {}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Water surface level of each tile (row by row) of given heights, when water
+/// fills depressions up to the height where it would flow out (at least
+/// `WATER_LEVEL`). Water flows out by area borders and seas (tiles under
+/// `WATER_LEVEL`). Tiles where level is above height are under water.
+fn water_levels(heights: &[f64], columns: usize) -> Vec<f64> {
+    let lines = heights.len() / columns.max(1);
+    let mut levels = vec![f64::NAN; heights.len()];
+    let mut heap = BinaryHeap::new();
+
+    // Priority flood from outlets (borders and seas): tiles are reached from
+    // their lowest outlet
+    for row_i in 0..lines {
+        for col_i in 0..columns {
+            let i = row_i * columns + col_i;
+            let border = row_i == 0 || col_i == 0 || row_i == lines - 1 || col_i == columns - 1;
+            if border || heights[i] < WATER_LEVEL {
+                levels[i] = heights[i].max(WATER_LEVEL);
+                heap.push(Reverse((Level(levels[i]), i)));
+            }
+        }
+    }
+
+    while let Some(Reverse((Level(level), i))) = heap.pop() {
+        let (row_i, col_i) = (i / columns, i % columns);
+        let neighbours = [
+            (row_i > 0).then(|| i - columns),
+            (row_i + 1 < lines).then(|| i + columns),
+            (col_i > 0).then(|| i - 1),
+            (col_i + 1 < columns).then(|| i + 1),
+        ];
+        for neighbour in neighbours.into_iter().flatten() {
+            if levels[neighbour].is_nan() {
+                levels[neighbour] = heights[neighbour].max(level);
+                heap.push(Reverse((Level(levels[neighbour]), neighbour)));
+            }
+        }
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        generator,
+        map::{builder::MapBuilder, AbsoluteMapColI, AbsoluteMapPoint, AbsoluteMapRowI},
+        space::{area::WorldArea, AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI},
+    };
+    use rstest::*;
+
+    fn generator() -> PerlinNoiseSimpleGenerator {
+        PerlinNoiseSimpleGenerator::new("seed", 10, 10, 0.005, 0.005, 0.05, 0.05, 2).streamed(true)
+    }
+
+    fn point(row_i: isize, col_i: isize) -> AbsoluteWorldPoint {
+        AbsoluteWorldPoint(AbsoluteWorldRowI(row_i), AbsoluteWorldColI(col_i))
+    }
+
+    #[rstest]
+    fn test_generate_chunk_deterministic() {
+        // Given
+        let generator = generator();
+
+        // When
+        let first = generator.generate_chunk(&ChunkPoint(-3, 7)).into_items();
+        let second = generator.generate_chunk(&ChunkPoint(-3, 7)).into_items();
+
+        // Then
+        assert_eq!(first.0.len(), CHUNK_SIZE * CHUNK_SIZE);
+        assert_eq!(first.0, second.0);
+        assert_eq!(first.1, second.1);
+        assert_eq!(first.2, second.2);
+    }
+
+    #[rstest]
+    fn test_stream() {
+        // Given
+        let mut world = generator().generate();
+        let mut map = MapBuilder::new(&world).build();
+        let area = WorldArea::new(point(-1, 60), 10, 10);
+        let animals = world.animals().len();
+
+        // When
+        let generated = generator::stream(&mut world, &mut map, &area);
+
+        // Then
+        assert_eq!(world.lines(), CHUNK_SIZE);
+        assert_eq!(
+            generated,
+            vec![ChunkPoint(-1, 0), ChunkPoint(-1, 1), ChunkPoint(0, 1)]
+        );
+        assert!(world.ground(&point(-1, 60)).is_some());
+        assert!(world.ground(&point(-65, 60)).is_none());
+        assert!(world.animals().len() > animals);
+        assert!(world
+            .animals()
+            .values()
+            .all(|animal| world.can_walk(animal.point())));
+        let sector = AbsoluteMapPoint(AbsoluteMapRowI(-1), AbsoluteMapColI(4));
+        assert!(map.sector(&sector).is_some());
+        assert!(generator::stream(&mut world, &mut map, &area).is_empty());
+    }
+
+    #[rstest]
+    fn test_water_levels_fill_depression() {
+        // Given
+        #[rustfmt::skip]
+        let heights = vec![
+            0.5, 0.5, 0.5, 0.5, 0.5,
+            0.5, 0.1, 0.3, 0.1, 0.5,
+            0.5, 0.3, 0.2, 0.3, 0.4,
+            0.5, 0.5, 0.5, 0.5, 0.5,
+        ];
+
+        // When
+        let levels = water_levels(&heights, 5);
+
+        // Then
+        #[rustfmt::skip]
+        let expected = vec![
+            0.5, 0.5, 0.5, 0.5, 0.5,
+            0.5, 0.4, 0.4, 0.4, 0.5,
+            0.5, 0.4, 0.4, 0.4, 0.4,
+            0.5, 0.5, 0.5, 0.5, 0.5,
+        ];
+        assert_eq!(levels, expected);
+    }
+
+    #[rstest]
+    fn test_water_levels_water_level() {
+        // Given
+        let heights = vec![-0.5, -0.5, -0.5, 0.2];
+
+        // When
+        let levels = water_levels(&heights, 2);
+
+        // Then
+        assert_eq!(levels, vec![WATER_LEVEL, WATER_LEVEL, WATER_LEVEL, 0.2]);
+    }
+
+    #[rstest]
+    fn test_chunk_same_as_generated_at_once() {
+        // Given
+        let generator =
+            PerlinNoiseSimpleGenerator::new("seed", 128, 128, 0.005, 0.005, 0.05, 0.05, 2);
+
+        // When
+        let at_once = generator.tiles(0, 0, 128, 128);
+        let chunk = generator.tiles(64, 64, 64, 64);
+
+        // Then
+        let at_once = at_once
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i / 128 >= 64 && i % 128 >= 64)
+            .map(|(_, (_, elevation))| elevation)
+            .collect::<Vec<_>>();
+        let chunk = chunk
+            .into_iter()
+            .map(|(_, elevation)| elevation)
+            .collect::<Vec<_>>();
+        assert_eq!(at_once, chunk);
+    }
+
+    #[rstest]
+    fn test_pools_above_water_level() {
+        // Given
+        let generator =
+            PerlinNoiseSimpleGenerator::new("seed", 128, 128, 0.005, 0.005, 0.05, 0.05, 2);
+
+        // When
+        let tiles = generator.tiles(0, 0, 128, 128);
+
+        // Then
+        let water_level = Elevation::from_noise(WATER_LEVEL);
+        assert!(tiles
+            .iter()
+            .any(|(tile, elevation)| matches!(tile, TileLike::Water) && elevation > &water_level));
+    }
 }
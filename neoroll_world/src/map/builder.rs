@@ -1,11 +1,16 @@
+use std::collections::HashMap;
+
 use super::{
     element::Element,
     sector::{Sector, SectorRelativePoint},
-    Map, MAP_TILE_FACTOR,
+    AbsoluteMapColI, AbsoluteMapPoint, AbsoluteMapRowI, Map, MAP_TILE_FACTOR,
 };
 use crate::{
-    entity::ground::Ground,
-    space::{world::World, AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI},
+    space::{
+        chunk::{ChunkPoint, CHUNK_SIZE},
+        world::World,
+        AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+    },
     utils::{BlindFoldedMazesResolver, Direction},
 };
 use rand::seq::SliceRandom;
@@ -31,39 +36,58 @@ impl<'a> MapBuilder<'a> {
     }
 
     pub fn build(&self) -> Map {
-        let mut sectors = vec![];
-        let world_lines = self.world.lines();
-        let world_columns = self.world.columns();
+        let mut sectors = HashMap::new();
 
-        for line in (0..world_lines).step_by(MAP_TILE_FACTOR) {
-            for column in (0..world_columns).step_by(MAP_TILE_FACTOR) {
-                sectors.push(self.sector(line, column));
+        // If number of lines/columns is not divisible by MAP_TILE_FACTOR
+        // a "not finished" sector will represent last part
+        for line in (0..self.world.lines()).step_by(MAP_TILE_FACTOR) {
+            for column in (0..self.world.columns()).step_by(MAP_TILE_FACTOR) {
+                let (line, column) = (line as isize, column as isize);
+                sectors.insert(Self::map_point(line, column), self.sector(line, column));
             }
         }
 
-        // If number of lines/columns is not divisible by MAP_TILE_FACTOR
-        // a "not finished" sector will represent last part. So,
-        // lines/columns count must count it
-        let lines = (world_lines as f32 / MAP_TILE_FACTOR as f32).ceil() as usize;
-        let columns = (world_columns as f32 / MAP_TILE_FACTOR as f32).ceil() as usize;
         let lakes = if self.build_lakes {
             self.lakes()
         } else {
             vec![]
         };
 
-        Map::new(sectors, lines, columns, lakes)
+        Map::new(sectors, lakes)
+    }
+
+    /// Sectors of given (generated) chunk
+    pub fn chunk_sectors(&self, chunk: &ChunkPoint) -> Vec<(AbsoluteMapPoint, Sector)> {
+        let start = chunk.start();
+        let mut sectors = vec![];
+
+        for line in (0..CHUNK_SIZE as isize).step_by(MAP_TILE_FACTOR) {
+            for column in (0..CHUNK_SIZE as isize).step_by(MAP_TILE_FACTOR) {
+                let line = start.row_i().0 + line;
+                let column = start.col_i().0 + column;
+                sectors.push((Self::map_point(line, column), self.sector(line, column)));
+            }
+        }
+
+        sectors
+    }
+
+    fn map_point(start_world_row: isize, start_world_col: isize) -> AbsoluteMapPoint {
+        AbsoluteMapPoint(
+            AbsoluteMapRowI(start_world_row.div_euclid(MAP_TILE_FACTOR as isize)),
+            AbsoluteMapColI(start_world_col.div_euclid(MAP_TILE_FACTOR as isize)),
+        )
     }
 
-    fn sector(&self, start_world_row: usize, start_world_col: usize) -> Sector {
+    fn sector(&self, start_world_row: isize, start_world_col: isize) -> Sector {
         let mut structures = vec![];
         let mut rng = rand::thread_rng();
 
-        for row_i in start_world_row..start_world_row + MAP_TILE_FACTOR {
-            for col_i in start_world_col..start_world_col + MAP_TILE_FACTOR {
+        for row_i in start_world_row..start_world_row + MAP_TILE_FACTOR as isize {
+            for col_i in start_world_col..start_world_col + MAP_TILE_FACTOR as isize {
                 if let Some(structure) = self.world.structure(&AbsoluteWorldPoint(
-                    AbsoluteWorldRowI(row_i as isize),
-                    AbsoluteWorldColI(col_i as isize),
+                    AbsoluteWorldRowI(row_i),
+                    AbsoluteWorldColI(col_i),
                 )) {
                     structures.push(structure);
                 }
@@ -128,10 +152,15 @@ impl<'a> MapBuilder<'a> {
                     AbsoluteWorldRowI(row as isize),
                     AbsoluteWorldColI(col as isize),
                 );
-                if self.world.ground(&point) == Some(&Ground::FreshWater) {
+                if self
+                    .world
+                    .ground(&point)
+                    .map(|g| g.is_water())
+                    .unwrap_or(false)
+                {
                     let all_neighbor_is_water = Direction::iter()
                         .map(|direction| self.world.ground(&point.next(&direction)))
-                        .all(|g| g == Some(&Ground::FreshWater));
+                        .all(|g| g.map(|g| g.is_water()).unwrap_or(false));
 
                     if !all_neighbor_is_water {
                         coasts.push(point);
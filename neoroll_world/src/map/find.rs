@@ -119,6 +119,16 @@ impl<'a> AroundTileFinder<'a> {
         }
 
         if let Some(expected_collect) = &self.collect {
+            // Like coasts, a walkable tile next to a collectable one
+            if expected_collect.from_adjacent() {
+                if self.world.can_walk(point)
+                    && self.world.collect_point(point, *expected_collect).is_some()
+                {
+                    return Some(*point);
+                }
+                return None;
+            }
+
             if let Some(point_ground) = self.world.ground(point) {
                 if !point_ground
                     .collect(*expected_collect)
@@ -160,10 +170,12 @@ impl<'a> AroundTileFinder<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::entity::elevation::Elevation;
     use crate::entity::Filled;
     use crate::space::layer::Layers;
     use crate::space::layer::{CompositeLayer, FilledLayer};
     use crate::space::{AbsoluteWorldColI, AbsoluteWorldRowI};
+    use crate::tests::str_map::WorldFromStrBuilder;
     use rstest::*;
 
     #[rstest]
@@ -247,6 +259,7 @@ mod test {
                 vec![],
                 vec![],
             ]),
+            FilledLayer::new(vec![Elevation::default(); 9]),
         );
         let world = World::new(layers, 3, 3, vec![]);
         let start = AbsoluteWorldPoint(AbsoluteWorldRowI(0), AbsoluteWorldColI(0));
@@ -263,4 +276,25 @@ mod test {
         // THEN
         assert_eq!(expected, found);
     }
+
+    #[rstest]
+    #[case("00011", (0, 0), Some((0, 2)))]
+    #[case("00011", (0, 2), Some((0, 2)))]
+    #[case("00000", (0, 0), None)]
+    fn test_around_tile_finder_from_adjacent(
+        #[case] map: &str,
+        #[case] start: (isize, isize),
+        #[case] expected: Option<(isize, isize)>,
+    ) {
+        // GIVEN
+        let world = WorldFromStrBuilder::new(map).build();
+        let start = AbsoluteWorldPoint(AbsoluteWorldRowI(start.0), AbsoluteWorldColI(start.1));
+        let finder = AroundTileFinder::new(&world, start).collect(Some(CollectType::Fish));
+
+        // WHEN
+        let found = finder.search();
+
+        // THEN
+        assert_eq!(found.map(|p| (p.0 .0, p.1 .0)), expected);
+    }
 }
@@ -1,4 +1,6 @@
 pub mod find;
+use std::collections::HashMap;
+
 use self::{area::MapArea, sector::Sector};
 use crate::space::AbsoluteWorldPoint;
 use serde::{Deserialize, Serialize};
@@ -14,52 +16,31 @@ pub const MAP_TILE_FACTOR: usize = 16;
 
 #[derive(Deserialize, Serialize, Default)]
 pub struct Map {
-    sectors: Vec<Sector>,
+    /// Sectors of generated world parts (streamed worlds sectors are added
+    /// when their chunks are generated)
+    sectors: HashMap<AbsoluteMapPoint, Sector>,
     // FIXME BS NOW : identify sectors where lake is to transmit only required
     lakes: Vec<Vec<AbsoluteWorldPoint>>,
-    lines: usize,
-    columns: usize,
 }
 
 impl Map {
     pub fn empty() -> Map {
         Map {
-            sectors: vec![],
+            sectors: HashMap::new(),
             lakes: vec![],
-            lines: 0,
-            columns: 0,
         }
     }
 
-    fn new(
-        sectors: Vec<Sector>,
-        lines: usize,
-        columns: usize,
-        lakes: Vec<Vec<AbsoluteWorldPoint>>,
-    ) -> Map {
-        Map {
-            sectors,
-            lakes,
-            lines,
-            columns,
-        }
+    fn new(sectors: HashMap<AbsoluteMapPoint, Sector>, lakes: Vec<Vec<AbsoluteWorldPoint>>) -> Map {
+        Map { sectors, lakes }
     }
 
     pub fn sector(&self, point: &AbsoluteMapPoint) -> Option<&Sector> {
-        // Outside
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return None;
-        }
-
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+        self.sectors.get(point)
+    }
 
-        let i = row_i * self.columns + col_i;
-        Some(&self.sectors[i])
+    pub fn set_sector(&mut self, point: AbsoluteMapPoint, sector: Sector) {
+        self.sectors.insert(point, sector);
     }
 
     pub fn lakes(&self) -> &Vec<Vec<AbsoluteWorldPoint>> {
@@ -67,13 +48,13 @@ impl Map {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash)]
+#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash, Deserialize, Serialize)]
 pub struct AbsoluteMapRowI(pub isize);
 
-#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash)]
+#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash, Deserialize, Serialize)]
 pub struct AbsoluteMapColI(pub isize);
 
-#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash)]
+#[derive(Debug, Eq, PartialEq, Default, Clone, Copy, Hash, Deserialize, Serialize)]
 pub struct AbsoluteMapPoint(pub AbsoluteMapRowI, pub AbsoluteMapColI);
 
 impl AbsoluteMapPoint {
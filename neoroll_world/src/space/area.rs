@@ -1,3 +1,5 @@
+use crate::map::{area::MapArea, MAP_TILE_FACTOR};
+
 use super::{AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -102,9 +104,21 @@ impl WorldArea {
     }
 }
 
+impl From<&MapArea> for WorldArea {
+    /// World tiles covered by given map sectors
+    fn from(area: &MapArea) -> Self {
+        Self::new(
+            area.start().into(),
+            area.lines() * MAP_TILE_FACTOR,
+            area.columns() * MAP_TILE_FACTOR,
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::map::{AbsoluteMapColI, AbsoluteMapPoint, AbsoluteMapRowI};
     use rstest::*;
 
     #[rstest]
@@ -137,4 +151,25 @@ mod test {
         assert_eq!(new_area.lines(), new_lines);
         assert_eq!(new_area.columns(), new_columns);
     }
+
+    #[rstest]
+    fn test_world_area_from_map_area() {
+        // Given
+        let area = MapArea::new(
+            AbsoluteMapPoint(AbsoluteMapRowI(-1), AbsoluteMapColI(2)),
+            2,
+            3,
+        );
+
+        // When
+        let world_area = WorldArea::from(&area);
+
+        // Then
+        assert_eq!(
+            world_area.start(),
+            AbsoluteWorldPoint(AbsoluteWorldRowI(-16), AbsoluteWorldColI(32))
+        );
+        assert_eq!(world_area.lines(), 32);
+        assert_eq!(world_area.columns(), 48);
+    }
 }
@@ -0,0 +1,626 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    entity::{elevation::Elevation, floor::Floor, ground::Ground, structure::Structure},
+    gameplay::{material::Material, Quantity},
+};
+
+use super::{
+    area::WorldArea,
+    layer::{CompositeLayer, FilledLayer, Layers},
+    AbsoluteWorldColI, AbsoluteWorldPoint, AbsoluteWorldRowI,
+};
+
+/// Chunks are squares of this count of tiles (smaller on right and bottom edges
+/// of worlds generated at once)
+pub const CHUNK_SIZE: usize = 64;
+
+/// Chunk position, in chunks count from world origin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct ChunkPoint(pub isize, pub isize);
+
+impl ChunkPoint {
+    pub fn from_world_point(point: &AbsoluteWorldPoint) -> Self {
+        Self(
+            point.row_i().0.div_euclid(CHUNK_SIZE as isize),
+            point.col_i().0.div_euclid(CHUNK_SIZE as isize),
+        )
+    }
+
+    /// World point of chunk top left tile
+    pub fn start(&self) -> AbsoluteWorldPoint {
+        AbsoluteWorldPoint(
+            AbsoluteWorldRowI(self.0 * CHUNK_SIZE as isize),
+            AbsoluteWorldColI(self.1 * CHUNK_SIZE as isize),
+        )
+    }
+
+    /// Chunks containing at least one point of given area
+    pub fn covering(area: &WorldArea) -> Vec<Self> {
+        if area.lines() == 0 || area.columns() == 0 {
+            return vec![];
+        }
+
+        let from = Self::from_world_point(&area.start());
+        let to = Self::from_world_point(&AbsoluteWorldPoint(
+            AbsoluteWorldRowI(area.end_row().0 - 1),
+            AbsoluteWorldColI(area.end_col().0 - 1),
+        ));
+        (from.0..=to.0)
+            .flat_map(|row_i| (from.1..=to.1).map(move |col_i| Self(row_i, col_i)))
+            .collect()
+    }
+
+    fn file_name(&self) -> String {
+        format!("chunk_{}_{}.bin", self.0, self.1)
+    }
+}
+
+/// Part of world layers, stored (and loaded) independently
+#[derive(Deserialize, Serialize)]
+pub struct Chunk {
+    columns: usize,
+    layers: Layers,
+}
+
+impl Chunk {
+    /// Tile index of given chunk relative position, or None if outside
+    fn index(&self, row_i: usize, col_i: usize) -> Option<usize> {
+        let i = row_i * self.columns + col_i;
+        if col_i >= self.columns || i >= self.layers.grounds().len() {
+            return None;
+        }
+
+        Some(i)
+    }
+}
+
+/// World layers split into chunks. Chunks are loaded on demand from world
+/// directory (when world has been opened from it) and changed chunks are
+/// tracked so saving only writes them. Clean chunks can be unloaded to free
+/// memory: they will be read again when accessed.
+#[derive(Default)]
+pub struct ChunkedLayers {
+    /// Size of the world generated at once (streamed chunks can extend beyond it)
+    lines: usize,
+    columns: usize,
+    /// Chunks which can't be read contain None and are considered as missing
+    chunks: HashMap<ChunkPoint, OnceLock<Option<Chunk>>>,
+    /// Directory where not loaded chunks can be read
+    source: Option<PathBuf>,
+    /// Chunks changed since last save
+    dirty: HashSet<ChunkPoint>,
+}
+
+impl ChunkedLayers {
+    /// Split given layers (of `lines` x `columns` tiles) into chunks. These
+    /// chunks have never been saved, so they are dirty.
+    pub fn from_layers(layers: Layers, lines: usize, columns: usize) -> Self {
+        let mut chunks_items = HashMap::new();
+
+        let (grounds, floors, structures, materials, elevations) = layers.into_items();
+        for (i, ((((ground, floor), structure), material), elevation)) in grounds
+            .into_iter()
+            .zip(floors)
+            .zip(structures)
+            .zip(materials)
+            .zip(elevations)
+            .enumerate()
+        {
+            let point = AbsoluteWorldPoint(
+                AbsoluteWorldRowI((i / columns) as isize),
+                AbsoluteWorldColI((i % columns) as isize),
+            );
+            let (grounds_, floors_, structures_, materials_, elevations_) = chunks_items
+                .entry(ChunkPoint::from_world_point(&point))
+                .or_insert((vec![], vec![], vec![], vec![], vec![]));
+            grounds_.push(ground);
+            floors_.push(floor);
+            structures_.push(structure);
+            materials_.push(material);
+            elevations_.push(elevation);
+        }
+
+        let chunks = chunks_items
+            .into_iter()
+            .map(
+                |(point, (grounds, floors, structures, materials, elevations))| {
+                    let start_col_i = point.start().col_i().0 as usize;
+                    let chunk = Chunk {
+                        columns: CHUNK_SIZE.min(columns - start_col_i),
+                        layers: Layers::new(
+                            FilledLayer::new(grounds),
+                            FilledLayer::new(floors),
+                            CompositeLayer::new(structures),
+                            FilledLayer::new(materials),
+                            FilledLayer::new(elevations),
+                        ),
+                    };
+                    (point, OnceLock::from(Some(chunk)))
+                },
+            )
+            .collect::<HashMap<_, _>>();
+
+        Self {
+            lines,
+            columns,
+            dirty: chunks.keys().copied().collect(),
+            chunks,
+            source: None,
+        }
+    }
+
+    /// Layers which given chunks will be loaded from given directory when accessed
+    pub fn open(directory: &Path, lines: usize, columns: usize, chunks: Vec<ChunkPoint>) -> Self {
+        Self {
+            lines,
+            columns,
+            chunks: chunks
+                .into_iter()
+                .map(|point| (point, OnceLock::new()))
+                .collect(),
+            source: Some(directory.to_path_buf()),
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Directory where chunks are read from (and saved to)
+    pub fn source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Existing (loaded or not) chunks
+    pub fn chunk_points(&self) -> Vec<ChunkPoint> {
+        let mut points = self
+            .chunks
+            .iter()
+            .filter(|(_, chunk)| !matches!(chunk.get(), Some(None)))
+            .map(|(point, _)| *point)
+            .collect::<Vec<_>>();
+        points.sort();
+        points
+    }
+
+    /// Chunks already loaded in memory (reading them don't touch disk)
+    pub fn loaded_chunk_points(&self) -> Vec<ChunkPoint> {
+        let mut points = self
+            .chunks
+            .iter()
+            .filter(|(_, chunk)| matches!(chunk.get(), Some(Some(_))))
+            .map(|(point, _)| *point)
+            .collect::<Vec<_>>();
+        points.sort();
+        points
+    }
+
+    /// True if chunk exists and is not known as unreadable
+    pub fn has_chunk(&self, point: &ChunkPoint) -> bool {
+        self.chunks
+            .get(point)
+            .is_some_and(|chunk| !matches!(chunk.get(), Some(None)))
+    }
+
+    /// Add a new chunk made of given layers (`CHUNK_SIZE` x `CHUNK_SIZE` tiles)
+    pub fn insert(&mut self, point: ChunkPoint, layers: Layers) {
+        let chunk = Chunk {
+            columns: CHUNK_SIZE,
+            layers,
+        };
+        self.chunks.insert(point, OnceLock::from(Some(chunk)));
+        self.dirty.insert(point);
+    }
+
+    /// Unload loaded chunks which are not changed since last save and not in
+    /// given chunks, and return unloaded chunks count. Nothing is unloaded if
+    /// chunks can't be read again (no source directory).
+    pub fn unload(&mut self, keep: &HashSet<ChunkPoint>) -> usize {
+        if self.source.is_none() {
+            return 0;
+        }
+
+        let mut unloaded = 0;
+        for (point, chunk) in self.chunks.iter_mut() {
+            if matches!(chunk.get(), Some(Some(_)))
+                && !self.dirty.contains(point)
+                && !keep.contains(point)
+            {
+                *chunk = OnceLock::new();
+                unloaded += 1;
+            }
+        }
+
+        unloaded
+    }
+
+    pub fn contains(&self, point: &AbsoluteWorldPoint) -> bool {
+        self.tile(point).is_some()
+    }
+
+    /// Loaded (or load) chunk. A chunk which can't be read is considered
+    /// as missing (and so can be generated again by streaming).
+    fn chunk(&self, point: &ChunkPoint) -> Option<&Chunk> {
+        self.chunks
+            .get(point)?
+            .get_or_init(|| {
+                let directory = self
+                    .source
+                    .as_ref()
+                    .expect("Not loaded chunk imply a source directory");
+                match read(&directory.join(point.file_name())) {
+                    Ok(chunk) => Some(chunk),
+                    Err(error) => {
+                        eprintln!("Can't load world chunk: {}", error);
+                        None
+                    }
+                }
+            })
+            .as_ref()
+    }
+
+    /// Chunk and tile index in this chunk of given point, or None if outside
+    fn tile(&self, point: &AbsoluteWorldPoint) -> Option<(&Chunk, usize)> {
+        let chunk = self.chunk(&ChunkPoint::from_world_point(point))?;
+        let row_i = point.row_i().0.rem_euclid(CHUNK_SIZE as isize) as usize;
+        let col_i = point.col_i().0.rem_euclid(CHUNK_SIZE as isize) as usize;
+        Some((chunk, chunk.index(row_i, col_i)?))
+    }
+
+    fn tile_mut(&mut self, point: &AbsoluteWorldPoint) -> Option<(&mut Chunk, usize)> {
+        let (_, i) = self.tile(point)?;
+        let chunk_point = ChunkPoint::from_world_point(point);
+        self.dirty.insert(chunk_point);
+        let chunk = self
+            .chunks
+            .get_mut(&chunk_point)
+            .and_then(|chunk| chunk.get_mut())
+            .and_then(Option::as_mut)
+            .expect("Chunk has just been loaded");
+        Some((chunk, i))
+    }
+
+    pub fn ground(&self, point: &AbsoluteWorldPoint) -> Option<&Ground> {
+        let (chunk, i) = self.tile(point)?;
+        Some(chunk.layers.grounds().get(i))
+    }
+
+    pub fn floor(&self, point: &AbsoluteWorldPoint) -> Option<&Floor> {
+        let (chunk, i) = self.tile(point)?;
+        Some(chunk.layers.floors().get(i))
+    }
+
+    pub fn structure(&self, point: &AbsoluteWorldPoint) -> Option<&Option<Structure>> {
+        let (chunk, i) = self.tile(point)?;
+        Some(chunk.layers.structures().get(i))
+    }
+
+    pub fn material(&self, point: &AbsoluteWorldPoint) -> Option<&Vec<(Material, Quantity)>> {
+        let (chunk, i) = self.tile(point)?;
+        Some(chunk.layers.materials().get(i))
+    }
+
+    pub fn elevation(&self, point: &AbsoluteWorldPoint) -> Option<&Elevation> {
+        let (chunk, i) = self.tile(point)?;
+        Some(chunk.layers.elevations().get(i))
+    }
+
+    pub fn set_ground(&mut self, point: &AbsoluteWorldPoint, ground: Ground) {
+        if let Some((chunk, i)) = self.tile_mut(point) {
+            chunk.layers.grounds_mut().set(i, ground);
+        }
+    }
+
+    pub fn set_floor(&mut self, point: &AbsoluteWorldPoint, floor: Floor) {
+        if let Some((chunk, i)) = self.tile_mut(point) {
+            chunk.layers.floors_mut().set(i, floor);
+        }
+    }
+
+    pub fn set_structure(&mut self, point: &AbsoluteWorldPoint, structure: Option<Structure>) {
+        if let Some((chunk, i)) = self.tile_mut(point) {
+            chunk.layers.structures_mut().set(i, structure);
+        }
+    }
+
+    pub fn material_mut(
+        &mut self,
+        point: &AbsoluteWorldPoint,
+    ) -> Option<&mut Vec<(Material, Quantity)>> {
+        let (chunk, i) = self.tile_mut(point)?;
+        Some(chunk.layers.materials_mut().get_mut(i))
+    }
+
+    /// Write chunks into given directory and return written chunks count. When
+    /// directory is the one where chunks come from, only changed chunks are written.
+    pub fn save(&mut self, directory: &Path) -> Result<usize, WorldFileError> {
+        let to_write = if self.source.as_deref() == Some(directory) {
+            let mut dirty = self.dirty.iter().copied().collect::<Vec<_>>();
+            dirty.sort();
+            dirty
+        } else {
+            self.chunk_points()
+        };
+
+        let mut written = 0;
+        for point in &to_write {
+            // Unreadable chunks are not written (they will be generated again)
+            if let Some(chunk) = self.chunk(point) {
+                write(&directory.join(point.file_name()), chunk)?;
+                written += 1;
+            }
+        }
+
+        self.source = Some(directory.to_path_buf());
+        self.dirty.clear();
+        Ok(written)
+    }
+}
+
+pub fn read<T: DeserializeOwned>(path: &Path) -> Result<T, WorldFileError> {
+    let content =
+        fs::read(path).map_err(|error| WorldFileError::Io(path.into(), error.to_string()))?;
+    bincode::deserialize(&content)
+        .map_err(|error| WorldFileError::Encoding(path.into(), error.to_string()))
+}
+
+pub fn write<T: Serialize>(path: &Path, value: &T) -> Result<(), WorldFileError> {
+    let content = bincode::serialize(value)
+        .map_err(|error| WorldFileError::Encoding(path.into(), error.to_string()))?;
+    fs::write(path, content).map_err(|error| WorldFileError::Io(path.into(), error.to_string()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorldFileError {
+    Io(PathBuf, String),
+    Encoding(PathBuf, String),
+    ChunkSize(usize),
+}
+
+impl Display for WorldFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorldFileError::Io(path, error) | WorldFileError::Encoding(path, error) => {
+                f.write_str(&format!("{}: {}", path.display(), error))
+            }
+            WorldFileError::ChunkSize(size) => f.write_str(&format!(
+                "World chunk size is {} but expected {}",
+                size, CHUNK_SIZE
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::entity::Filled;
+    use rstest::*;
+
+    fn layers(lines: usize, columns: usize) -> Layers {
+        let count = lines * columns;
+        Layers::new(
+            FilledLayer::new((0..count).map(|_| Ground::Soil).collect()),
+            FilledLayer::new((0..count).map(|_| Floor::Nothing).collect()),
+            // Structure on tile index, to identify tiles
+            CompositeLayer::new(
+                (0..count)
+                    .map(|i| {
+                        if i % 7 == 0 {
+                            Some(Structure::Campfire)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            ),
+            FilledLayer::new((0..count).map(|_| vec![]).collect()),
+            FilledLayer::new((0..count).map(|i| Elevation(i as u8)).collect()),
+        )
+    }
+
+    fn point(row_i: isize, col_i: isize) -> AbsoluteWorldPoint {
+        AbsoluteWorldPoint(AbsoluteWorldRowI(row_i), AbsoluteWorldColI(col_i))
+    }
+
+    fn directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("neoroll_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[rstest]
+    #[case(1, 1, 1)]
+    #[case(64, 64, 1)]
+    #[case(65, 64, 2)]
+    #[case(130, 70, 6)]
+    fn test_from_layers(#[case] lines: usize, #[case] columns: usize, #[case] chunks: usize) {
+        // Given
+        let chunked = ChunkedLayers::from_layers(layers(lines, columns), lines, columns);
+
+        // When
+        let structures = (0..lines)
+            .flat_map(|row_i| (0..columns).map(move |col_i| (row_i, col_i)))
+            .map(|(row_i, col_i)| chunked.structure(&point(row_i as isize, col_i as isize)))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(chunked.chunks.len(), chunks);
+        for (i, structure) in structures.into_iter().enumerate() {
+            assert_eq!(structure.unwrap().is_some(), i % 7 == 0);
+        }
+    }
+
+    #[rstest]
+    #[case(-1, 0)]
+    #[case(0, -1)]
+    #[case(100, 0)]
+    #[case(0, 70)]
+    fn test_outside(#[case] row_i: isize, #[case] col_i: isize) {
+        // Given
+        let mut chunked = ChunkedLayers::from_layers(layers(100, 70), 100, 70);
+
+        // When
+        chunked.set_ground(&point(row_i, col_i), Ground::FreshWater(Filled::full()));
+
+        // Then
+        assert_eq!(chunked.ground(&point(row_i, col_i)), None);
+    }
+
+    #[rstest]
+    #[case((0, 0), 1, 1, vec![(0, 0)])]
+    #[case((0, 0), 64, 65, vec![(0, 0), (0, 1)])]
+    #[case((-1, -1), 2, 2, vec![(-1, -1), (-1, 0), (0, -1), (0, 0)])]
+    #[case((-64, 0), 64, 64, vec![(-1, 0)])]
+    #[case((0, 0), 0, 10, vec![])]
+    fn test_covering(
+        #[case] start: (isize, isize),
+        #[case] lines: usize,
+        #[case] columns: usize,
+        #[case] expected: Vec<(isize, isize)>,
+    ) {
+        // Given
+        let area = WorldArea::new(point(start.0, start.1), lines, columns);
+
+        // When
+        let chunks = ChunkPoint::covering(&area);
+
+        // Then
+        let expected = expected
+            .into_iter()
+            .map(|(row_i, col_i)| ChunkPoint(row_i, col_i))
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, expected);
+    }
+
+    #[test]
+    fn test_insert() {
+        // Given
+        let mut chunked = ChunkedLayers::from_layers(layers(64, 64), 64, 64);
+        let size = CHUNK_SIZE * CHUNK_SIZE;
+        let mut layers = layers(CHUNK_SIZE, CHUNK_SIZE);
+        layers
+            .grounds_mut()
+            .set(size - 1, Ground::FreshWater(Filled::full()));
+
+        // When
+        chunked.insert(ChunkPoint(-1, -1), layers);
+
+        // Then
+        assert_eq!(
+            chunked.ground(&point(-1, -1)),
+            Some(&Ground::FreshWater(Filled::full()))
+        );
+        assert_eq!(chunked.ground(&point(-64, -64)), Some(&Ground::Soil));
+        assert_eq!(chunked.ground(&point(-65, -1)), None);
+    }
+
+    #[test]
+    fn test_save_only_changed_chunks() {
+        // Given
+        let directory = directory();
+        let mut chunked = ChunkedLayers::from_layers(layers(130, 70), 130, 70);
+        assert_eq!(chunked.save(&directory), Ok(6));
+        let mut opened = ChunkedLayers::open(&directory, 130, 70, chunked.chunk_points());
+
+        // When
+        opened.set_floor(&point(100, 65), Floor::ShortGrass);
+        let written = opened.save(&directory);
+
+        // Then
+        assert_eq!(written, Ok(1));
+        assert_eq!(opened.save(&directory), Ok(0));
+        let reopened = ChunkedLayers::open(&directory, 130, 70, opened.chunk_points());
+        assert_eq!(reopened.floor(&point(100, 65)), Some(&Floor::ShortGrass));
+        assert_eq!(reopened.floor(&point(100, 64)), Some(&Floor::Nothing));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_open_load_on_demand() {
+        // Given
+        let directory = directory();
+        let mut chunked = ChunkedLayers::from_layers(layers(130, 70), 130, 70);
+        chunked.save(&directory).unwrap();
+
+        // When
+        let opened = ChunkedLayers::open(&directory, 130, 70, chunked.chunk_points());
+        opened.ground(&point(70, 10));
+
+        // Then
+        assert_eq!(opened.loaded_chunk_points(), vec![ChunkPoint(1, 0)]);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_unreadable_chunk_is_missing() {
+        // Given
+        let directory = directory();
+        let mut chunked = ChunkedLayers::from_layers(layers(130, 70), 130, 70);
+        chunked.save(&directory).unwrap();
+        fs::write(directory.join(ChunkPoint(1, 0).file_name()), b"corrupted").unwrap();
+        let opened = ChunkedLayers::open(&directory, 130, 70, chunked.chunk_points());
+
+        // When
+        let ground = opened.ground(&point(70, 10));
+
+        // Then
+        assert_eq!(ground, None);
+        assert!(!opened.has_chunk(&ChunkPoint(1, 0)));
+        assert!(opened.has_chunk(&ChunkPoint(0, 0)));
+        assert!(!opened.chunk_points().contains(&ChunkPoint(1, 0)));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_unload_clean_chunks() {
+        // Given
+        let directory = directory();
+        let mut chunked = ChunkedLayers::from_layers(layers(130, 70), 130, 70);
+        chunked.save(&directory).unwrap();
+        let mut opened = ChunkedLayers::open(&directory, 130, 70, chunked.chunk_points());
+        opened.ground(&point(0, 0));
+        opened.ground(&point(70, 0));
+        opened.set_floor(&point(0, 65), Floor::ShortGrass);
+
+        // When
+        let unloaded = opened.unload(&HashSet::from([ChunkPoint(0, 0)]));
+
+        // Then
+        assert_eq!(unloaded, 1);
+        assert_eq!(
+            opened.loaded_chunk_points(),
+            vec![ChunkPoint(0, 0), ChunkPoint(0, 1)]
+        );
+        assert_eq!(opened.ground(&point(70, 0)), Some(&Ground::Soil));
+        assert_eq!(opened.floor(&point(0, 65)), Some(&Floor::ShortGrass));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_unload_without_source() {
+        // Given
+        let mut chunked = ChunkedLayers::from_layers(layers(130, 70), 130, 70);
+
+        // When
+        let unloaded = chunked.unload(&HashSet::new());
+
+        // Then
+        assert_eq!(unloaded, 0);
+        assert_eq!(chunked.loaded_chunk_points().len(), 6);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    entity::{floor::Floor, ground::Ground, structure::Structure},
+    entity::{elevation::Elevation, floor::Floor, ground::Ground, structure::Structure},
     gameplay::{material::Material, Quantity},
 };
 
@@ -11,6 +11,7 @@ pub struct Layers {
     floors: FilledLayer<Floor>,
     structures: CompositeLayer<Structure>,
     materials: FilledLayer<Vec<(Material, Quantity)>>,
+    elevations: FilledLayer<Elevation>,
 }
 
 impl Layers {
@@ -19,12 +20,14 @@ impl Layers {
         floors: FilledLayer<Floor>,
         structures: CompositeLayer<Structure>,
         materials: FilledLayer<Vec<(Material, Quantity)>>,
+        elevations: FilledLayer<Elevation>,
     ) -> Self {
         Self {
             grounds,
             floors,
             structures,
             materials,
+            elevations,
         }
     }
 
@@ -59,6 +62,29 @@ impl Layers {
     pub fn materials_mut(&mut self) -> &mut FilledLayer<Vec<(Material, Quantity)>> {
         &mut self.materials
     }
+
+    pub fn elevations(&self) -> &FilledLayer<Elevation> {
+        &self.elevations
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn into_items(
+        self,
+    ) -> (
+        Vec<Ground>,
+        Vec<Floor>,
+        Vec<Option<Structure>>,
+        Vec<Vec<(Material, Quantity)>>,
+        Vec<Elevation>,
+    ) {
+        (
+            self.grounds.items,
+            self.floors.items,
+            self.structures.items,
+            self.materials.items,
+            self.elevations.items,
+        )
+    }
 }
 
 impl Default for Layers {
@@ -68,6 +94,7 @@ impl Default for Layers {
             floors: FilledLayer::new(vec![]),
             structures: CompositeLayer::new(vec![]),
             materials: FilledLayer::new(vec![]),
+            elevations: FilledLayer::new(vec![]),
         }
     }
 }
@@ -9,6 +9,7 @@ use crate::{
 use self::area::WorldArea;
 
 pub mod area;
+pub mod chunk;
 pub mod layer;
 pub mod part;
 pub mod patch;
@@ -50,6 +51,26 @@ impl AbsoluteWorldPoint {
             AbsoluteWorldColI(self.1 .0 + modifier.1),
         )
     }
+
+    pub fn squared_distance(&self, other: &AbsoluteWorldPoint) -> isize {
+        let rows = self.0 .0 - other.0 .0;
+        let cols = self.1 .0 - other.1 .0;
+        rows * rows + cols * cols
+    }
+
+    /// Points in the circle of given radius around this point (including itself)
+    pub fn around(&self, radius: u32) -> Vec<AbsoluteWorldPoint> {
+        let radius = radius as isize;
+        let mut points = vec![];
+        for row in -radius..=radius {
+            for col in -radius..=radius {
+                if row * row + col * col <= radius * radius {
+                    points.push(self.apply((row, col)));
+                }
+            }
+        }
+        points
+    }
 }
 
 impl From<AbsoluteMapPoint> for AbsoluteWorldPoint {
@@ -108,6 +129,7 @@ mod test {
     #[cfg(test)]
     #[fixture]
     fn world() -> World {
+        use crate::entity::elevation::Elevation;
         use crate::space::layer::{FilledLayer, Layers};
 
         let lines = 5;
@@ -122,6 +144,7 @@ mod test {
                 FilledLayer::new(floors),
                 CompositeLayer::new(structures),
                 FilledLayer::new(materials),
+                FilledLayer::new(vec![Elevation::default(); 25]),
             ),
             lines,
             columns,
@@ -210,4 +233,16 @@ mod test {
             .collect();
         assert_eq!(grounds, expected)
     }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 5)]
+    #[case(2, 13)]
+    fn test_point_around(#[case] radius: u32, #[case] expected: usize) {
+        let center = AbsoluteWorldPoint(AbsoluteWorldRowI(3), AbsoluteWorldColI(3));
+        let points = center.around(radius);
+
+        assert_eq!(points.len(), expected);
+        assert!(points.contains(&center));
+    }
 }
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use crate::{
     entity::{
+        animal::{Animal, AnimalId},
         creature::{CreatureId, PartialCreature},
+        elevation::Elevation,
         floor::Floor,
         ground::Ground,
         structure::Structure,
@@ -16,6 +18,7 @@ use super::{area::WorldArea, layer::CompositeLayer, patch::NewLayers, AbsoluteWo
 pub struct WorldPart {
     layers: LayersPart,
     creatures: HashMap<CreatureId, PartialCreature>,
+    animals: HashMap<AnimalId, Animal>,
     area: WorldArea,
 }
 
@@ -28,6 +31,7 @@ impl WorldPart {
         Self {
             layers,
             creatures,
+            animals: HashMap::new(),
             area,
         }
     }
@@ -39,6 +43,7 @@ impl WorldPart {
                 CompositeLayer::empty(),
                 CompositeLayer::empty(),
                 CompositeLayer::empty(),
+                CompositeLayer::empty(),
             ),
             HashMap::new(),
             WorldArea::new(AbsoluteWorldPoint::zero(), 0, 0),
@@ -136,6 +141,25 @@ impl WorldPart {
         self.layers.structures().get(self.index(point))
     }
 
+    pub fn elevations(&self) -> Vec<(AbsoluteWorldPoint, &Option<Elevation>)> {
+        let mut elevations = vec![];
+
+        for point in self.area().points() {
+            elevations.push((point, self.elevation(&point)));
+        }
+
+        elevations
+    }
+
+    pub fn elevation(&self, point: &AbsoluteWorldPoint) -> &Option<Elevation> {
+        // Outside
+        if !self.area.contains(point) {
+            return &None;
+        }
+
+        self.layers.elevations().get(self.index(point))
+    }
+
     pub fn creatures(&self) -> &HashMap<CreatureId, PartialCreature> {
         &self.creatures
     }
@@ -148,6 +172,23 @@ impl WorldPart {
         self.creatures.get_mut(id)
     }
 
+    pub fn animals(&self) -> &HashMap<AnimalId, Animal> {
+        &self.animals
+    }
+
+    /// Add or update animal, or forget it if it is now outside the area
+    pub fn set_animal(&mut self, animal: Animal) {
+        if self.area.contains(animal.point()) {
+            self.animals.insert(*animal.id(), animal);
+        } else {
+            self.animals.remove(animal.id());
+        }
+    }
+
+    pub fn remove_animal(&mut self, id: &AnimalId) {
+        self.animals.remove(id);
+    }
+
     pub fn area(&self) -> &WorldArea {
         &self.area
     }
@@ -181,11 +222,16 @@ impl WorldPart {
         self.creatures.insert(*creature.id(), creature);
     }
 
+    pub fn remove_creature(&mut self, id: &CreatureId) {
+        self.creatures.remove(id);
+    }
+
     pub fn switch(&mut self, new: NewLayers, area: WorldArea) {
         let mut grounds = vec![];
         let mut floors = vec![];
         let mut structures = vec![];
         let mut materials = vec![];
+        let mut elevations = vec![];
 
         for point in area.points() {
             // dbg!(&new.material(&point));
@@ -209,6 +255,7 @@ impl WorldPart {
                     .clone()
                     .or_else(|| new.material(&point).cloned()),
             );
+            elevations.push((*self.elevation(&point)).or_else(|| new.elevation(&point).copied()));
         }
 
         self.layers = LayersPart::new(
@@ -216,18 +263,25 @@ impl WorldPart {
             CompositeLayer::new(floors),
             CompositeLayer::new(structures),
             CompositeLayer::new(materials),
+            CompositeLayer::new(elevations),
         );
         self.creatures = new
             .creatures()
             .iter()
             .map(|c| (*c.id(), c.clone()))
             .collect::<HashMap<CreatureId, PartialCreature>>();
+        self.animals = new
+            .animals()
+            .iter()
+            .map(|a| (*a.id(), a.clone()))
+            .collect::<HashMap<AnimalId, Animal>>();
         self.area = area;
     }
 
     pub fn clear(&mut self) {
         self.layers = LayersPart::default();
         self.creatures = Default::default();
+        self.animals = Default::default();
         self.area = WorldArea::zero();
     }
 }
@@ -238,6 +292,7 @@ pub struct LayersPart {
     floors: CompositeLayer<Floor>,
     structures: CompositeLayer<Structure>,
     materials: CompositeLayer<Vec<(Material, Quantity)>>,
+    elevations: CompositeLayer<Elevation>,
 }
 
 impl LayersPart {
@@ -246,12 +301,14 @@ impl LayersPart {
         floors: CompositeLayer<Floor>,
         structures: CompositeLayer<Structure>,
         materials: CompositeLayer<Vec<(Material, Quantity)>>,
+        elevations: CompositeLayer<Elevation>,
     ) -> Self {
         Self {
             grounds,
             floors,
             structures,
             materials,
+            elevations,
         }
     }
 
@@ -286,6 +343,10 @@ impl LayersPart {
     pub fn materials_mut(&mut self) -> &mut CompositeLayer<Vec<(Material, Quantity)>> {
         &mut self.materials
     }
+
+    pub fn elevations(&self) -> &CompositeLayer<Elevation> {
+        &self.elevations
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -294,6 +355,7 @@ pub enum WorldPartMessage {
     Floor(AbsoluteWorldPoint, WorldPartFloorMessage),
     Ground(AbsoluteWorldPoint, WorldPartGroundMessage),
     Creature(CreatureId, WorldPartCreatureMessage),
+    Animal(AnimalId, WorldPartAnimalMessage),
     Material(AbsoluteWorldPoint, WorldPartMaterialMessage),
 }
 
@@ -315,6 +377,13 @@ pub enum WorldPartGroundMessage {
 #[derive(Debug, Clone, PartialEq)]
 pub enum WorldPartCreatureMessage {
     New(PartialCreature),
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorldPartAnimalMessage {
+    Set(Animal),
+    Remove,
 }
 
 #[derive(Debug, Clone, PartialEq)]
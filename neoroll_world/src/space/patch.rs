@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use crate::{
-    entity::{creature::PartialCreature, floor::Floor, ground::Ground, structure::Structure},
+    entity::{
+        animal::Animal, creature::PartialCreature, elevation::Elevation, floor::Floor,
+        ground::Ground, structure::Structure,
+    },
     gameplay::{material::Material, Quantity},
 };
 
@@ -13,7 +16,9 @@ pub struct NewLayers {
     floors: HashMap<AbsoluteWorldPoint, Floor>,
     structures: HashMap<AbsoluteWorldPoint, Structure>,
     creatures: Vec<PartialCreature>,
+    animals: Vec<Animal>,
     materials: HashMap<AbsoluteWorldPoint, Vec<(Material, Quantity)>>,
+    elevations: HashMap<AbsoluteWorldPoint, Elevation>,
 }
 
 impl NewLayers {
@@ -22,6 +27,7 @@ impl NewLayers {
         let mut floors = HashMap::new();
         let mut structures = HashMap::new();
         let mut materials = HashMap::new();
+        let mut elevations = HashMap::new();
 
         for point in area.points() {
             if !ignore.contains(&point) {
@@ -37,6 +43,9 @@ impl NewLayers {
                 if let Some(material) = world.material(&point) {
                     materials.insert(point, material.clone());
                 }
+                if let Some(elevation) = world.elevation(&point) {
+                    elevations.insert(point, *elevation);
+                }
             }
         }
 
@@ -51,7 +60,14 @@ impl NewLayers {
                 .cloned()
                 .map(|c| c.into())
                 .collect(),
+            animals: world
+                .animals()
+                .values()
+                .filter(|a| area.contains(a.point()))
+                .cloned()
+                .collect(),
             materials,
+            elevations,
         }
     }
 
@@ -71,6 +87,10 @@ impl NewLayers {
         self.structures.get(point)
     }
 
+    pub fn elevation(&self, point: &AbsoluteWorldPoint) -> Option<&Elevation> {
+        self.elevations.get(point)
+    }
+
     pub fn len(&self) -> usize {
         self.grounds.len() + self.floors.len() + self.structures.len()
     }
@@ -79,6 +99,10 @@ impl NewLayers {
         &self.creatures
     }
 
+    pub fn animals(&self) -> &[Animal] {
+        &self.animals
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -1,11 +1,17 @@
 use glam::Vec2;
 use pathfinding::prelude::astar;
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
 use strum::IntoEnumIterator;
 
 use crate::{
     entity::{
+        animal::{Animal, AnimalChange, AnimalId},
         creature::{Creature, CreatureChange, CreatureId},
+        elevation::Elevation,
         floor::Floor,
         ground::Ground,
         structure::Structure,
@@ -14,22 +20,49 @@ use crate::{
         config::IntoCollect,
         material::Material,
         tribe::{structure::StructureOwn, TribeId},
-        CollectType, Quantity,
+        unit::UnitMismatch,
+        CollectType, Quantity, Volume, Weight,
+    },
+    generator::perlin_noise_simple::PerlinNoiseSimpleGenerator,
+    space::{
+        area::WorldArea,
+        chunk::{self, ChunkPoint, ChunkedLayers, WorldFileError, CHUNK_SIZE},
+        layer::Layers,
+        AbsoluteWorldPoint,
     },
-    space::{layer::Layers, AbsoluteWorldPoint},
     utils::Direction,
 };
 use serde::{Deserialize, Serialize};
 
 use super::{AbsoluteWorldColI, AbsoluteWorldRowI};
 
-#[derive(Deserialize, Serialize, Default)]
-pub struct World {
-    layers: Layers,
+/// File (in world directory) containing world description, next to chunks files
+const WORLD_HEADER_FILE: &str = "world.bin";
+
+/// World content which is not chunked
+#[derive(Deserialize, Serialize)]
+struct WorldHeader {
     lines: usize,
     columns: usize,
+    chunk_size: usize,
+    chunks: Vec<ChunkPoint>,
+    generator: Option<PerlinNoiseSimpleGenerator>,
+    creatures: Vec<Creature>,
+    animals: Vec<Animal>,
+    /// Kept here because rebuilding it would need to load all chunks
+    regrowing: Vec<AbsoluteWorldPoint>,
+}
+
+#[derive(Default)]
+pub struct World {
+    layers: ChunkedLayers,
     creatures: HashMap<CreatureId, Creature>,
     tribes_creatures: HashMap<TribeId, Vec<CreatureId>>, // TODO: feel like it should be in `Game` ...
+    animals: HashMap<AnimalId, Animal>,
+    /// When set, missing chunks can be generated (world is streamed)
+    generator: Option<PerlinNoiseSimpleGenerator>,
+    /// Points where a collectable is not full and can regrow (generated worlds are full)
+    regrowing: HashSet<AbsoluteWorldPoint>,
 }
 
 impl World {
@@ -48,88 +81,124 @@ impl World {
             .collect::<HashMap<CreatureId, Creature>>();
 
         Self {
-            layers,
-            lines,
-            columns,
+            layers: ChunkedLayers::from_layers(layers, lines, columns),
             creatures,
             tribes_creatures,
+            animals: HashMap::new(),
+            generator: None,
+            regrowing: HashSet::new(),
         }
     }
 
-    pub fn lines(&self) -> usize {
-        self.lines
+    /// Open world from given world directory. Chunks are loaded when accessed.
+    pub fn open(directory: &Path) -> Result<Self, WorldFileError> {
+        let header: WorldHeader = chunk::read(&directory.join(WORLD_HEADER_FILE))?;
+        if header.chunk_size != CHUNK_SIZE {
+            return Err(WorldFileError::ChunkSize(header.chunk_size));
+        }
+
+        let mut world = Self::new(Layers::default(), 0, 0, header.creatures);
+        world.layers = ChunkedLayers::open(directory, header.lines, header.columns, header.chunks);
+        world.generator = header.generator;
+        world.regrowing = header.regrowing.into_iter().collect();
+        for animal in header.animals {
+            world.add_animal(animal);
+        }
+
+        Ok(world)
     }
 
-    pub fn columns(&self) -> usize {
-        self.columns
+    /// Save world into given world directory. When world comes from this
+    /// directory, only changed chunks are written.
+    pub fn save(&mut self, directory: &Path) -> Result<(), WorldFileError> {
+        fs::create_dir_all(directory)
+            .map_err(|error| WorldFileError::Io(directory.into(), error.to_string()))?;
+        let header = WorldHeader {
+            lines: self.lines(),
+            columns: self.columns(),
+            chunk_size: CHUNK_SIZE,
+            chunks: self.layers.chunk_points(),
+            generator: self.generator.clone(),
+            creatures: self.creatures.values().cloned().collect(),
+            animals: self.animals.values().cloned().collect(),
+            regrowing: self.regrowing.iter().copied().collect(),
+        };
+        chunk::write(&directory.join(WORLD_HEADER_FILE), &header)?;
+        self.layers.save(directory)?;
+
+        Ok(())
     }
 
-    pub fn ground(&self, point: &AbsoluteWorldPoint) -> Option<&Ground> {
-        // Outside
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return None;
-        }
+    pub fn set_generator(&mut self, generator: PerlinNoiseSimpleGenerator) {
+        self.generator = Some(generator);
+    }
 
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+    /// Chunks of given area which can be generated (but are not yet)
+    pub fn missing_chunks(&self, area: &WorldArea) -> Vec<ChunkPoint> {
+        if self.generator.is_none() {
+            return vec![];
+        }
 
-        let i = row_i * self.columns + col_i;
-        Some(self.layers.grounds().get(i))
+        ChunkPoint::covering(area)
+            .into_iter()
+            .filter(|point| !self.layers.has_chunk(point))
+            .collect()
     }
 
-    pub fn floor(&self, point: &AbsoluteWorldPoint) -> Option<&Floor> {
-        // Outside
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return None;
+    /// Generate missing chunks of given area and return them
+    pub fn generate_chunks(&mut self, area: &WorldArea) -> Vec<ChunkPoint> {
+        let missing = self.missing_chunks(area);
+        if let Some(generator) = &self.generator {
+            for point in &missing {
+                self.layers.insert(*point, generator.generate_chunk(point));
+            }
         }
 
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+        missing
+    }
 
-        let i = row_i * self.columns + col_i;
-        Some(self.layers.floors().get(i))
+    /// Chunks already loaded in memory
+    pub fn loaded_chunks(&self) -> Vec<ChunkPoint> {
+        self.layers.loaded_chunk_points()
     }
 
-    pub fn material(&self, point: &AbsoluteWorldPoint) -> Option<&Vec<(Material, Quantity)>> {
-        // Outside
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return None;
-        }
+    /// Directory world has been opened from (or last saved to)
+    pub fn directory(&self) -> Option<&Path> {
+        self.layers.source()
+    }
 
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+    /// Unload chunks saved and not in given chunks, return unloaded chunks count
+    pub fn unload_chunks(&mut self, keep: &HashSet<ChunkPoint>) -> usize {
+        self.layers.unload(keep)
+    }
 
-        let i = row_i * self.columns + col_i;
-        Some(self.layers.materials().get(i))
+    /// Size of the world generated at once (streamed worlds extend beyond it)
+    pub fn lines(&self) -> usize {
+        self.layers.lines()
     }
 
-    pub fn structure(&self, point: &AbsoluteWorldPoint) -> &Option<Structure> {
-        // Outside
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return &None;
-        }
+    pub fn columns(&self) -> usize {
+        self.layers.columns()
+    }
+
+    pub fn ground(&self, point: &AbsoluteWorldPoint) -> Option<&Ground> {
+        self.layers.ground(point)
+    }
 
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+    pub fn floor(&self, point: &AbsoluteWorldPoint) -> Option<&Floor> {
+        self.layers.floor(point)
+    }
 
-        let i = row_i * self.columns + col_i;
-        self.layers.structures().get(i)
+    pub fn material(&self, point: &AbsoluteWorldPoint) -> Option<&Vec<(Material, Quantity)>> {
+        self.layers.material(point)
+    }
+
+    pub fn structure(&self, point: &AbsoluteWorldPoint) -> &Option<Structure> {
+        self.layers.structure(point).unwrap_or(&None)
+    }
+
+    pub fn elevation(&self, point: &AbsoluteWorldPoint) -> Option<&Elevation> {
+        self.layers.elevation(point)
     }
 
     pub fn creatures(&self) -> &HashMap<CreatureId, Creature> {
@@ -144,58 +213,123 @@ impl World {
         self.creatures.insert(*creature.id(), creature);
     }
 
+    pub fn remove_creature(&mut self, id: &CreatureId) -> Option<Creature> {
+        let creature = self.creatures.remove(id)?;
+        if let Some(creature_ids) = self.tribes_creatures.get_mut(creature.tribe_id()) {
+            creature_ids.retain(|creature_id| creature_id != id);
+        }
+        Some(creature)
+    }
+
     // FIXME: don't permit modify self.creatures by outside to be able to ensure tribes_creatures integrity
     pub fn creatures_mut(&mut self) -> &mut HashMap<CreatureId, Creature> {
         &mut self.creatures
     }
 
-    pub fn set_structure(&mut self, point: AbsoluteWorldPoint, structure: Option<Structure>) {
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+    pub fn animals(&self) -> &HashMap<AnimalId, Animal> {
+        &self.animals
+    }
 
-        let i = row_i * self.columns + col_i;
-        self.layers.structures_mut().set(i, structure);
+    pub fn animals_mut(&mut self) -> &mut HashMap<AnimalId, Animal> {
+        &mut self.animals
     }
 
-    pub fn set_floor(&mut self, point: AbsoluteWorldPoint, floor: Floor) {
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+    pub fn add_animal(&mut self, animal: Animal) {
+        self.animals.insert(*animal.id(), animal);
+    }
 
-        let i = row_i * self.columns + col_i;
-        self.layers.floors_mut().set(i, floor);
+    pub fn remove_animal(&mut self, id: &AnimalId) -> Option<Animal> {
+        self.animals.remove(id)
+    }
+
+    pub fn set_structure(&mut self, point: AbsoluteWorldPoint, structure: Option<Structure>) {
+        self.layers.set_structure(&point, structure);
+        self.update_regrowing(point);
+    }
+
+    pub fn set_floor(&mut self, point: AbsoluteWorldPoint, floor: Floor) {
+        self.layers.set_floor(&point, floor);
+        self.update_regrowing(point);
     }
 
     pub fn set_ground(&mut self, point: AbsoluteWorldPoint, ground: Ground) {
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+        self.layers.set_ground(&point, ground);
+        self.update_regrowing(point);
+    }
+
+    pub fn regrowing(&self) -> &HashSet<AbsoluteWorldPoint> {
+        &self.regrowing
+    }
 
-        let i = row_i * self.columns + col_i;
-        self.layers.grounds_mut().set(i, ground);
+    fn update_regrowing(&mut self, point: AbsoluteWorldPoint) {
+        let growing = self
+            .structure(&point)
+            .as_ref()
+            .map(|s| s.growing())
+            .unwrap_or(false)
+            || self.floor(&point).map(|f| f.growing()).unwrap_or(false);
+        let regrowing = growing
+            || CollectType::iter().any(|type_| {
+                self.ground(&point)
+                    .map(|g| g.regrowing(type_))
+                    .unwrap_or(false)
+                    || self
+                        .floor(&point)
+                        .map(|f| f.regrowing(type_))
+                        .unwrap_or(false)
+                    || self
+                        .structure(&point)
+                        .as_ref()
+                        .map(|s| s.regrowing(type_))
+                        .unwrap_or(false)
+            });
+
+        if regrowing {
+            self.regrowing.insert(point);
+        } else {
+            self.regrowing.remove(&point);
+        }
     }
 
+    /// Given quantity must be counted in material unit
     pub fn add_material(
         &mut self,
         point: AbsoluteWorldPoint,
         material: Material,
         quantity: Quantity,
-    ) {
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
-        let i = row_i * self.columns + col_i;
-
-        let materials = self.layers.materials_mut().get_mut(i);
+    ) -> Result<(), UnitMismatch> {
+        let quantity = material.checked_quantity(quantity)?;
+        let materials = match self.layers.material_mut(&point) {
+            Some(materials) => materials,
+            None => return Ok(()),
+        };
         if let Some((_, quantity_)) = materials.iter_mut().find(|(m, _)| m == &material) {
-            quantity_.0 += quantity.0;
+            *quantity_ = quantity_.clone().checked_add(quantity)?;
         } else {
             materials.push((material, quantity));
         }
+
+        Ok(())
+    }
+
+    pub fn remove_material(
+        &mut self,
+        point: AbsoluteWorldPoint,
+        material: Material,
+        quantity: Quantity,
+    ) {
+        let materials = match self.layers.material_mut(&point) {
+            Some(materials) => materials,
+            None => return,
+        };
+        if let Some((_, quantity_)) = materials.iter_mut().find(|(m, _)| m == &material) {
+            quantity_.0 -= quantity.0.min(quantity_.0);
+        }
+        materials.retain(|(_, q)| q.0 > 0);
     }
 
     pub fn contains(&self, point: &AbsoluteWorldPoint) -> bool {
-        point.0 .0 >= 0
-            && point.1 .0 >= 0
-            && point.0 .0 < self.lines as isize
-            && point.1 .0 < self.columns as isize
+        self.layers.contains(point)
     }
 
     // FIXME BS NOW: used for humans, but need to have separate code for humans !
@@ -211,14 +345,6 @@ impl World {
             .collect()
     }
 
-    pub fn layers(&self) -> &Layers {
-        &self.layers
-    }
-
-    pub fn layers_mut(&mut self) -> &mut Layers {
-        &mut self.layers
-    }
-
     pub fn can_collect(&self, point: &AbsoluteWorldPoint, collect_type: CollectType) -> bool {
         self.structure(point)
             .as_ref()
@@ -234,27 +360,36 @@ impl World {
                 .unwrap_or(false)
     }
 
-    pub fn materials_on(
+    /// Point where given collect type can be collected by a creature standing at given
+    /// point: the point itself, or an adjacent one for [CollectType::from_adjacent]
+    pub fn collect_point(
         &self,
         point: &AbsoluteWorldPoint,
-        filter: Option<Material>,
-    ) -> Vec<&(Material, Quantity)> {
-        // FIXME: something to refactor (see .floor(), .structure() etc)
-        if point.row_i().0 >= self.lines as isize
-            || point.row_i().0 < 0
-            || point.col_i().0 >= self.columns as isize
-            || point.col_i().0 < 0
-        {
-            return vec![];
+        collect_type: CollectType,
+    ) -> Option<AbsoluteWorldPoint> {
+        if self.can_collect(point, collect_type) {
+            return Some(*point);
         }
 
-        let row_i = point.row_i().0 as usize;
-        let col_i = point.col_i().0 as usize;
+        if collect_type.from_adjacent() {
+            return Direction::iter()
+                .map(|direction| point.next(&direction))
+                .find(|point| self.can_collect(point, collect_type));
+        }
 
-        let i = row_i * self.columns + col_i;
+        None
+    }
 
+    pub fn materials_on(
+        &self,
+        point: &AbsoluteWorldPoint,
+        filter: Option<Material>,
+    ) -> Vec<&(Material, Quantity)> {
         let mut found = vec![];
-        let materials = self.layers().materials().get(i);
+        let materials = match self.material(point) {
+            Some(materials) => materials,
+            None => return vec![],
+        };
 
         match filter {
             Some(filter_) => {
@@ -269,6 +404,42 @@ impl World {
         found
     }
 
+    /// Quantity of given material on given point
+    pub fn material_quantity(&self, point: &AbsoluteWorldPoint, material: Material) -> Quantity {
+        // Materials quantities are checked to be counted in their unit when added
+        material.quantity(
+            self.materials_on(point, Some(material))
+                .iter()
+                .map(|(_, q)| q.0)
+                .sum(),
+        )
+    }
+
+    /// Weight and volume of materials stored on given point
+    pub fn filling(&self, point: &AbsoluteWorldPoint) -> (Weight, Volume) {
+        let materials = self.materials_on(point, None);
+        (
+            materials.iter().map(|(m, q)| m.weight(q)).sum(),
+            materials.iter().map(|(m, q)| m.volume(q)).sum(),
+        )
+    }
+
+    /// Quantity of given material which can still be stored on given point, or
+    /// None if structure on this point is not a storage
+    pub fn storage_room(
+        &self,
+        point: &AbsoluteWorldPoint,
+        material: &Material,
+    ) -> Option<Quantity> {
+        let (weight_capacity, volume_capacity) =
+            self.structure(point).as_ref()?.storage_capacity()?;
+        let (weight, volume) = self.filling(point);
+        Some(material.fitting(
+            &Weight(weight_capacity.0.saturating_sub(weight.0)),
+            &Volume(volume_capacity.0.saturating_sub(volume.0)),
+        ))
+    }
+
     pub fn find_path(
         &self,
         from: &AbsoluteWorldPoint,
@@ -282,8 +453,11 @@ impl World {
         )
     }
 
+    /// Adjacent walkable points and their move cost, which depends on slope. Too
+    /// steep slopes can't be walked.
     fn successors(&self, from: &AbsoluteWorldPoint) -> Vec<(AbsoluteWorldPoint, i32)> {
         let mut successors = vec![];
+        let elevation = self.elevation(from).copied().unwrap_or_default();
 
         for direction in Direction::iter() {
             let (mod_row, mod_col) = direction.modifier();
@@ -296,27 +470,49 @@ impl World {
                 continue;
             }
 
-            successors.push((new_point, 1));
+            let new_elevation = self.elevation(&new_point).copied().unwrap_or_default();
+            if !elevation.walkable_to(&new_elevation) {
+                continue;
+            }
+
+            successors.push((new_point, elevation.move_cost(&new_elevation)));
         }
 
         successors
     }
 
-    pub fn can_walk(&self, point: &AbsoluteWorldPoint) -> bool {
-        // Don't care ifd outside map
-        if point.row_i().0 < 0
-            || point.col_i().0 < 0
-            || point.col_i().0 > self.columns as isize
-            || point.row_i().0 > self.lines as isize
-        {
-            return false;
-        }
+    /// A sapling can grow on soil without structure (stumps rot and let place)
+    pub fn can_plant(&self, point: &AbsoluteWorldPoint) -> bool {
+        let soil = matches!(
+            self.ground(point),
+            Some(Ground::Soil) | Some(Ground::SoilFlint(_))
+        );
+        let free = matches!(
+            self.structure(point),
+            None | Some(Structure::Nothing) | Some(Structure::Stump)
+        );
+
+        soil && free
+    }
 
-        if let Some(Ground::FreshWater) = self.ground(point) {
-            return false;
-        }
+    /// Field tile where a fruit bush can be sown
+    pub fn can_sow(&self, point: &AbsoluteWorldPoint) -> bool {
+        let soil = matches!(self.ground(point), Some(Ground::Soil));
+        let bare = matches!(
+            self.floor(point),
+            Some(Floor::Nothing) | Some(Floor::ShortGrass)
+        );
+        let free = matches!(self.structure(point), None | Some(Structure::Nothing));
 
-        true
+        soil && bare && free
+    }
+
+    pub fn can_walk(&self, point: &AbsoluteWorldPoint) -> bool {
+        // Outside (or not yet generated part of) world
+        match self.ground(point) {
+            None | Some(Ground::FreshWater(_)) => false,
+            Some(_) => true,
+        }
     }
 }
 
@@ -327,6 +523,7 @@ pub enum WorldChange {
     Ground(AbsoluteWorldPoint, GroundChange),
     Material(AbsoluteWorldPoint, MaterialChange),
     Creature(CreatureId, CreatureChange),
+    Animal(AnimalId, AnimalChange),
 }
 
 #[derive(Debug)]
@@ -348,4 +545,115 @@ pub enum GroundChange {
 #[derive(Debug)]
 pub enum MaterialChange {
     Add(Material, Quantity),
+    Remove(Material, Quantity),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::entity::Filled;
+    use crate::gameplay::{material::Resource, unit::Unit};
+    use crate::space::{
+        layer::{CompositeLayer, FilledLayer},
+        AbsoluteWorldColI, AbsoluteWorldRowI,
+    };
+    use rstest::*;
+
+    fn world(elevations: Vec<u8>) -> World {
+        World::new(
+            Layers::new(
+                FilledLayer::new(vec![Ground::Soil; 9]),
+                FilledLayer::new(vec![Floor::Nothing; 9]),
+                CompositeLayer::new(vec![None; 9]),
+                FilledLayer::new(vec![vec![]; 9]),
+                FilledLayer::new(elevations.into_iter().map(Elevation).collect()),
+            ),
+            3,
+            3,
+            vec![],
+        )
+    }
+
+    fn point(row: isize, col: isize) -> AbsoluteWorldPoint {
+        AbsoluteWorldPoint(AbsoluteWorldRowI(row), AbsoluteWorldColI(col))
+    }
+
+    #[rstest]
+    #[case(vec![0, 0, 0, 0, 0, 0, 0, 0, 0], Some(2))]
+    #[case(vec![0, 9, 0, 0, 9, 0, 0, 9, 0], Some(8))]
+    #[case(vec![0, 50, 0, 0, 50, 0, 0, 3, 0], Some(6))]
+    #[case(vec![0, 50, 0, 0, 50, 0, 0, 50, 0], None)]
+    fn test_find_path_slope(#[case] elevations: Vec<u8>, #[case] expected_cost: Option<i32>) {
+        // Given
+        let world = world(elevations);
+
+        // When
+        let path = world.find_path(&point(0, 0), &point(0, 2));
+
+        // Then
+        assert_eq!(path.map(|(_, cost)| cost), expected_cost);
+    }
+
+    #[test]
+    fn test_add_material_unit_mismatch() {
+        // Given
+        let mut world = world(vec![0; 9]);
+        let food = Material::Resource(Resource::Food);
+        world
+            .add_material(point(1, 1), food, Quantity::grams(500))
+            .unwrap();
+
+        // When
+        let added = world.add_material(point(1, 1), food, Quantity::units(2));
+
+        // Then
+        assert_eq!(added, Err(UnitMismatch(Unit::Gram, Unit::Unit)));
+        assert_eq!(
+            world.material_quantity(&point(1, 1), food),
+            Quantity::grams(500)
+        );
+    }
+
+    #[test]
+    fn test_open_keep_regrowing() {
+        // Given
+        let directory = std::env::temp_dir().join(format!("neoroll_{}", uuid::Uuid::new_v4()));
+        let mut world = world(vec![0; 9]);
+        world.set_structure(point(1, 1), Some(Structure::Sapling(Filled::empty())));
+        world.save(&directory).unwrap();
+
+        // When
+        let opened = World::open(&directory).unwrap();
+
+        // Then
+        assert_eq!(opened.regrowing(), &HashSet::from([point(1, 1)]));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[rstest]
+    #[case(Ground::Soil, None, true)]
+    #[case(Ground::SoilFlint(Filled::full()), None, true)]
+    #[case(Ground::FreshWater(Filled::full()), None, false)]
+    #[case(Ground::Soil, Some(Structure::Nothing), true)]
+    #[case(Ground::Soil, Some(Structure::Stump), true)]
+    #[case(Ground::Soil, Some(Structure::Sapling(Filled::empty())), false)]
+    #[case(Ground::Soil, Some(Structure::BigLeafTree(Filled::full())), false)]
+    #[case(Ground::Soil, Some(Structure::Campfire), false)]
+    fn test_can_plant(
+        #[case] ground: Ground,
+        #[case] structure: Option<Structure>,
+        #[case] expected: bool,
+    ) {
+        // Given
+        let mut world = world(vec![0; 9]);
+        world.set_ground(point(1, 1), ground);
+        world.set_structure(point(1, 1), structure);
+
+        // When
+        let can_plant = world.can_plant(&point(1, 1));
+
+        // Then
+        assert_eq!(can_plant, expected);
+        assert!(!world.can_plant(&point(3, 3)));
+    }
 }
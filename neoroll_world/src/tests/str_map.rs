@@ -1,5 +1,5 @@
 use crate::{
-    entity::{floor::Floor, ground::Ground},
+    entity::{elevation::Elevation, floor::Floor, ground::Ground, Filled},
     space::{
         layer::{CompositeLayer, FilledLayer, Layers},
         world::World,
@@ -23,7 +23,7 @@ impl<'a> WorldFromStrBuilder<'a> {
         for line in &lines {
             for char in line.trim().chars() {
                 if char == '1' {
-                    grounds.push(Ground::FreshWater)
+                    grounds.push(Ground::FreshWater(Filled::full()))
                 } else {
                     grounds.push(Ground::Soil)
                 }
@@ -36,6 +36,7 @@ impl<'a> WorldFromStrBuilder<'a> {
                 FilledLayer::new(vec![Floor::Nothing; lines.len() * columns]),
                 CompositeLayer::new(vec![None; lines.len() * columns]),
                 FilledLayer::new(vec![vec![]; lines.len() * columns]),
+                FilledLayer::new(vec![Elevation::default(); lines.len() * columns]),
             ),
             lines.len(),
             columns,
@@ -2,10 +2,7 @@ use anyhow::{bail, Result};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use crate::{
-    entity::ground::Ground,
-    space::{world::World, AbsoluteWorldPoint},
-};
+use crate::space::{world::World, AbsoluteWorldPoint};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
 pub enum Direction {
@@ -215,7 +212,7 @@ impl<'a> BlindFoldedMazeWalker<'a> {
                 // Foot must not be on the coast
                 !coasts.contains(try_foot)
                 // Foot must be inside the lake (so in water)
-                && (!start_in_water || world.ground(try_foot) == Some(&Ground::FreshWater))
+                && (!start_in_water || world.ground(try_foot).map(|g| g.is_water()).unwrap_or(false))
                 // Tile at left must be start (because left handled)
                 && &try_foot.next(&try_looking.left()) == start
             })